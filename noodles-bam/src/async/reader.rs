@@ -1,4 +1,5 @@
 mod query;
+mod query_regions;
 mod record;
 
 use std::num::NonZeroUsize;
@@ -8,7 +9,7 @@ use bytes::BytesMut;
 use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 use noodles_sam::{
     self as sam,
     alignment::RecordBuf,
@@ -19,7 +20,7 @@ use noodles_sam::{
 };
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
-use self::{query::query, record::read_record};
+use self::{query::query, query_regions::query_regions, record::read_record};
 use crate::{
     io::reader::{bytes_with_nul_to_string, resolve_region},
     Record, MAGIC_NUMBER,
@@ -444,6 +445,56 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns a stream over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. Each record is paired with the
+    /// indices of the regions it intersects.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bam::{self as bam, bai};
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bam").await.map(bam::AsyncReader::new)?;
+    /// let header = reader.read_header().await?.parse()?;
+    ///
+    /// let index = bai::r#async::read("sample.bam.bai").await?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some((record, region_indices)) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_regions<'a, I>(
+        &'a mut self,
+        header: &'a sam::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<impl Stream<Item = io::Result<(RecordBuf, Vec<usize>)>> + 'a>
+    where
+        I: BinningIndex,
+    {
+        let intervals = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.reference_sequences(), region)
+                    .map(|id| (id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(query_regions(self, header, chunks, intervals))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
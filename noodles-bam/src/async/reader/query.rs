@@ -82,7 +82,7 @@ where
     }))
 }
 
-async fn next_record<R>(
+pub(super) async fn next_record<R>(
     reader: &mut Reader<bgzf::AsyncReader<R>>,
     header: &sam::Header,
 ) -> io::Result<Option<RecordBuf>>
@@ -100,7 +100,11 @@ where
         })
 }
 
-fn intersects(record: &RecordBuf, reference_sequence_id: usize, region_interval: Interval) -> bool {
+pub(super) fn intersects(
+    record: &RecordBuf,
+    reference_sequence_id: usize,
+    region_interval: Interval,
+) -> bool {
     match (
         record.reference_sequence_id(),
         record.alignment_start(),
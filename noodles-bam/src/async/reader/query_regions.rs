@@ -0,0 +1,91 @@
+use std::vec;
+
+use futures::{stream, Stream};
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::binning_index::index::reference_sequence::bin::Chunk;
+use noodles_sam::{self as sam, alignment::RecordBuf};
+use tokio::io::{self, AsyncRead, AsyncSeek};
+
+use super::{
+    query::{intersects, next_record},
+    Reader,
+};
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+struct Context<'a, R>
+where
+    R: AsyncRead + AsyncSeek,
+{
+    reader: &'a mut Reader<bgzf::AsyncReader<R>>,
+
+    header: &'a sam::Header,
+    chunks: vec::IntoIter<Chunk>,
+
+    intervals: Vec<(usize, Interval)>,
+
+    state: State,
+}
+
+pub fn query_regions<'a, R>(
+    reader: &'a mut Reader<bgzf::AsyncReader<R>>,
+    header: &'a sam::Header,
+    chunks: Vec<Chunk>,
+    intervals: Vec<(usize, Interval)>,
+) -> impl Stream<Item = io::Result<(RecordBuf, Vec<usize>)>> + 'a
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let ctx = Context {
+        reader,
+
+        header,
+        chunks: chunks.into_iter(),
+
+        intervals,
+
+        state: State::Seek,
+    };
+
+    Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            match ctx.state {
+                State::Seek => {
+                    ctx.state = match ctx.chunks.next() {
+                        Some(chunk) => {
+                            ctx.reader.seek(chunk.start()).await?;
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    };
+                }
+                State::Read(chunk_end) => match next_record(ctx.reader, ctx.header).await? {
+                    Some(record) => {
+                        if ctx.reader.virtual_position() >= chunk_end {
+                            ctx.state = State::Seek;
+                        }
+
+                        let region_indices: Vec<_> = ctx
+                            .intervals
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, (id, interval))| intersects(&record, *id, *interval))
+                            .map(|(i, _)| i)
+                            .collect();
+
+                        if !region_indices.is_empty() {
+                            return Ok(Some(((record, region_indices), ctx)));
+                        }
+                    }
+                    None => ctx.state = State::Seek,
+                },
+                State::Done => return Ok(None),
+            }
+        }
+    }))
+}
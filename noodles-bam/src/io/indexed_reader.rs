@@ -2,7 +2,7 @@
 
 mod builder;
 
-use std::io::{self, Read, Seek};
+use std::io::{self, Read};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
@@ -11,12 +11,15 @@ use noodles_sam::{self as sam, alignment::RecordBuf};
 
 pub use self::builder::Builder;
 use super::{
-    reader::{Query, RecordBufs, Records},
+    reader::{Query, QueryRegions, RecordBufs, Records},
     Reader,
 };
 use crate::Record;
 
 /// An indexed BAM reader.
+///
+/// The underlying reader is a BGZF reader, e.g., a [`bgzf::Reader`] or a
+/// [`bgzf::MultithreadedReader`].
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
@@ -26,6 +29,28 @@ impl<R> IndexedReader<R>
 where
     R: Read,
 {
+    /// Creates an indexed BAM reader from a BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let inner = bgzf::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, &[][..]);
+    /// let reader = bam::io::IndexedReader::from_reader(inner, bai::Index::default());
+    /// ```
+    pub fn from_reader<I>(inner: R, index: I) -> Self
+    where
+        I: BinningIndex + 'static,
+    {
+        Self {
+            inner: Reader::from(inner),
+            index: Box::new(index),
+        }
+    }
+
     /// Returns a reference to the underlying reader.
     pub fn get_ref(&self) -> &R {
         self.inner.get_ref()
@@ -85,16 +110,13 @@ where
     where
         I: BinningIndex + 'static,
     {
-        Self {
-            inner: Reader::new(inner),
-            index: Box::new(index),
-        }
+        Self::from_reader(bgzf::Reader::new(inner), index)
     }
 }
 
-impl<R> IndexedReader<bgzf::Reader<R>>
+impl<R> IndexedReader<R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     /// Returns an iterator over records that intersect the given region.
     ///
@@ -107,6 +129,17 @@ where
         self.inner.query(header, &self.index, region)
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions(
+        &mut self,
+        header: &sam::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'_, R>> {
+        self.inner.query_regions(header, &self.index, regions)
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     pub fn query_unmapped(&mut self) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
         self.inner.query_unmapped(&self.index)
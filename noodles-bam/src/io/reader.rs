@@ -2,7 +2,9 @@
 
 mod builder;
 mod header;
+mod partition;
 mod query;
+mod query_regions;
 mod record;
 mod record_buf;
 mod record_bufs;
@@ -10,15 +12,20 @@ mod records;
 
 use std::{
     ffi::CStr,
-    io::{self, Read, Seek},
+    io::{self, Read},
+    iter,
+    ops::Range,
 };
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 use noodles_sam::{self as sam, alignment::RecordBuf, header::ReferenceSequences};
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs, records::Records};
+pub use self::{
+    builder::Builder, query::Query, query_regions::QueryRegions, record_bufs::RecordBufs,
+    records::Records,
+};
 use self::{record::read_record, record_buf::read_record_buf};
 use crate::Record;
 
@@ -264,7 +271,12 @@ where
     pub fn new(reader: R) -> Self {
         Self::from(bgzf::Reader::new(reader))
     }
+}
 
+impl<R> Reader<R>
+where
+    R: bgzf::io::BufRead,
+{
     /// Returns the current virtual position of the underlying BGZF reader.
     ///
     /// # Examples
@@ -286,9 +298,9 @@ where
     }
 }
 
-impl<R> Reader<bgzf::Reader<R>>
+impl<R> Reader<R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     /// Seeks the underlying BGZF reader to the given virtual position.
     ///
@@ -306,7 +318,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
-        self.inner.seek(pos)
+        self.inner.seek_to_virtual_position(pos)
     }
 
     // Seeks to the first record by setting the cursor to the beginning of the stream and
@@ -360,6 +372,53 @@ where
         ))
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. The regions that a record
+    /// intersects are available from [`QueryRegions::region_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some(record) = query.next().transpose()? {
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<I>(
+        &mut self,
+        header: &sam::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'_, R>>
+    where
+        I: BinningIndex,
+    {
+        let intervals = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.reference_sequences(), region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(QueryRegions::new(self.get_mut(), chunks, intervals))
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// # Examples
@@ -400,6 +459,78 @@ where
                 .unwrap_or(true)
         }))
     }
+
+    /// Returns an iterator over records that start in the given partition.
+    ///
+    /// Partitions typically come from [`bgzf::partition::partition`]. Unless the partition starts
+    /// at the beginning of the stream, the first record that starts in the partition is found by
+    /// searching for data that looks like a record, i.e., plausible fixed-length fields and a
+    /// valid read name, followed by another plausible record. Records are then read until one
+    /// starts at or after the end of the partition.
+    ///
+    /// Given contiguous partitions of a stream, each record is read by exactly one partition.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, num::NonZeroUsize};
+    /// use noodles_bam as bam;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let partitions = bgzf::partition::partition(reader.get_mut().get_mut(), worker_count)?;
+    ///
+    /// for partition in &partitions {
+    ///     for result in reader.query_partition(&header, partition)? {
+    ///         let record = result?;
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_partition(
+        &mut self,
+        header: &sam::Header,
+        partition: &Range<bgzf::VirtualPosition>,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
+        use self::partition::find_record_start;
+
+        let end = partition.end;
+        let mut is_empty = false;
+
+        if partition.start == bgzf::VirtualPosition::default() {
+            self.seek_to_first_record()?;
+        } else {
+            self.seek(partition.start)?;
+
+            let reference_sequence_count = header.reference_sequences().len();
+
+            match find_record_start(&mut self.inner, reference_sequence_count, end)? {
+                Some(pos) => {
+                    self.seek(pos)?;
+                }
+                // No record starts in the partition.
+                None => is_empty = true,
+            }
+        }
+
+        let mut record = Record::default();
+
+        Ok(iter::from_fn(move || {
+            if is_empty || self.virtual_position() >= end {
+                return None;
+            }
+
+            match self.read_record(&mut record) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok(record.clone())),
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -457,3 +588,256 @@ pub(crate) fn resolve_region(
             )
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use noodles_core::Position;
+        use noodles_csi::binning_index::{
+            index::reference_sequence::{bin::Chunk, index::LinearIndex},
+            Indexer,
+        };
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::cigar::{op::Kind, Op},
+                record_buf::{Cigar, Name},
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(144)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(144)?),
+            )
+            .build();
+
+        let records = [
+            (b"r0", 0, 1),
+            (b"r1", 0, 5),
+            (b"r2", 0, 9),
+            (b"r3", 0, 13),
+            (b"r4", 1, 1),
+        ];
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        for (name, reference_sequence_id, alignment_start) in records {
+            let alignment_start = Position::try_from(alignment_start)?;
+            let alignment_end = alignment_start
+                .checked_add(3)
+                .ok_or("invalid alignment end")?;
+
+            let record = RecordBuf::builder()
+                .set_name(Name::from(&name[..]))
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(alignment_start)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect::<Cigar>())
+                .build();
+
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_alignment_record(&header, &record)?;
+            let end_position = writer.get_ref().virtual_position();
+
+            indexer.add_record(
+                Some((reference_sequence_id, alignment_start, alignment_end, true)),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.into_inner().into_inner();
+        let index = indexer.build(header.reference_sequences().len());
+
+        let mut reader = Reader::new(Cursor::new(data.clone()));
+        let header = reader.read_header()?;
+
+        let regions = ["sq0:6-10".parse()?, "sq0:9-9".parse()?, "sq1".parse()?];
+
+        let mut query = reader.query_regions(&header, &index, &regions)?;
+        let mut actual = Vec::new();
+
+        while let Some(record) = query.next().transpose()? {
+            let name = record.name().map(|name| name.as_bytes().to_vec());
+            actual.push((name, query.region_indices().to_vec()));
+        }
+
+        let expected = [
+            (Some(b"r1".to_vec()), vec![0]),
+            (Some(b"r2".to_vec()), vec![0, 1]),
+            (Some(b"r4".to_vec()), vec![2]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let inner = bgzf::MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+        let mut reader = crate::io::IndexedReader::from_reader(inner, index);
+        let header = reader.read_header()?;
+
+        let mut query = reader.query_regions(&header, &regions)?;
+        let mut actual = Vec::new();
+
+        while let Some(record) = query.next().transpose()? {
+            let name = record.name().map(|name| name.as_bytes().to_vec());
+            actual.push((name, query.region_indices().to_vec()));
+        }
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_partition() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::{Cursor, Write},
+            num::NonZeroUsize,
+        };
+
+        use sam::{
+            alignment::{io::Write as _, record::Flags, record_buf::Name},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        const NAMES: [&[u8]; 8] = [b"r0", b"r1", b"r2", b"r3", b"r4", b"r5", b"r6", b"r7"];
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let mut writer = crate::io::Writer::from(Vec::new());
+        writer.write_header(&header)?;
+
+        for name in NAMES {
+            let record = RecordBuf::builder()
+                .set_name(Name::from(name))
+                .set_flags(Flags::UNMAPPED)
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.get_ref();
+
+        // Split the uncompressed data into small blocks, so that records span block boundaries.
+        let mut writer = bgzf::Writer::new(Vec::new());
+
+        for chunk in src.chunks(37) {
+            writer.write_all(chunk)?;
+            writer.flush()?;
+        }
+
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let expected: Vec<_> = NAMES.iter().map(|name| Some(name.to_vec())).collect();
+
+        for count in 1..=16 {
+            let partitions = bgzf::partition::partition(
+                reader.get_mut().get_mut(),
+                NonZeroUsize::try_from(count)?,
+            )?;
+
+            let mut names = Vec::new();
+
+            for partition in &partitions {
+                for result in reader.query_partition(&header, partition)? {
+                    let record = result?;
+                    names.push(record.name().map(|name| name.as_bytes().to_vec()));
+                }
+            }
+
+            assert_eq!(names, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_partition_with_record_larger_than_search_window(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::Flags,
+                record_buf::{Name, QualityScores, Sequence},
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        // 2 MiB bases, i.e., 1 MiB of packed bases and 2 MiB of quality scores.
+        const BASE_COUNT: usize = 2 << 20;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (name, base_count) in [(b"r0", 4), (b"r1", BASE_COUNT), (b"r2", 4)] {
+            let record = RecordBuf::builder()
+                .set_name(Name::from(name))
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(Sequence::from(vec![b'A'; base_count]))
+                .set_quality_scores(QualityScores::from(vec![30; base_count]))
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let expected: Vec<_> = [b"r0", b"r1", b"r2"]
+            .iter()
+            .map(|name| Some(name.to_vec()))
+            .collect();
+
+        for count in [2, 3, 8] {
+            let partitions = bgzf::partition::partition(
+                reader.get_mut().get_mut(),
+                NonZeroUsize::try_from(count)?,
+            )?;
+
+            let mut names = Vec::new();
+
+            for partition in &partitions {
+                for result in reader.query_partition(&header, partition)? {
+                    let record = result?;
+                    names.push(record.name().map(|name| name.as_bytes().to_vec()));
+                }
+            }
+
+            assert_eq!(names, expected);
+        }
+
+        Ok(())
+    }
+}
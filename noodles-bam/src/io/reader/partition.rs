@@ -0,0 +1,315 @@
+use std::io;
+
+use bytes::Buf;
+use noodles_bgzf as bgzf;
+
+// The number of uncompressed bytes before the search position to accumulate before they are
+// discarded.
+const WINDOW_SIZE: usize = 1 << 20;
+
+// The size of the fixed-length fields of a record, excluding the block size.
+const MIN_RECORD_SIZE: usize = 32;
+
+// The maximum size of a plausible record, including the block size.
+const MAX_RECORD_SIZE: usize = 1 << 28;
+
+/// Searches for the virtual position of the first record that starts at or after the current
+/// position of the reader and before the given end position.
+///
+/// BAM records have no sync marker, so record starts are guessed: a record start has plausible
+/// fixed-length fields and a valid read name, and it is followed by another plausible record (or
+/// the end of the stream). The following record is not checked if it starts more than the window
+/// size after the record start.
+///
+/// This returns `None` if no record starts before `end` or the stream reaches EOF before a record
+/// start is found.
+pub(super) fn find_record_start<R>(
+    reader: &mut R,
+    reference_sequence_count: usize,
+    end: bgzf::VirtualPosition,
+) -> io::Result<Option<bgzf::VirtualPosition>>
+where
+    R: bgzf::io::BufRead,
+{
+    let mut window = Window::default();
+    let mut i = 0;
+
+    loop {
+        if !window.fill_to(reader, i + 4 + MIN_RECORD_SIZE)? {
+            return Ok(None);
+        }
+
+        let position = window.virtual_position(i)?;
+
+        if position >= end {
+            return Ok(None);
+        }
+
+        if is_record_start(reader, &mut window, i, reference_sequence_count)? {
+            return Ok(Some(position));
+        }
+
+        i += 1;
+        window.discard_before(i);
+    }
+}
+
+// Returns whether a record starts at `i`.
+//
+// The fixed-length fields of the record at `i` are expected to be in the window.
+fn is_record_start<R>(
+    reader: &mut R,
+    window: &mut Window,
+    i: usize,
+    reference_sequence_count: usize,
+) -> io::Result<bool>
+where
+    R: bgzf::io::BufRead,
+{
+    const L_READ_NAME_POSITION: usize = 12;
+
+    let Some(record_size) = read_record_size(window.get(i), reference_sequence_count) else {
+        return Ok(false);
+    };
+
+    let l_read_name = usize::from(window.get(i)[L_READ_NAME_POSITION]);
+
+    if !window.fill_to(reader, i + 4 + MIN_RECORD_SIZE + l_read_name)?
+        || !is_valid_read_name(window.get(i))
+    {
+        return Ok(false);
+    }
+
+    if record_size > WINDOW_SIZE {
+        return Ok(true);
+    }
+
+    let next_start = i + record_size;
+
+    if window.fill_to(reader, next_start + 4 + MIN_RECORD_SIZE)? {
+        Ok(read_record_size(window.get(next_start), reference_sequence_count).is_some())
+    } else {
+        Ok(window.end() == next_start)
+    }
+}
+
+// A growing buffer of the uncompressed data read by the search.
+#[derive(Default)]
+struct Window {
+    buf: Vec<u8>,
+    // The offset of the start of `buf` from the start of the search.
+    offset: usize,
+    // The offsets and virtual positions of the data read from the reader.
+    segments: Vec<(usize, bgzf::VirtualPosition)>,
+    is_eof: bool,
+}
+
+impl Window {
+    fn end(&self) -> usize {
+        self.offset + self.buf.len()
+    }
+
+    fn get(&self, i: usize) -> &[u8] {
+        &self.buf[i - self.offset..]
+    }
+
+    // Reads data until the window ends at or after `end`. This returns `false` if the stream
+    // reaches EOF first.
+    fn fill_to<R>(&mut self, reader: &mut R, end: usize) -> io::Result<bool>
+    where
+        R: bgzf::io::BufRead,
+    {
+        while self.end() < end {
+            if self.is_eof {
+                return Ok(false);
+            }
+
+            let src = reader.fill_buf()?;
+
+            if src.is_empty() {
+                self.is_eof = true;
+                return Ok(false);
+            }
+
+            let start = self.end();
+            self.buf.extend_from_slice(src);
+            let len = self.end() - start;
+
+            self.segments.push((start, reader.virtual_position()));
+            reader.consume(len);
+        }
+
+        Ok(true)
+    }
+
+    fn virtual_position(&self, i: usize) -> io::Result<bgzf::VirtualPosition> {
+        resolve_virtual_position(&self.segments, i)
+    }
+
+    // Discards the data before `i` once it reaches the window size.
+    fn discard_before(&mut self, i: usize) {
+        if i - self.offset < WINDOW_SIZE {
+            return;
+        }
+
+        self.buf.drain(..i - self.offset);
+        self.offset = i;
+
+        let j = self.segments.partition_point(|(start, _)| *start <= i) - 1;
+        self.segments.drain(..j);
+    }
+}
+
+// Returns the record size (block size + 4) if `src` starts with plausible fixed-length fields.
+fn read_record_size(mut src: &[u8], reference_sequence_count: usize) -> Option<usize> {
+    const UNMAPPED: i32 = -1;
+
+    if src.len() < 4 + MIN_RECORD_SIZE {
+        return None;
+    }
+
+    let block_size = usize::try_from(src.get_u32_le()).ok()?;
+
+    if block_size < MIN_RECORD_SIZE {
+        return None;
+    }
+
+    let is_valid_reference_sequence_id =
+        |n: i32| n == UNMAPPED || usize::try_from(n).is_ok_and(|m| m < reference_sequence_count);
+
+    let reference_sequence_id = src.get_i32_le();
+    let position = src.get_i32_le();
+    let l_read_name = usize::from(src.get_u8());
+    src.advance(1); // mapq
+    src.advance(2); // bin
+    let n_cigar_op = usize::from(src.get_u16_le());
+    src.advance(2); // flag
+    let l_seq = usize::try_from(src.get_u32_le()).ok()?;
+    let mate_reference_sequence_id = src.get_i32_le();
+    let mate_position = src.get_i32_le();
+
+    if !is_valid_reference_sequence_id(reference_sequence_id)
+        || !is_valid_reference_sequence_id(mate_reference_sequence_id)
+        || position < UNMAPPED
+        || mate_position < UNMAPPED
+        || l_read_name == 0
+    {
+        return None;
+    }
+
+    let variable_size = l_read_name
+        .checked_add(n_cigar_op.checked_mul(4)?)?
+        .checked_add(l_seq / 2 + l_seq % 2)?
+        .checked_add(l_seq)?;
+
+    if MIN_RECORD_SIZE.checked_add(variable_size)? > block_size {
+        return None;
+    }
+
+    block_size
+        .checked_add(4)
+        .filter(|&record_size| record_size <= MAX_RECORD_SIZE)
+}
+
+// Returns whether the read name of the record in `src` is NUL-terminated and valid.
+//
+// `src` is expected to have plausible fixed-length fields.
+fn is_valid_read_name(src: &[u8]) -> bool {
+    const L_READ_NAME_POSITION: usize = 12;
+    const READ_NAME_POSITION: usize = 4 + MIN_RECORD_SIZE;
+    const NUL: u8 = 0x00;
+
+    let l_read_name = usize::from(src[L_READ_NAME_POSITION]);
+
+    let Some(buf) = src.get(READ_NAME_POSITION..READ_NAME_POSITION + l_read_name) else {
+        return false;
+    };
+
+    match buf.split_last() {
+        Some((&NUL, name)) => {
+            !name.is_empty() && name.iter().all(|&b| b.is_ascii_graphic() && b != b'@')
+        }
+        _ => false,
+    }
+}
+
+fn resolve_virtual_position(
+    segments: &[(usize, bgzf::VirtualPosition)],
+    i: usize,
+) -> io::Result<bgzf::VirtualPosition> {
+    // SAFETY: `segments` is non-empty, and its first offset is <= `i`.
+    let j = segments.partition_point(|(start, _)| *start <= i) - 1;
+    let (start, segment_position) = segments[j];
+
+    let upos = usize::from(segment_position.uncompressed()) + (i - start);
+
+    u16::try_from(upos)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .and_then(|upos| {
+            bgzf::VirtualPosition::try_from((segment_position.compressed(), upos))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn build_fixed_fields(block_size: u32, l_read_name: u8) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend(block_size.to_le_bytes());
+        buf.extend((-1i32).to_le_bytes()); // ref_id
+        buf.extend((-1i32).to_le_bytes()); // pos
+        buf.push(l_read_name);
+        buf.extend([0; 7]); // mapq, bin, n_cigar_op, flag
+        buf.extend(0u32.to_le_bytes()); // l_seq
+        buf.extend((-1i32).to_le_bytes()); // next_ref_id
+        buf.extend((-1i32).to_le_bytes()); // next_pos
+        buf.extend(0i32.to_le_bytes()); // tlen
+        buf
+    }
+
+    #[test]
+    fn test_find_record_start_with_large_record() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(&build_fixed_fields(1 << 24, 3))?;
+        writer.write_all(b"r0\x00")?;
+        let data = writer.finish()?;
+
+        let mut reader = bgzf::Reader::new(&data[..]);
+
+        assert_eq!(
+            find_record_start(&mut reader, 0, bgzf::VirtualPosition::MAX)?,
+            Some(bgzf::VirtualPosition::MIN)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_size() {
+        assert_eq!(read_record_size(&build_fixed_fields(35, 3), 0), Some(39));
+        assert!(read_record_size(&build_fixed_fields(31, 3), 0).is_none());
+        assert!(read_record_size(&build_fixed_fields(u32::MAX, 3), 0).is_none());
+        assert!(read_record_size(&build_fixed_fields(35, 0), 0).is_none());
+    }
+
+    #[test]
+    fn test_is_valid_read_name() {
+        fn build_record(read_name: &[u8]) -> Vec<u8> {
+            let mut buf = vec![0; 4 + MIN_RECORD_SIZE];
+            buf[12] = read_name.len() as u8;
+            buf.extend_from_slice(read_name);
+            buf
+        }
+
+        assert!(is_valid_read_name(&build_record(b"r0\x00")));
+        assert!(is_valid_read_name(&build_record(b"*\x00")));
+        assert!(!is_valid_read_name(&build_record(b"\x00")));
+        assert!(!is_valid_read_name(&build_record(b"r0")));
+        assert!(!is_valid_read_name(&build_record(b"r@\x00")));
+        assert!(!is_valid_read_name(&build_record(b"r 0\x00")));
+    }
+}
@@ -1,4 +1,4 @@
-use std::io::{self, Read, Seek};
+use std::io;
 
 use noodles_bgzf as bgzf;
 use noodles_core::region::Interval;
@@ -13,7 +13,7 @@ use crate::Record;
 /// This is created by calling [`Reader::query`].
 pub struct Query<'a, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     reader: Reader<csi::io::Query<'a, R>>,
     reference_sequence_id: usize,
@@ -23,10 +23,10 @@ where
 
 impl<'a, R> Query<'a, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     pub(super) fn new(
-        reader: &'a mut bgzf::Reader<R>,
+        reader: &'a mut R,
         chunks: Vec<Chunk>,
         reference_sequence_id: usize,
         interval: Interval,
@@ -49,7 +49,7 @@ where
 
 impl<'a, R> Iterator for Query<'a, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     type Item = io::Result<Record>;
 
@@ -70,7 +70,7 @@ where
     }
 }
 
-fn intersects(
+pub(super) fn intersects(
     record: &Record,
     reference_sequence_id: usize,
     region_interval: Interval,
@@ -0,0 +1,89 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::{query::intersects, Reader};
+use crate::Record;
+
+/// An iterator over records of a BAM reader that intersect any of a list of regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`Reader::query_regions`].
+pub struct QueryRegions<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    reader: Reader<csi::io::Query<'a, R>>,
+    intervals: Vec<(usize, Interval)>,
+    record: Record,
+    region_indices: Vec<usize>,
+}
+
+impl<'a, R> QueryRegions<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'a mut R,
+        chunks: Vec<Chunk>,
+        intervals: Vec<(usize, Interval)>,
+    ) -> Self {
+        Self {
+            reader: Reader::from(csi::io::Query::new(reader, chunks)),
+            intervals,
+            record: Record::default(),
+            region_indices: Vec::new(),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        &self.region_indices
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<Record>> {
+        self.reader.read_record(&mut self.record).map(|n| match n {
+            0 => None,
+            _ => Some(self.record.clone()),
+        })
+    }
+
+    fn update_region_indices(&mut self, record: &Record) -> io::Result<()> {
+        self.region_indices.clear();
+
+        for (i, &(reference_sequence_id, interval)) in self.intervals.iter().enumerate() {
+            if intersects(record, reference_sequence_id, interval)? {
+                self.region_indices.push(i);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, R> Iterator for QueryRegions<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.next_record() {
+                Ok(Some(record)) => match self.update_region_indices(&record) {
+                    Ok(()) if !self.region_indices.is_empty() => return Some(Ok(record)),
+                    Ok(()) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
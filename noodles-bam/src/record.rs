@@ -169,6 +169,8 @@ impl Record {
 
     /// Returns the quality scores.
     ///
+    /// Quality scores that are missing, i.e., filled with `0xff`, are returned as empty.
+    ///
     /// # Examples
     ///
     /// ```
@@ -489,6 +491,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_record_for_sam_alignment_record_with_name() -> io::Result<()> {
+        use sam::alignment::record_buf::Name;
+
+        let mut buf = DATA.to_vec();
+        buf[32] = b'r';
+
+        let lazy_record = Record::try_from(buf)?;
+        let actual = sam::alignment::RecordBuf::try_from(lazy_record)?;
+        assert_eq!(actual.name(), Some(&Name::from(b"r")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_record_for_sam_alignment_record_with_missing_quality_scores() -> io::Result<()>
+    {
+        let mut buf = DATA.to_vec();
+        buf[40..44].fill(0xff);
+
+        let lazy_record = Record::try_from(buf)?;
+        assert!(lazy_record.quality_scores().is_empty());
+
+        let actual = sam::alignment::RecordBuf::try_from(lazy_record)?;
+        assert_eq!(actual.sequence().as_ref(), b"ACGT");
+        assert!(actual.quality_scores().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quality_scores_with_partial_missing_quality_scores() -> io::Result<()> {
+        let mut buf = DATA.to_vec();
+        buf[40] = 0xff;
+
+        let record = Record::try_from(buf)?;
+        assert_eq!(record.quality_scores().as_ref(), [0xff, b'D', b'L', b'S']);
+
+        Ok(())
+    }
+
     #[test]
     fn test_cigar_with_oversized_cigar() -> Result<(), Box<dyn std::error::Error>> {
         use std::num::NonZeroUsize;
@@ -109,8 +109,17 @@ impl<'a> Fields<'a> {
     }
 
     pub(super) fn quality_scores(&self) -> QualityScores<'a> {
+        const MISSING: u8 = 0xff;
+
         let src = &self.buf[self.bounds.quality_scores_range()];
-        QualityScores::new(src)
+
+        // § 4.2.3 "SEQ and QUAL encoding" (2023-05-24): "When base qualities are omitted but the
+        // sequence is not, `qual` is filled with `0xFF` bytes (to length `l_seq`)."
+        if src.iter().all(|&b| b == MISSING) {
+            QualityScores::new(&[])
+        } else {
+            QualityScores::new(src)
+        }
     }
 
     pub(super) fn data(&self) -> Data<'a> {
@@ -32,7 +32,7 @@ impl<'a> AsRef<[u8]> for Name<'a> {
 
 impl<'a> From<Name<'a>> for sam::alignment::record_buf::Name {
     fn from(name: Name<'a>) -> Self {
-        Self::from(name.as_ref())
+        Self::from(name.as_bytes())
     }
 }
 
@@ -48,4 +48,11 @@ mod tests {
         let name = Name::new(b"r0");
         assert_eq!(name.as_bytes(), b"r0");
     }
+
+    #[test]
+    fn test_from_name_for_sam_alignment_record_buf_name() {
+        let name = Name::new(b"r0\x00");
+        let actual = sam::alignment::record_buf::Name::from(name);
+        assert_eq!(actual, sam::alignment::record_buf::Name::from(b"r0"));
+    }
 }
@@ -56,6 +56,19 @@ impl<'a> AsRef<[u8]> for Sequence<'a> {
 
 impl<'a> From<Sequence<'a>> for sam::alignment::record_buf::Sequence {
     fn from(sequence: Sequence<'a>) -> Self {
-        Self::from(sequence.as_ref().to_vec())
+        Self::from(sequence.iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_sequence_for_sam_alignment_record_buf_sequence() {
+        let sequence = Sequence::new(&[0x12, 0x48], 4);
+        let actual = sam::alignment::record_buf::Sequence::from(sequence);
+        let expected = sam::alignment::record_buf::Sequence::from(b"ACGT".to_vec());
+        assert_eq!(actual, expected);
     }
 }
@@ -1,13 +1,14 @@
 mod lazy_record;
 mod query;
+mod query_regions;
 
 use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
-use self::{lazy_record::read_lazy_record, query::query};
+use self::{lazy_record::read_lazy_record, query::query, query_regions::query_regions};
 use crate::{header::string_maps::ContigStringMap, lazy};
 
 /// An async BCF reader.
@@ -331,6 +332,60 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns a stream over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. Each record is paired with the
+    /// indices of the regions it intersects.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_csi as csi;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+    /// reader.read_file_format().await?;
+    ///
+    /// let string_maps: StringMaps = reader.read_header().await?.parse()?;
+    ///
+    /// let index = csi::r#async::read("sample.bcf.csi").await?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(string_maps.contigs(), &index, &regions)?;
+    ///
+    /// while let Some((record, region_indices)) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_regions<I>(
+        &mut self,
+        contig_string_map: &ContigStringMap,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<impl Stream<Item = io::Result<(lazy::Record, Vec<usize>)>> + '_>
+    where
+        I: BinningIndex,
+    {
+        use crate::reader::resolve_region;
+
+        let intervals = regions
+            .iter()
+            .map(|region| {
+                resolve_region(contig_string_map, region).map(|id| (id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(query_regions(self, chunks, intervals))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -79,7 +79,7 @@ where
     }))
 }
 
-async fn next_record<R>(
+pub(super) async fn next_record<R>(
     reader: &mut Reader<bgzf::AsyncReader<R>>,
 ) -> io::Result<Option<lazy::Record>>
 where
@@ -93,7 +93,7 @@ where
     })
 }
 
-fn intersects(
+pub(super) fn intersects(
     record: &lazy::Record,
     chromosome_id: usize,
     region_interval: Interval,
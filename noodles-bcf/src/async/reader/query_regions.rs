@@ -0,0 +1,88 @@
+use std::vec;
+
+use futures::{stream, Stream};
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::binning_index::index::reference_sequence::bin::Chunk;
+use tokio::io::{self, AsyncRead, AsyncSeek};
+
+use super::{
+    query::{intersects, next_record},
+    Reader,
+};
+use crate::lazy;
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+struct Context<'a, R>
+where
+    R: AsyncRead + AsyncSeek,
+{
+    reader: &'a mut Reader<bgzf::AsyncReader<R>>,
+
+    chunks: vec::IntoIter<Chunk>,
+
+    intervals: Vec<(usize, Interval)>,
+
+    state: State,
+}
+
+pub fn query_regions<R>(
+    reader: &mut Reader<bgzf::AsyncReader<R>>,
+    chunks: Vec<Chunk>,
+    intervals: Vec<(usize, Interval)>,
+) -> impl Stream<Item = io::Result<(lazy::Record, Vec<usize>)>> + '_
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let ctx = Context {
+        reader,
+
+        chunks: chunks.into_iter(),
+
+        intervals,
+
+        state: State::Seek,
+    };
+
+    Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            match ctx.state {
+                State::Seek => {
+                    ctx.state = match ctx.chunks.next() {
+                        Some(chunk) => {
+                            ctx.reader.seek(chunk.start()).await?;
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    };
+                }
+                State::Read(chunk_end) => match next_record(ctx.reader).await? {
+                    Some(record) => {
+                        if ctx.reader.virtual_position() >= chunk_end {
+                            ctx.state = State::Seek;
+                        }
+
+                        let mut region_indices = Vec::new();
+
+                        for (i, &(chromosome_id, interval)) in ctx.intervals.iter().enumerate() {
+                            if intersects(&record, chromosome_id, interval)? {
+                                region_indices.push(i);
+                            }
+                        }
+
+                        if !region_indices.is_empty() {
+                            return Ok(Some(((record, region_indices), ctx)));
+                        }
+                    }
+                    None => ctx.state = State::Seek,
+                },
+                State::Done => return Ok(None),
+            }
+        }
+    }))
+}
@@ -14,7 +14,7 @@ use noodles_vcf as vcf;
 use super::{
     header::StringMaps,
     lazy,
-    reader::{Query, Records},
+    reader::{Query, QueryRegions, Records},
     Reader,
 };
 
@@ -118,4 +118,13 @@ where
     ) -> io::Result<Query<'r, 'h, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    pub fn query_regions<'r, 'h>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        self.inner.query_regions(header, &self.index, regions)
+    }
 }
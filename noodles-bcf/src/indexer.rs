@@ -0,0 +1,131 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    self as csi,
+    binning_index::index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+};
+
+use super::{lazy, Reader};
+
+/// Indexes a BCF file with a coordinate-sorted index (CSI).
+///
+/// The min shift and depth of the binning index are configurable, which allows indexing reference
+/// sequences with positions greater than 2^29 - 1. The maximum position is
+/// 2^(min_shift + 3 * depth) - 1. `bcftools index` uses a min shift of 14 and depth of 5 by
+/// default.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bcf as bcf;
+/// let index = bcf::index_csi("sample.bcf", 14, 6)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index_csi<P>(src: P, min_shift: u8, depth: u8) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(Reader::new)?;
+    build_csi_index(&mut reader, min_shift, depth)
+}
+
+fn build_csi_index<R>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    min_shift: u8,
+    depth: u8,
+) -> io::Result<csi::Index>
+where
+    R: Read,
+{
+    let header = reader.read_header()?;
+
+    let mut indexer = csi::binning_index::Indexer::<BinnedIndex>::new(min_shift, depth);
+
+    let mut record = lazy::Record::default();
+    let mut start_position = reader.virtual_position();
+
+    while reader.read_lazy_record(&mut record)? != 0 {
+        let end_position = reader.virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        let reference_sequence_id = record.chromosome_id();
+        let start = Position::try_from(usize::from(record.position()))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let end = record.end().and_then(|position| {
+            Position::try_from(usize::from(position))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
+
+        indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build(header.contigs().len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_csi::BinningIndex;
+    use noodles_vcf::{
+        self as vcf,
+        header::record::value::{map::Contig, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_build_csi_index() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 6;
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (chromosome, position) in [("sq0", 8), ("sq1", 536870913)] {
+            let record = vcf::Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(vcf::record::Position::from(position))
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.into_inner().into_inner();
+
+        let mut reader = Reader::new(Cursor::new(data.clone()));
+        let index = build_csi_index(&mut reader, MIN_SHIFT, DEPTH)?;
+
+        assert_eq!(index.min_shift(), MIN_SHIFT);
+        assert_eq!(index.depth(), DEPTH);
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        let position = Position::try_from(536870913)?;
+        let chunks = index.query(1, (position..=position).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        // The default binning parameters cannot index the second record.
+        let mut reader = Reader::new(Cursor::new(data));
+        assert!(matches!(
+            build_csi_index(&mut reader, 14, 5),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -7,13 +7,14 @@ mod r#async;
 
 pub mod header;
 pub mod indexed_reader;
+mod indexer;
 pub mod io;
 pub mod lazy;
 pub mod reader;
 pub(crate) mod record;
 pub mod writer;
 
-pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
+pub use self::{indexed_reader::IndexedReader, indexer::index_csi, reader::Reader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::Reader as AsyncReader;
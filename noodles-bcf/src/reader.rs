@@ -4,10 +4,11 @@ mod builder;
 mod header;
 pub(crate) mod lazy_record;
 pub(crate) mod query;
+mod query_regions;
 pub(crate) mod record;
 mod records;
 
-pub use self::{builder::Builder, query::Query, records::Records};
+pub use self::{builder::Builder, query::Query, query_regions::QueryRegions, records::Records};
 
 use std::{
     io::{self, BufRead, Read, Seek},
@@ -17,7 +18,7 @@ use std::{
 use byteorder::ReadBytesExt;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 use noodles_vcf as vcf;
 
 use self::{header::read_header, lazy_record::read_lazy_record, record::read_record};
@@ -337,6 +338,60 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. The regions that a record
+    /// intersects are available from [`QueryRegions::region_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = csi::read("sample.bcf.csi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some(record) = query.next().transpose()? {
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h, I>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        let intervals = regions
+            .iter()
+            .map(|region| {
+                resolve_region(self.string_maps.contigs(), region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(QueryRegions::new(
+            &mut self.inner,
+            header,
+            &self.string_maps,
+            chunks,
+            intervals,
+        ))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -413,6 +468,80 @@ pub(crate) fn resolve_region(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_core::Position;
+        use noodles_csi::{
+            self as csi,
+            binning_index::{index::reference_sequence::bin::Chunk, Indexer},
+        };
+        use vcf::header::record::value::{map::Contig, Map};
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let records = [(0, 1), (0, 5), (0, 9), (0, 13), (1, 1)];
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let mut indexer = Indexer::default();
+
+        for (reference_sequence_id, position) in records {
+            let record = vcf::Record::builder()
+                .set_chromosome(format!("sq{reference_sequence_id}").parse()?)
+                .set_position(vcf::record::Position::from(position))
+                .set_reference_bases("ACGT".parse()?)
+                .build()?;
+
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_record(&header, &record)?;
+            let end_position = writer.get_ref().virtual_position();
+
+            let start = Position::try_from(position)?;
+            let end = start.checked_add(3).ok_or("invalid end")?;
+
+            indexer.add_record(
+                Some((reference_sequence_id, start, end, true)),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.into_inner().into_inner();
+        let index: csi::Index = indexer.build(2);
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let regions = ["sq0:6-10".parse()?, "sq0:9-9".parse()?, "sq1".parse()?];
+
+        let mut query = reader.query_regions(&header, &index, &regions)?;
+        let mut actual = Vec::new();
+
+        while let Some(record) = query.next().transpose()? {
+            actual.push((
+                record.chromosome().to_string(),
+                usize::from(record.position()),
+                query.region_indices().to_vec(),
+            ));
+        }
+
+        let expected = [
+            (String::from("sq0"), 5, vec![0]),
+            (String::from("sq0"), 9, vec![0, 1]),
+            (String::from("sq1"), 1, vec![2]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic() {
         let data = b"BCF";
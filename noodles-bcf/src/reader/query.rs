@@ -14,7 +14,7 @@ pub struct Query<'r, 'h, R>
 where
     R: Read + Seek,
 {
-    reader: csi::io::Query<'r, R>,
+    reader: csi::io::Query<'r, bgzf::Reader<R>>,
     header: &'h vcf::Header,
     string_maps: &'r StringMaps,
     chromosome_id: usize,
@@ -86,7 +86,7 @@ where
     }
 }
 
-fn intersects(
+pub(super) fn intersects(
     string_maps: &StringMaps,
     record: &vcf::Record,
     chromosome_id: usize,
@@ -0,0 +1,107 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+use noodles_vcf as vcf;
+
+use super::query::intersects;
+use crate::header::StringMaps;
+
+/// An iterator over records of a BCF reader that intersect any of a list of regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`super::Reader::query_regions`].
+pub struct QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    reader: csi::io::Query<'r, bgzf::Reader<R>>,
+    header: &'h vcf::Header,
+    string_maps: &'r StringMaps,
+    intervals: Vec<(usize, Interval)>,
+    buf: Vec<u8>,
+    record: vcf::Record,
+    region_indices: Vec<usize>,
+}
+
+impl<'r, 'h, R> QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut bgzf::Reader<R>,
+        header: &'h vcf::Header,
+        string_maps: &'r StringMaps,
+        chunks: Vec<Chunk>,
+        intervals: Vec<(usize, Interval)>,
+    ) -> Self {
+        Self {
+            reader: csi::io::Query::new(reader, chunks),
+            header,
+            string_maps,
+            intervals,
+            buf: Vec::new(),
+            record: vcf::Record::default(),
+            region_indices: Vec::new(),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        &self.region_indices
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<vcf::Record>> {
+        use super::read_record;
+
+        read_record(
+            &mut self.reader,
+            self.header,
+            self.string_maps,
+            &mut self.buf,
+            &mut self.record,
+        )
+        .map(|n| match n {
+            0 => None,
+            _ => Some(self.record.clone()),
+        })
+    }
+
+    fn update_region_indices(&mut self, record: &vcf::Record) -> io::Result<()> {
+        self.region_indices.clear();
+
+        for (i, &(chromosome_id, interval)) in self.intervals.iter().enumerate() {
+            if intersects(self.string_maps, record, chromosome_id, interval)? {
+                self.region_indices.push(i);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'r, 'h, R> Iterator for QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<vcf::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.next_record() {
+                Ok(Some(record)) => match self.update_region_indices(&record) {
+                    Ok(()) if !self.region_indices.is_empty() => return Some(Ok(record)),
+                    Ok(()) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
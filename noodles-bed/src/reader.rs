@@ -4,6 +4,8 @@ use std::{
     str::FromStr,
 };
 
+use noodles_core::Region;
+
 use super::Record;
 
 /// A BED reader.
@@ -139,6 +141,43 @@ where
             }
         })
     }
+
+    /// Reads all records as a list of regions.
+    ///
+    /// Only the first three fields (reference sequence name, start position, and end position) of
+    /// each record are used. The regions are returned in file order, which is suitable for
+    /// multi-region queries of indexed readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let data = b"sq0\t7\t13\tn0\n# sq0\t20\t34\nsq1\t20\t34\n";
+    /// let mut reader = bed::Reader::new(&data[..]);
+    ///
+    /// let regions = reader.read_regions()?;
+    ///
+    /// assert_eq!(regions, [
+    ///     Region::new("sq0", Position::try_from(8).unwrap()..=Position::try_from(13).unwrap()),
+    ///     Region::new("sq1", Position::try_from(21).unwrap()..=Position::try_from(34).unwrap()),
+    /// ]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_regions(&mut self) -> io::Result<Vec<Region>> {
+        self.records::<3>()
+            .map(|result| {
+                result.map(|record| {
+                    Region::new(
+                        record.reference_sequence_name(),
+                        record.start_position()..=record.end_position(),
+                    )
+                })
+            })
+            .collect()
+    }
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
@@ -16,7 +16,7 @@ use std::{
     str::FromStr,
 };
 
-use noodles_core::Position;
+use noodles_core::{Position, Region};
 
 const DELIMITER: char = '\t';
 const MISSING_STRING: &str = ".";
@@ -465,6 +465,20 @@ where
     }
 }
 
+impl<const N: u8> From<Record<N>> for (Region, Record<N>)
+where
+    Record<N>: BedN<3>,
+{
+    fn from(record: Record<N>) -> Self {
+        let region = Region::new(
+            record.reference_sequence_name(),
+            record.start_position()..=record.end_position(),
+        );
+
+        (region, record)
+    }
+}
+
 impl fmt::Display for Record<3> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         format_bed_3_fields(f, self)?;
@@ -1411,4 +1425,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_record_for_region_record_tuple() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::<3>::builder()
+            .set_reference_sequence_name("sq0")
+            .set_start_position(Position::try_from(8)?)
+            .set_end_position(Position::try_from(13)?)
+            .build()?;
+
+        let (region, _) = <(Region, Record<3>)>::from(record);
+        assert_eq!(region, "sq0:8-13".parse()?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,33 @@
+//! Concatenates blocked gzip files (BGZF) without recompressing their blocks.
+//!
+//! The compressed and uncompressed start positions of each input in the output are printed to
+//! stderr.
+//!
+//! The result is similar to the output of `cat <srcs...>`, with the EOF markers of the inputs
+//! removed.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_bgzf as bgzf;
+
+fn main() -> io::Result<()> {
+    let srcs: Vec<_> = env::args().skip(1).collect();
+
+    let readers = srcs
+        .iter()
+        .map(|src| File::open(src).map(BufReader::new))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut writer = io::stdout().lock();
+    let offsets = bgzf::concat::concatenate(readers, &mut writer)?;
+
+    for (src, offset) in srcs.iter().zip(offsets) {
+        eprintln!("{src}\t{}\t{}", offset.compressed(), offset.uncompressed());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! Validates every block of a blocked gzip file (BGZF).
+//!
+//! Each problem found is printed with the compressed position of the block it was found in. The
+//! process exits with a nonzero status if any problems are found.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+    process,
+};
+
+use noodles_bgzf as bgzf;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let reader = File::open(src).map(BufReader::new)?;
+    let diagnostics = bgzf::validation::validate(reader)?;
+
+    for diagnostic in &diagnostics {
+        println!("{}\t{}", diagnostic.position(), diagnostic.kind());
+    }
+
+    if !diagnostics.is_empty() {
+        process::exit(1);
+    }
+
+    Ok(())
+}
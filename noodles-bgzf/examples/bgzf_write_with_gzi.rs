@@ -0,0 +1,40 @@
+//! Compresses a file as a blocked gzip file (BGZF) and writes its gzip index (GZI).
+//!
+//! The result is similar to the outputs of `bgzip --index --index-name <dst>.gzi --stdout <src> >
+//! <dst>`.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use noodles_bgzf::{self as bgzf, gzi};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let dst = args.next().expect("missing dst");
+
+    let mut reader = File::open(src)?;
+
+    let mut writer = File::create(&dst).map(|file| {
+        bgzf::writer::Builder::default()
+            .set_build_gzi_index(true)
+            .build_with_writer(file)
+    })?;
+
+    io::copy(&mut reader, &mut writer)?;
+    writer.try_finish()?;
+
+    let index = writer.gzi_index().expect("missing GZ index");
+
+    let mut gzi_writer = File::create(format!("{dst}.gzi"))
+        .map(BufWriter::new)
+        .map(gzi::Writer::new)?;
+
+    gzi_writer.write_index(index)?;
+
+    Ok(())
+}
@@ -1,21 +1,26 @@
 //! Async BGZF writer.
 
+mod block_position;
 mod builder;
 pub(crate) mod deflate;
 mod deflater;
 
-pub use self::builder::Builder;
+pub use self::{block_position::BlockPosition, builder::Builder};
 
 use std::{
-    cmp,
+    cmp, mem,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
 use bytes::{Buf, Bytes, BytesMut};
 use futures::{ready, sink::Buffer, Sink};
 use pin_project_lite::pin_project;
-use tokio::io::{self, AsyncWrite};
+use tokio::{
+    io::{self, AsyncWrite},
+    sync::Semaphore,
+};
 
 use self::{deflate::Deflate, deflater::Deflater};
 
@@ -33,6 +38,7 @@ pin_project! {
         #[pin]
         eof_buf: Bytes,
         compression_level: CompressionLevel,
+        workers: Arc<Semaphore>,
     }
 }
 
@@ -64,6 +70,44 @@ where
     pub fn into_inner(self) -> W {
         self.sink.into_inner().into_inner()
     }
+
+    /// Takes the positions of the blocks written since the last call.
+    ///
+    /// Block positions are only tracked when enabled using
+    /// [`Builder::set_track_block_positions`]. Otherwise, this always returns an empty list.
+    ///
+    /// Blocks are compressed concurrently, and a block position is only available after the block
+    /// is written to the underlying writer. All block positions are available after the writer is
+    /// shut down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_bgzf as bgzf;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let mut writer = bgzf::r#async::writer::Builder::default()
+    ///     .set_track_block_positions(true)
+    ///     .build_with_writer(Vec::new());
+    ///
+    /// writer.write_all(b"noodles").await?;
+    /// writer.shutdown().await?;
+    ///
+    /// let block_positions = writer.take_block_positions();
+    /// assert_eq!(block_positions.len(), 1);
+    /// assert_eq!(block_positions[0].virtual_position(), bgzf::VirtualPosition::default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn take_block_positions(&mut self) -> Vec<BlockPosition> {
+        self.sink
+            .get_mut()
+            .block_positions_mut()
+            .map(mem::take)
+            .unwrap_or_default()
+    }
 }
 
 impl<W> AsyncWrite for Writer<W>
@@ -100,9 +144,11 @@ where
         ready!(this.sink.as_mut().poll_ready(cx))?;
 
         let buf = this.buf.split();
-        this.sink
-            .as_mut()
-            .start_send(Deflate::new(buf, *this.compression_level))?;
+        this.sink.as_mut().start_send(Deflate::new(
+            buf,
+            *this.compression_level,
+            this.workers.clone(),
+        ))?;
 
         Poll::Ready(Ok(()))
     }
@@ -133,3 +179,54 @@ where
         Poll::Ready(Ok(()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+    use crate::VirtualPosition;
+
+    #[tokio::test]
+    async fn test_take_block_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Builder::default()
+            .set_worker_count(NonZeroUsize::MIN)
+            .set_queue_depth(NonZeroUsize::MIN)
+            .set_track_block_positions(true)
+            .build_with_writer(Vec::new());
+
+        writer.write_all(b"noodles").await?;
+        writer.flush().await?;
+        writer.write_all(b"-bgzf").await?;
+        writer.shutdown().await?;
+
+        let block_positions = writer.take_block_positions();
+        assert!(writer.take_block_positions().is_empty());
+
+        let data = writer.into_inner();
+        let first_block_size = u64::from(u16::from_le_bytes([data[16], data[17]])) + 1;
+
+        assert_eq!(
+            block_positions,
+            [
+                BlockPosition::new(VirtualPosition::default(), 0, 7),
+                BlockPosition::new(VirtualPosition::try_from((first_block_size, 0))?, 7, 5),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_take_block_positions_without_tracking() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles").await?;
+        writer.shutdown().await?;
+
+        assert!(writer.take_block_positions().is_empty());
+
+        Ok(())
+    }
+}
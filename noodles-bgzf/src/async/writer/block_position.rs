@@ -0,0 +1,78 @@
+use crate::VirtualPosition;
+
+/// The position of a block written by an async BGZF writer.
+///
+/// This maps the uncompressed data of a block to its position in the compressed stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BlockPosition {
+    virtual_position: VirtualPosition,
+    uncompressed_position: u64,
+    uncompressed_len: usize,
+}
+
+impl BlockPosition {
+    pub(crate) fn new(
+        virtual_position: VirtualPosition,
+        uncompressed_position: u64,
+        uncompressed_len: usize,
+    ) -> Self {
+        Self {
+            virtual_position,
+            uncompressed_position,
+            uncompressed_len,
+        }
+    }
+
+    /// Returns the virtual position of the start of the block.
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.virtual_position
+    }
+
+    /// Returns the position of the start of the block's data in the uncompressed stream.
+    pub fn uncompressed_position(&self) -> u64 {
+        self.uncompressed_position
+    }
+
+    /// Returns the length of the block's uncompressed data.
+    pub fn uncompressed_len(&self) -> usize {
+        self.uncompressed_len
+    }
+
+    /// Resolves a position in the uncompressed stream to a virtual position.
+    ///
+    /// This returns `None` if the given position is not in the block.
+    pub fn resolve(&self, uncompressed_position: u64) -> Option<VirtualPosition> {
+        let offset = uncompressed_position.checked_sub(self.uncompressed_position)?;
+        let offset = usize::try_from(offset).ok()?;
+
+        if offset >= self.uncompressed_len {
+            return None;
+        }
+
+        let upos = u16::try_from(offset).ok()?;
+        VirtualPosition::try_from((self.virtual_position.compressed(), upos)).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() -> Result<(), Box<dyn std::error::Error>> {
+        let block_position = BlockPosition::new(VirtualPosition::try_from((35, 0))?, 7, 4);
+
+        assert_eq!(block_position.resolve(6), None);
+        assert_eq!(
+            block_position.resolve(7),
+            Some(VirtualPosition::try_from((35, 0))?)
+        );
+        assert_eq!(
+            block_position.resolve(10),
+            Some(VirtualPosition::try_from((35, 3))?)
+        );
+        assert_eq!(block_position.resolve(11), None);
+
+        Ok(())
+    }
+}
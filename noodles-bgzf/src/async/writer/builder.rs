@@ -1,8 +1,8 @@
-use std::{num::NonZeroUsize, thread};
+use std::{num::NonZeroUsize, sync::Arc, thread};
 
 use bytes::{Bytes, BytesMut};
 use futures::SinkExt;
-use tokio::io::AsyncWrite;
+use tokio::{io::AsyncWrite, sync::Semaphore};
 use tokio_util::codec::FramedWrite;
 
 use super::{Deflater, Writer};
@@ -16,6 +16,8 @@ use crate::{
 pub struct Builder {
     compression_level: Option<CompressionLevel>,
     worker_count: Option<NonZeroUsize>,
+    queue_depth: Option<NonZeroUsize>,
+    track_block_positions: bool,
 }
 
 impl Builder {
@@ -38,6 +40,8 @@ impl Builder {
 
     /// Sets a worker count.
     ///
+    /// This is the maximum number of blocks that are compressed concurrently.
+    ///
     /// By default, the worker count is set to the number of available logical CPUs.
     ///
     /// # Examples
@@ -57,6 +61,47 @@ impl Builder {
         self
     }
 
+    /// Sets the queue depth.
+    ///
+    /// This is the maximum number of blocks that are queued to be compressed or written. When the
+    /// queue is full, writes wait until a queued block is written to the underlying writer.
+    ///
+    /// By default, the queue depth is set to the worker count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let queue_depth = NonZeroUsize::try_from(16)?;
+    /// let builder = bgzf::r#async::writer::Builder::default()
+    ///     .set_queue_depth(queue_depth);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_queue_depth(mut self, queue_depth: NonZeroUsize) -> Self {
+        self.queue_depth = Some(queue_depth);
+        self
+    }
+
+    /// Sets whether to track the positions of written blocks.
+    ///
+    /// The block positions are retrieved using [`Writer::take_block_positions`].
+    ///
+    /// By default, block positions are not tracked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::r#async::writer::Builder::default().set_track_block_positions(true);
+    /// ```
+    pub fn set_track_block_positions(mut self, track_block_positions: bool) -> Self {
+        self.track_block_positions = track_block_positions;
+        self
+    }
+
     /// Builds an async BGZF writer.
     ///
     /// # Examples
@@ -77,11 +122,19 @@ impl Builder {
             thread::available_parallelism().unwrap_or_else(|_| NonZeroUsize::new(1).unwrap())
         });
 
+        let queue_depth = self.queue_depth.unwrap_or(worker_count);
+
+        let deflater = Deflater::new(
+            FramedWrite::new(writer, BlockCodec),
+            self.track_block_positions,
+        );
+
         Writer {
-            sink: Deflater::new(FramedWrite::new(writer, BlockCodec)).buffer(worker_count.get()),
+            sink: deflater.buffer(queue_depth.get()),
             buf: BytesMut::with_capacity(MAX_BUF_SIZE),
             eof_buf: Bytes::from_static(BGZF_EOF),
             compression_level: compression_level.into(),
+            workers: Arc::new(Semaphore::new(worker_count.get())),
         }
     }
 }
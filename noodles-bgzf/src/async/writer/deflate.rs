@@ -2,12 +2,13 @@ use std::{
     future::Future,
     io,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
 use bytes::BytesMut;
 use pin_project_lite::pin_project;
-use tokio::task::JoinHandle;
+use tokio::{sync::Semaphore, task::JoinHandle};
 
 use super::CompressionLevel;
 
@@ -22,9 +23,22 @@ pin_project! {
 }
 
 impl Deflate {
-    pub fn new(data: BytesMut, compression_level: CompressionLevel) -> Self {
+    // The semaphore limits the number of blocks that are compressed concurrently, i.e., the
+    // number of workers.
+    pub fn new(
+        data: BytesMut,
+        compression_level: CompressionLevel,
+        workers: Arc<Semaphore>,
+    ) -> Self {
         Self {
-            handle: tokio::task::spawn_blocking(move || deflate(data, compression_level)),
+            handle: tokio::spawn(async move {
+                let _permit = workers
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+                tokio::task::spawn_blocking(move || deflate(data, compression_level)).await?
+            }),
         }
     }
 }
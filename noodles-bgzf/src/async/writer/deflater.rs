@@ -10,9 +10,9 @@ use pin_project_lite::pin_project;
 use tokio::io::AsyncWrite;
 use tokio_util::codec::FramedWrite;
 
-use crate::r#async::BlockCodec;
+use crate::{gz, r#async::BlockCodec, VirtualPosition, BGZF_HEADER_SIZE};
 
-use super::Deflate;
+use super::{BlockPosition, Deflate};
 
 pin_project! {
     pub struct Deflater<W> {
@@ -20,6 +20,9 @@ pin_project! {
         sink: FramedWrite<W, BlockCodec>,
         #[pin]
         state: Option<Deflate>,
+        position: u64,
+        uncompressed_position: u64,
+        block_positions: Option<Vec<BlockPosition>>,
     }
 }
 
@@ -27,8 +30,18 @@ impl<W> Deflater<W>
 where
     W: AsyncWrite,
 {
-    pub fn new(sink: FramedWrite<W, BlockCodec>) -> Self {
-        Self { sink, state: None }
+    pub fn new(sink: FramedWrite<W, BlockCodec>, track_block_positions: bool) -> Self {
+        Self {
+            sink,
+            state: None,
+            position: 0,
+            uncompressed_position: 0,
+            block_positions: track_block_positions.then(Vec::new),
+        }
+    }
+
+    pub fn block_positions_mut(&mut self) -> Option<&mut Vec<BlockPosition>> {
+        self.block_positions.as_mut()
     }
 
     pub fn get_mut(&mut self) -> &mut W {
@@ -48,8 +61,28 @@ where
         };
 
         this.state.set(None);
+
+        let (cdata, _, r#isize) = &data;
+        let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
+        let uncompressed_len =
+            usize::try_from(*r#isize).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if let Some(block_positions) = this.block_positions.as_mut() {
+            let virtual_position = VirtualPosition::try_from((*this.position, 0))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            block_positions.push(BlockPosition::new(
+                virtual_position,
+                *this.uncompressed_position,
+                uncompressed_len,
+            ));
+        }
+
         this.sink.start_send(data)?;
 
+        *this.position += block_size as u64;
+        *this.uncompressed_position += uncompressed_len as u64;
+
         Poll::Ready(Ok(()))
     }
 }
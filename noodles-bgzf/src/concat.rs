@@ -0,0 +1,298 @@
+//! BGZF block-level concatenation.
+//!
+//! BGZF streams can be concatenated by copying their raw compressed blocks, i.e., without
+//! inflating and deflating the block data again. Only the EOF markers of the inputs are removed,
+//! and a single EOF marker is appended to the output.
+
+use std::{
+    io::{self, Read, Write},
+    mem,
+};
+
+use super::{
+    gzi,
+    virtual_position::{self, TryFromU64U16TupleError},
+    VirtualPosition,
+};
+
+/// The start of a concatenated input in the output stream.
+///
+/// This is used to remap positions of an input, e.g., from its CSI or GZ index, to positions in
+/// the concatenated output.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Offset {
+    compressed: u64,
+    uncompressed: u64,
+}
+
+impl Offset {
+    /// Returns the compressed position of the start of the input in the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::concat::Offset;
+    /// let offset = Offset::default();
+    /// assert_eq!(offset.compressed(), 0);
+    /// ```
+    pub fn compressed(&self) -> u64 {
+        self.compressed
+    }
+
+    /// Returns the uncompressed position of the start of the input in the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::concat::Offset;
+    /// let offset = Offset::default();
+    /// assert_eq!(offset.uncompressed(), 0);
+    /// ```
+    pub fn uncompressed(&self) -> u64 {
+        self.uncompressed
+    }
+
+    /// Shifts a virtual position of the input to its virtual position in the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, concat::Offset};
+    /// let offset = Offset::default();
+    /// let virtual_position = bgzf::VirtualPosition::from(8);
+    /// assert_eq!(offset.shift_virtual_position(virtual_position), Ok(virtual_position));
+    /// ```
+    pub fn shift_virtual_position(
+        &self,
+        pos: VirtualPosition,
+    ) -> Result<VirtualPosition, TryFromU64U16TupleError> {
+        let (cpos, upos) = pos.into();
+
+        let shifted_cpos = cpos
+            .checked_add(self.compressed)
+            .filter(|&n| n <= virtual_position::MAX_COMPRESSED_POSITION)
+            .ok_or(TryFromU64U16TupleError::CompressedPositionOverflow)?;
+
+        VirtualPosition::try_from((shifted_cpos, upos))
+    }
+
+    /// Shifts the entries of a gzip index of the input to positions in the output stream.
+    ///
+    /// The shifted indices of all inputs can be concatenated, in order, to build the gzip index of
+    /// the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::concat::Offset;
+    /// let offset = Offset::default();
+    /// let index = vec![(0, 0), (35, 7)];
+    /// assert_eq!(offset.shift_gzi_index(&index), index);
+    /// ```
+    pub fn shift_gzi_index(&self, index: &gzi::Index) -> gzi::Index {
+        index
+            .iter()
+            .map(|&(cpos, upos)| (cpos + self.compressed, upos + self.uncompressed))
+            .collect()
+    }
+}
+
+/// Concatenates BGZF streams by copying their raw compressed blocks.
+///
+/// The inputs are read in order. The blocks of each input are copied to the writer as-is, except
+/// for the trailing empty blocks of each input (i.e., its EOF marker), which are discarded. A
+/// single EOF marker is written after the last input.
+///
+/// This returns the start positions of each input in the output stream, which can be used to
+/// remap positions from the indices of the inputs.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let a = writer.finish()?;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"-bgzf")?;
+/// let b = writer.finish()?;
+///
+/// let mut dst = Vec::new();
+/// let offsets = bgzf::concat::concatenate([&a[..], &b[..]], &mut dst)?;
+/// assert_eq!(offsets[1].uncompressed(), 7);
+///
+/// let mut reader = bgzf::Reader::new(&dst[..]);
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles-bgzf");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn concatenate<I, R, W>(readers: I, writer: &mut W) -> io::Result<Vec<Offset>>
+where
+    I: IntoIterator<Item = R>,
+    R: Read,
+    W: Write,
+{
+    use super::{
+        reader::block::{parse_header, read_frame_into},
+        writer::BGZF_EOF,
+        BGZF_HEADER_SIZE,
+    };
+
+    let mut offsets = Vec::new();
+    let mut offset = Offset::default();
+
+    let mut buf = Vec::new();
+    let mut pending_empty_blocks = Vec::new();
+
+    for mut reader in readers {
+        offsets.push(offset);
+        pending_empty_blocks.clear();
+
+        while read_frame_into(&mut reader, &mut buf)?.is_some() {
+            parse_header(&buf[..BGZF_HEADER_SIZE])?;
+
+            if read_isize(&buf) == 0 {
+                pending_empty_blocks.extend_from_slice(&buf);
+                continue;
+            }
+
+            if !pending_empty_blocks.is_empty() {
+                writer.write_all(&pending_empty_blocks)?;
+                offset.compressed += pending_empty_blocks.len() as u64;
+                pending_empty_blocks.clear();
+            }
+
+            writer.write_all(&buf)?;
+
+            offset.compressed += buf.len() as u64;
+            offset.uncompressed += u64::from(read_isize(&buf));
+        }
+    }
+
+    writer.write_all(BGZF_EOF)?;
+
+    Ok(offsets)
+}
+
+fn read_isize(frame: &[u8]) -> u32 {
+    // SAFETY: `frame` is at least `BGZF_HEADER_SIZE + gz::TRAILER_SIZE` bytes.
+    let start = frame.len() - mem::size_of::<u32>();
+    let buf: [u8; 4] = frame[start..].try_into().unwrap();
+    u32::from_le_bytes(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{writer::BGZF_EOF, Reader, Writer};
+
+    fn build_stream(data: &[&[u8]]) -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+
+        for buf in data {
+            writer.write_all(buf)?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    #[test]
+    fn test_concatenate() -> io::Result<()> {
+        let a = build_stream(&[b"noodles", b"-"])?;
+        let b = build_stream(&[b"bgzf"])?;
+        let c = build_stream(&[])?;
+
+        let mut dst = Vec::new();
+        let offsets = concatenate([&a[..], &b[..], &c[..]], &mut dst)?;
+
+        let a_len = (a.len() - BGZF_EOF.len()) as u64;
+        let b_len = (b.len() - BGZF_EOF.len()) as u64;
+
+        assert_eq!(
+            offsets,
+            [
+                Offset::default(),
+                Offset {
+                    compressed: a_len,
+                    uncompressed: 8
+                },
+                Offset {
+                    compressed: a_len + b_len,
+                    uncompressed: 12
+                },
+            ]
+        );
+
+        assert_eq!(dst.len() as u64, a_len + b_len + BGZF_EOF.len() as u64);
+        assert!(dst.ends_with(BGZF_EOF));
+
+        let mut reader = Reader::new(&dst[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concatenate_with_empty_block_before_data() -> io::Result<()> {
+        let mut a = BGZF_EOF.to_vec();
+        a.extend(build_stream(&[b"noodles"])?);
+
+        let mut dst = Vec::new();
+        concatenate([&a[..]], &mut dst)?;
+
+        assert_eq!(dst, a);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concatenate_with_invalid_header() {
+        let mut data = BGZF_EOF.to_vec();
+        data[0] = 0x00;
+
+        let mut dst = Vec::new();
+
+        assert!(matches!(
+            concatenate([&data[..]], &mut dst),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_shift_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
+        let offset = Offset {
+            compressed: 35,
+            uncompressed: 7,
+        };
+
+        assert_eq!(
+            offset.shift_virtual_position(VirtualPosition::try_from((8, 3))?),
+            VirtualPosition::try_from((43, 3))
+        );
+
+        assert_eq!(
+            offset.shift_virtual_position(VirtualPosition::MAX),
+            Err(TryFromU64U16TupleError::CompressedPositionOverflow)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shift_gzi_index() {
+        let offset = Offset {
+            compressed: 35,
+            uncompressed: 7,
+        };
+
+        let index = vec![(0, 0), (21, 4)];
+        assert_eq!(offset.shift_gzi_index(&index), [(35, 7), (56, 11)]);
+    }
+}
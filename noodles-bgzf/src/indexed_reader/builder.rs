@@ -25,6 +25,16 @@ impl Builder {
         self
     }
 
+    /// Sets the capacity of the block cache.
+    ///
+    /// See [`reader::Builder::set_block_cache_capacity`].
+    ///
+    /// By default, the block cache is disabled.
+    pub fn set_block_cache_capacity(mut self, capacity: NonZeroUsize) -> Self {
+        self.reader_builder = self.reader_builder.set_block_cache_capacity(capacity);
+        self
+    }
+
     /// Sets a GZ index.
     pub fn set_index(mut self, index: gzi::Index) -> Self {
         self.index = Some(index);
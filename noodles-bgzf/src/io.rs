@@ -0,0 +1,6 @@
+//! BGZF I/O traits.
+
+mod buf_read;
+mod seek;
+
+pub use self::{buf_read::BufRead, seek::Seek};
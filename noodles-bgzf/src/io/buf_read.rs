@@ -0,0 +1,26 @@
+use std::io::{self, Read};
+
+use crate::{MultithreadedReader, Reader, VirtualPosition};
+
+/// A BGZF reader.
+///
+/// This is a buffered reader that tracks the virtual position of the stream.
+pub trait BufRead: io::BufRead {
+    /// Returns the current virtual position of the stream.
+    fn virtual_position(&self) -> VirtualPosition;
+}
+
+impl<R> BufRead for Reader<R>
+where
+    R: Read,
+{
+    fn virtual_position(&self) -> VirtualPosition {
+        self.virtual_position()
+    }
+}
+
+impl<R> BufRead for MultithreadedReader<R> {
+    fn virtual_position(&self) -> VirtualPosition {
+        self.virtual_position()
+    }
+}
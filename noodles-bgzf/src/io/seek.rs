@@ -0,0 +1,27 @@
+use std::io::{self, Read};
+
+use crate::{MultithreadedReader, Reader, VirtualPosition};
+
+/// A BGZF reader that can seek to a virtual position.
+pub trait Seek {
+    /// Seeks the stream to the given virtual position.
+    fn seek_to_virtual_position(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition>;
+}
+
+impl<R> Seek for Reader<R>
+where
+    R: Read + io::Seek,
+{
+    fn seek_to_virtual_position(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        self.seek(pos)
+    }
+}
+
+impl<R> Seek for MultithreadedReader<R>
+where
+    R: Read + io::Seek + Send + 'static,
+{
+    fn seek_to_virtual_position(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        self.seek(pos)
+    }
+}
@@ -38,12 +38,16 @@
 pub mod r#async;
 
 mod block;
+pub mod concat;
 mod gz;
 pub mod gzi;
 pub mod indexed_reader;
+pub mod io;
 mod multithreaded_reader;
-mod multithreaded_writer;
+pub mod multithreaded_writer;
+pub mod partition;
 pub mod reader;
+pub mod validation;
 pub mod virtual_position;
 pub mod writer;
 
@@ -1,5 +1,5 @@
 use std::{
-    io::{self, BufRead, Read},
+    io::{self, BufRead, Read, Seek, SeekFrom},
     mem,
     num::NonZeroUsize,
     thread::{self, JoinHandle},
@@ -7,7 +7,7 @@ use std::{
 
 use crossbeam_channel::{Receiver, Sender};
 
-use crate::{Block, VirtualPosition};
+use crate::{gzi, Block, VirtualPosition};
 
 type BufferedTx = Sender<io::Result<Buffer>>;
 type BufferedRx = Receiver<io::Result<Buffer>>;
@@ -29,8 +29,12 @@ struct Buffer {
 /// This is a basic multithreaded BGZF reader that uses a thread pool to decompress block data. It
 /// differs from a [`super::Reader`] with > 1 worker by placing the inner reader on its own thread
 /// to read the raw frames asynchronously.
-#[doc(hidden)]
+///
+/// When the inner reader is seekable, the reader can be repositioned using
+/// [`Self::seek`] or [`Self::seek_by_uncompressed_position`]. This drains and restarts the read and
+/// inflate workers.
 pub struct MultithreadedReader<R> {
+    worker_count: NonZeroUsize,
     reader_handle: Option<JoinHandle<io::Result<R>>>,
     inflater_handles: Vec<JoinHandle<()>>,
     read_rx: ReadRx,
@@ -51,14 +55,20 @@ impl<R> MultithreadedReader<R> {
     }
 
     /// Shuts down the reader and inflate workers.
+    ///
+    /// This returns an error if the reader was already finished.
     pub fn finish(&mut self) -> io::Result<R> {
+        let handle = self
+            .reader_handle
+            .take()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "reader already finished"))?;
+
         self.recycle_tx.take();
 
         for handle in self.inflater_handles.drain(..) {
             handle.join().unwrap();
         }
 
-        let handle = self.reader_handle.take().unwrap();
         let inner = handle.join().unwrap()?;
 
         Ok(inner)
@@ -75,6 +85,10 @@ impl<R> MultithreadedReader<R> {
     }
 
     fn read_block(&mut self) -> io::Result<()> {
+        if self.reader_handle.is_none() {
+            return Err(io::Error::new(io::ErrorKind::Other, "reader finished"));
+        }
+
         while let Some(mut buffer) = self.recv_buffer()? {
             buffer.block.set_position(self.position);
             self.position += buffer.block.size();
@@ -97,18 +111,10 @@ where
 {
     /// Creates a multithreaded BGZF reader.
     pub fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
-        let (inflate_tx, inflate_rx) = crossbeam_channel::bounded(worker_count.get());
-        let (read_tx, read_rx) = crossbeam_channel::bounded(worker_count.get());
-        let (recycle_tx, recycle_rx) = crossbeam_channel::bounded(worker_count.get());
-
-        for _ in 0..worker_count.get() {
-            recycle_tx.send(Buffer::default()).unwrap();
-        }
-
-        let reader_handle = spawn_reader(inner, inflate_tx, read_tx, recycle_rx);
-        let inflater_handles = spawn_inflaters(worker_count, inflate_rx);
+        let (read_rx, recycle_tx, reader_handle, inflater_handles) = spawn(worker_count, inner);
 
         Self {
+            worker_count,
             reader_handle: Some(reader_handle),
             inflater_handles,
             read_rx,
@@ -117,11 +123,119 @@ where
             buffer: Buffer::default(),
         }
     }
+
+    fn restart(&mut self, inner: R, position: u64) {
+        let (read_rx, recycle_tx, reader_handle, inflater_handles) =
+            spawn(self.worker_count, inner);
+
+        self.reader_handle = Some(reader_handle);
+        self.inflater_handles = inflater_handles;
+        self.read_rx = read_rx;
+        self.recycle_tx = Some(recycle_tx);
+        self.position = position;
+    }
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Seek + Send + 'static,
+{
+    /// Seeks the stream to the given virtual position.
+    ///
+    /// The read and inflate workers are first shut down, discarding any blocks that were read
+    /// ahead. The underlying stream's cursor is then moved to the compressed position, the workers
+    /// are restarted, and the first block has its own cursor moved to the uncompressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut reader =
+    ///     bgzf::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, Cursor::new(data));
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::default();
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        let (cpos, upos) = pos.into();
+
+        self.seek_to_block(cpos)?;
+
+        self.buffer.block.data_mut().set_position(usize::from(upos));
+
+        Ok(pos)
+    }
+
+    /// Seeks the stream to the given uncompressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut reader =
+    ///     bgzf::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, Cursor::new(data));
+    ///
+    /// let index = vec![(0, 0)];
+    /// reader.seek_by_uncompressed_position(&index, 0)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek_by_uncompressed_position(
+        &mut self,
+        index: &gzi::Index,
+        pos: u64,
+    ) -> io::Result<u64> {
+        assert!(!index.is_empty());
+
+        let i = index.partition_point(|r| r.1 <= pos);
+        // SAFETY: `i` is > 0.
+        let record = index[i - 1];
+
+        let cpos = record.0;
+        self.seek_to_block(cpos)?;
+
+        let upos = usize::try_from(pos - record.1)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.buffer.block.data_mut().set_position(upos);
+
+        Ok(pos)
+    }
+
+    // Shuts down the workers, moves the underlying stream's cursor to the given compressed
+    // position, restarts the workers, and reads the block at that position.
+    //
+    // If moving the cursor fails, the workers are restarted at the block following the current
+    // block, which is kept, before the error is returned, so the reader remains usable. If the
+    // workers cannot be restarted, subsequent reads return an error.
+    fn seek_to_block(&mut self, cpos: u64) -> io::Result<()> {
+        let mut inner = self.finish()?;
+
+        if let Err(e) = inner.seek(SeekFrom::Start(cpos)) {
+            inner.seek(SeekFrom::Start(self.position))?;
+            self.restart(inner, self.position);
+            return Err(e);
+        }
+
+        self.restart(inner, cpos);
+        self.buffer.block = Block::default();
+
+        self.read_block()
+    }
 }
 
 impl<R> Drop for MultithreadedReader<R> {
     fn drop(&mut self) {
-        let _ = self.finish();
+        if self.reader_handle.is_some() {
+            let _ = self.finish();
+        }
     }
 }
 
@@ -148,6 +262,32 @@ impl<R> BufRead for MultithreadedReader<R> {
     }
 }
 
+fn spawn<R>(
+    worker_count: NonZeroUsize,
+    inner: R,
+) -> (
+    ReadRx,
+    RecycleTx,
+    JoinHandle<io::Result<R>>,
+    Vec<JoinHandle<()>>,
+)
+where
+    R: Read + Send + 'static,
+{
+    let (inflate_tx, inflate_rx) = crossbeam_channel::bounded(worker_count.get());
+    let (read_tx, read_rx) = crossbeam_channel::bounded(worker_count.get());
+    let (recycle_tx, recycle_rx) = crossbeam_channel::bounded(worker_count.get());
+
+    for _ in 0..worker_count.get() {
+        recycle_tx.send(Buffer::default()).unwrap();
+    }
+
+    let reader_handle = spawn_reader(inner, inflate_tx, read_tx, recycle_rx);
+    let inflater_handles = spawn_inflaters(worker_count, inflate_rx);
+
+    (read_rx, recycle_tx, reader_handle, inflater_handles)
+}
+
 fn spawn_reader<R>(
     mut reader: R,
     inflate_tx: InflateTx,
@@ -191,3 +331,184 @@ fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<Joi
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+
+    #[test]
+    fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodlesbgzf");
+
+        let eof = reader.virtual_position();
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"dlesbgzf");
+        assert_eq!(reader.virtual_position(), eof);
+
+        let position = reader.seek(VirtualPosition::try_from((0, 3))?)?;
+        assert_eq!(reader.virtual_position(), position);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_with_failed_inner_seek() -> Result<(), Box<dyn std::error::Error>> {
+        struct BoundedReader(Cursor<Vec<u8>>);
+
+        impl Read for BoundedReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0.read(buf)
+            }
+        }
+
+        impl Seek for BoundedReader {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                match pos {
+                    SeekFrom::Start(n) if n > self.0.get_ref().len() as u64 => Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "position out of bounds",
+                    )),
+                    _ => self.0.seek(pos),
+                }
+            }
+        }
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader =
+            MultithreadedReader::with_worker_count(worker_count, BoundedReader(Cursor::new(data)));
+
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"noo");
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((1 << 20, 0))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"dlesbgzf");
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"dlesbgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_with_failed_read_ahead() -> Result<(), Box<dyn std::error::Error>> {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+        }
+
+        impl Seek for FailingReader {
+            fn seek(&mut self, _: SeekFrom) -> io::Result<u64> {
+                Ok(0)
+            }
+        }
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, FailingReader);
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::default()),
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe
+        ));
+
+        let mut buf = Vec::new();
+        assert!(reader.read_to_end(&mut buf).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_after_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let data = crate::Writer::new(Vec::new()).finish()?;
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+
+        reader.finish()?;
+
+        assert!(reader.finish().is_err());
+        assert!(reader.seek(VirtualPosition::default()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_by_uncompressed_position() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+
+        let index = vec![(0, 0), (35, 7)];
+
+        reader.seek_by_uncompressed_position(&index, 3)?;
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"dles");
+
+        reader.seek_by_uncompressed_position(&index, 8)?;
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"gz");
+
+        Ok(())
+    }
+}
@@ -1,3 +1,9 @@
+//! Multithreaded BGZF writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
     io::{self, Write},
     num::NonZeroUsize,
@@ -7,10 +13,11 @@ use std::{
 use bytes::{BufMut, Bytes, BytesMut};
 use crossbeam_channel::{Receiver, Sender};
 
-use super::gz;
+use super::{gz, gzi};
 
-type BufferedTx = Sender<io::Result<Vec<u8>>>;
-type BufferedRx = Receiver<io::Result<Vec<u8>>>;
+// A compressed frame and the length of its uncompressed data.
+type BufferedTx = Sender<io::Result<(Vec<u8>, usize)>>;
+type BufferedRx = Receiver<io::Result<(Vec<u8>, usize)>>;
 type DeflateTx = Sender<(Bytes, BufferedTx)>;
 type DeflateRx = Receiver<(Bytes, BufferedTx)>;
 type WriteTx = Sender<BufferedRx>;
@@ -20,11 +27,12 @@ type WriteRx = Receiver<BufferedRx>;
 ///
 /// This is much more basic than [`super::Writer`] but uses a thread pool to compress block data.
 pub struct MultithreadedWriter {
-    writer_handle: Option<JoinHandle<io::Result<()>>>,
+    writer_handle: Option<JoinHandle<io::Result<Option<gzi::Index>>>>,
     deflater_handles: Vec<JoinHandle<()>>,
     buf: BytesMut,
     write_tx: Option<WriteTx>,
     deflate_tx: Option<DeflateTx>,
+    gzi_index: Option<gzi::Index>,
 }
 
 impl MultithreadedWriter {
@@ -33,19 +41,34 @@ impl MultithreadedWriter {
     where
         W: Write + Send + 'static,
     {
-        let (write_tx, write_rx) = crossbeam_channel::bounded(worker_count.get());
-        let (deflate_tx, deflate_rx) = crossbeam_channel::bounded(worker_count.get());
-
-        let writer_handle = spawn_writer(inner, write_rx);
-        let deflater_handles = spawn_deflaters(worker_count, deflate_rx);
-
-        Self {
-            writer_handle: Some(writer_handle),
-            deflater_handles,
-            buf: BytesMut::new(),
-            write_tx: Some(write_tx),
-            deflate_tx: Some(deflate_tx),
-        }
+        Builder::default()
+            .set_worker_count(worker_count)
+            .build_from_writer(inner)
+    }
+
+    /// Returns the gzip index built from the written blocks.
+    ///
+    /// This is only available after the writer is finished and if it was built with GZ index
+    /// building enabled (see [`Builder::set_build_gzi_index`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_build_gzi_index(true)
+    ///     .build_from_writer(io::sink());
+    ///
+    /// writer.write_all(b"noodles-bgzf")?;
+    /// writer.finish()?;
+    ///
+    /// assert_eq!(writer.gzi_index(), Some(&vec![(0, 0)]));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn gzi_index(&self) -> Option<&gzi::Index> {
+        self.gzi_index.as_ref()
     }
 
     /// Finishes the output stream by flushing any remaining buffers.
@@ -63,7 +86,7 @@ impl MultithreadedWriter {
         self.write_tx.take();
 
         if let Some(handle) = self.writer_handle.take() {
-            handle.join().unwrap()?;
+            self.gzi_index = handle.join().unwrap()?;
         }
 
         Ok(())
@@ -115,23 +138,41 @@ impl Write for MultithreadedWriter {
     }
 }
 
-fn spawn_writer<W>(mut writer: W, write_rx: WriteRx) -> JoinHandle<io::Result<()>>
+fn spawn_writer<W>(
+    mut writer: W,
+    write_rx: WriteRx,
+    build_gzi_index: bool,
+) -> JoinHandle<io::Result<Option<gzi::Index>>>
 where
     W: Write + Send + 'static,
 {
     use super::writer::BGZF_EOF;
 
     thread::spawn(move || {
+        let mut gzi_index = build_gzi_index.then(|| vec![(0, 0)]);
+        let mut position = 0;
+        let mut uncompressed_position = 0;
+
         while let Ok(buffered_rx) = write_rx.recv() {
             if let Ok(result) = buffered_rx.recv() {
-                let buf = result?;
+                let (buf, uncompressed_len) = result?;
+
+                if let Some(index) = gzi_index.as_mut() {
+                    if position > 0 {
+                        index.push((position, uncompressed_position));
+                    }
+                }
+
                 writer.write_all(&buf[..])?;
+
+                position += buf.len() as u64;
+                uncompressed_position += uncompressed_len as u64;
             }
         }
 
         writer.write_all(BGZF_EOF)?;
 
-        Ok(())
+        Ok(gzi_index)
     })
 }
 
@@ -142,7 +183,7 @@ fn spawn_deflaters(worker_count: NonZeroUsize, deflate_rx: DeflateRx) -> Vec<Joi
 
             thread::spawn(move || {
                 while let Ok((src, buffered_tx)) = deflate_rx.recv() {
-                    let result = compress(&src);
+                    let result = compress(&src).map(|buf| (buf, src.len()));
                     buffered_tx.send(result).ok();
                 }
             })
@@ -210,3 +251,30 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gzi_index() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .set_build_gzi_index(true)
+            .build_from_writer(io::sink());
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        writer.finish()?;
+
+        let mut expected_writer = crate::Writer::new(io::sink());
+        expected_writer.write_all(b"noodles")?;
+        expected_writer.flush()?;
+        let position = expected_writer.position();
+
+        assert_eq!(writer.gzi_index(), Some(&vec![(0, 0), (position, 7)]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::{io::Write, num::NonZeroUsize, thread};
+
+use bytes::BytesMut;
+
+use super::{spawn_deflaters, spawn_writer, MultithreadedWriter};
+
+const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
+    Some(worker_count) => worker_count,
+    None => unreachable!(),
+};
+
+/// A multithreaded BGZF writer builder.
+#[derive(Debug)]
+pub struct Builder {
+    worker_count: NonZeroUsize,
+    build_gzi_index: bool,
+}
+
+impl Builder {
+    /// Sets the worker count.
+    ///
+    /// By default, the worker count is set to the available parallelism of the system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let builder = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_worker_count(worker_count);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets whether to build a gzip index (GZI) while writing.
+    ///
+    /// When enabled, the compressed and uncompressed positions of each block are recorded as it
+    /// is written. The resulting index can be retrieved using [`MultithreadedWriter::gzi_index`]
+    /// after the writer is finished.
+    ///
+    /// By default, building a gzip index is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::multithreaded_writer::Builder::default().set_build_gzi_index(true);
+    /// ```
+    pub fn set_build_gzi_index(mut self, build_gzi_index: bool) -> Self {
+        self.build_gzi_index = build_gzi_index;
+        self
+    }
+
+    /// Builds a multithreaded BGZF writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::multithreaded_writer::Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> MultithreadedWriter
+    where
+        W: Write + Send + 'static,
+    {
+        let worker_count = self.worker_count;
+
+        let (write_tx, write_rx) = crossbeam_channel::bounded(worker_count.get());
+        let (deflate_tx, deflate_rx) = crossbeam_channel::bounded(worker_count.get());
+
+        let writer_handle = spawn_writer(writer, write_rx, self.build_gzi_index);
+        let deflater_handles = spawn_deflaters(worker_count, deflate_rx);
+
+        MultithreadedWriter {
+            writer_handle: Some(writer_handle),
+            deflater_handles,
+            buf: BytesMut::new(),
+            write_tx: Some(write_tx),
+            deflate_tx: Some(deflate_tx),
+            gzi_index: None,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        let worker_count = thread::available_parallelism().unwrap_or(DEFAULT_WORKER_COUNT);
+
+        Self {
+            worker_count,
+            build_gzi_index: false,
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! BGZF partitioning.
+//!
+//! A BGZF stream can be split into byte ranges that are aligned to block boundaries without an
+//! index. Each range starts at the start of a block, i.e., at a virtual position with an
+//! uncompressed position of 0, and ends at the start of the next range. This allows independent
+//! readers, e.g., on separate threads, to each process a portion of a single stream.
+//!
+//! Block boundaries are not necessarily record boundaries. Format readers are expected to resync
+//! to the first record that starts in a range and read records until the end of the range.
+
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+    ops::Range,
+};
+
+use bytes::Buf;
+
+use super::{VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+
+// The size of the window to search for a block start. A window of two max block sizes
+// guarantees it includes both the header of a block and the header of the block following it.
+const WINDOW_SIZE: usize = 2 * BGZF_MAX_ISIZE + BGZF_HEADER_SIZE;
+
+/// Splits a BGZF stream into up to `count` ranges aligned to block boundaries.
+///
+/// The stream is split into ranges of approximately equal compressed sizes. The first range
+/// starts at the beginning of the stream, and the last range ends at the end of the stream. Fewer
+/// ranges may be returned if the stream has fewer blocks than the requested count.
+///
+/// Block starts are found by searching for a valid BGZF header that is followed by another valid
+/// BGZF header (or the end of the stream).
+///
+/// The position of the stream is undefined after partitioning.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor, Write};
+/// use std::num::NonZeroUsize;
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// writer.flush()?;
+/// writer.write_all(b"bgzf")?;
+/// let data = writer.finish()?;
+///
+/// let count = NonZeroUsize::try_from(2).unwrap();
+/// let partitions = bgzf::partition::partition(&mut Cursor::new(data), count)?;
+/// assert_eq!(partitions.len(), 2);
+/// assert_eq!(partitions[0].start, bgzf::VirtualPosition::default());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn partition<R>(reader: &mut R, count: NonZeroUsize) -> io::Result<Vec<Range<VirtualPosition>>>
+where
+    R: Read + Seek,
+{
+    let len = reader.seek(SeekFrom::End(0))?;
+
+    let mut starts = vec![0];
+
+    for i in 1..count.get() {
+        let target = u64::try_from(u128::from(len) * i as u128 / count.get() as u128)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        // SAFETY: `starts` is never empty.
+        let prev_start = *starts.last().unwrap();
+
+        if target <= prev_start {
+            continue;
+        }
+
+        if let Some(start) = find_block_start(reader, target, len)? {
+            if start > prev_start && start < len {
+                starts.push(start);
+            }
+        }
+    }
+
+    starts.push(len);
+
+    starts
+        .windows(2)
+        .map(|positions| {
+            let start = build_virtual_position(positions[0])?;
+            let end = build_virtual_position(positions[1])?;
+            Ok(start..end)
+        })
+        .collect()
+}
+
+fn build_virtual_position(compressed_position: u64) -> io::Result<VirtualPosition> {
+    VirtualPosition::try_from((compressed_position, 0))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+// Returns the position of the first block start at or after `position`.
+fn find_block_start<R>(reader: &mut R, position: u64, len: u64) -> io::Result<Option<u64>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(position))?;
+
+    let mut buf = Vec::with_capacity(WINDOW_SIZE);
+    reader.take(WINDOW_SIZE as u64).read_to_end(&mut buf)?;
+
+    for i in 0..buf.len() {
+        let block_size = match read_block_size(&buf[i..]) {
+            Some(block_size) => block_size,
+            None => continue,
+        };
+
+        let next_start = i + block_size;
+
+        if position + next_start as u64 == len
+            || (next_start < buf.len() && read_block_size(&buf[next_start..]).is_some())
+        {
+            return Ok(Some(position + i as u64));
+        }
+    }
+
+    Ok(None)
+}
+
+// Returns the block size (`BSIZE` + 1) if `src` starts with a valid BGZF header.
+fn read_block_size(src: &[u8]) -> Option<usize> {
+    use super::{gz, reader::block::is_valid_header};
+
+    const BSIZE_POSITION: usize = 16;
+    const MIN_FRAME_SIZE: usize = BGZF_HEADER_SIZE + gz::TRAILER_SIZE;
+
+    if src.len() < BGZF_HEADER_SIZE || !is_valid_header(src) {
+        return None;
+    }
+
+    let block_size = usize::from((&src[BSIZE_POSITION..]).get_u16_le()) + 1;
+
+    if block_size < MIN_FRAME_SIZE {
+        None
+    } else {
+        Some(block_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::{writer::BGZF_EOF, Writer};
+
+    fn build_data() -> io::Result<(Vec<u8>, Vec<u64>)> {
+        let mut writer = Writer::new(Vec::new());
+        let mut block_starts = Vec::new();
+
+        for i in 0..8 {
+            block_starts.push(writer.position());
+            writer.write_all(format!("noodles-bgzf-{i}").as_bytes())?;
+            writer.flush()?;
+        }
+
+        writer.finish().map(|data| (data, block_starts))
+    }
+
+    #[test]
+    fn test_partition() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, block_starts) = build_data()?;
+        let len = data.len() as u64;
+
+        let partitions = partition(&mut Cursor::new(&data), NonZeroUsize::try_from(3)?)?;
+
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions[0].start, VirtualPosition::default());
+        assert_eq!(partitions[2].end, VirtualPosition::try_from((len, 0))?);
+
+        for window in partitions.windows(2) {
+            assert_eq!(window[0].end, window[1].start);
+        }
+
+        for range in &partitions {
+            assert!(block_starts.contains(&range.start.compressed()));
+            assert_eq!(range.start.uncompressed(), 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_with_more_partitions_than_blocks() -> Result<(), Box<dyn std::error::Error>> {
+        let partitions = partition(&mut Cursor::new(BGZF_EOF), NonZeroUsize::try_from(4)?)?;
+
+        let end = VirtualPosition::try_from((BGZF_EOF.len() as u64, 0))?;
+        assert_eq!(partitions, [VirtualPosition::default()..end]);
+
+        let partitions = partition(&mut Cursor::new(&[]), NonZeroUsize::try_from(4)?)?;
+        assert_eq!(
+            partitions,
+            [VirtualPosition::default()..VirtualPosition::default()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_block_start() -> io::Result<()> {
+        let (data, block_starts) = build_data()?;
+        let len = data.len() as u64;
+
+        let mut reader = Cursor::new(&data);
+
+        assert_eq!(find_block_start(&mut reader, 0, len)?, Some(0));
+        assert_eq!(
+            find_block_start(&mut reader, 1, len)?,
+            Some(block_starts[1])
+        );
+
+        let eof_start = len - BGZF_EOF.len() as u64;
+        assert_eq!(
+            find_block_start(&mut reader, block_starts[7] + 1, len)?,
+            Some(eof_start)
+        );
+        assert_eq!(find_block_start(&mut reader, eof_start + 1, len)?, None);
+
+        Ok(())
+    }
+}
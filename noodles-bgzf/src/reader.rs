@@ -1,12 +1,14 @@
 //! BGZF reader.
 
 pub(crate) mod block;
+mod block_cache;
 mod builder;
 
 pub use self::builder::Builder;
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
+use self::block_cache::BlockCache;
 use super::{gzi, Block, VirtualPosition};
 
 /// A BGZF reader.
@@ -30,6 +32,7 @@ pub struct Reader<R> {
     inner: block::Inner<R>,
     position: u64,
     block: Block,
+    block_cache: Option<BlockCache>,
 }
 
 impl<R> Reader<R>
@@ -143,6 +146,10 @@ where
     /// The underlying stream's cursor is first moved the the compressed position. A block is read,
     /// decompressed, and has its own cursor moved to the uncompressed position.
     ///
+    /// If the reader has a block cache (see [`Builder::set_block_cache_capacity`]) and the block at
+    /// the compressed position is cached, the cached block is used instead of reading and
+    /// decompressing it again.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -156,10 +163,7 @@ where
     pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
         let (cpos, upos) = pos.into();
 
-        self.inner.get_mut().seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
-
-        self.read_block()?;
+        self.seek_to_block(cpos)?;
 
         self.block.data_mut().set_position(usize::from(upos));
 
@@ -190,10 +194,7 @@ where
         let record = index[i - 1];
 
         let cpos = record.0;
-        self.inner.get_mut().seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
-
-        self.read_block()?;
+        self.seek_to_block(cpos)?;
 
         let upos = usize::try_from(pos - record.1)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
@@ -201,6 +202,28 @@ where
 
         Ok(pos)
     }
+
+    fn seek_to_block(&mut self, cpos: u64) -> io::Result<()> {
+        if let Some(block) = self.block_cache.as_mut().and_then(|cache| cache.get(cpos)) {
+            self.block.clone_from(block);
+            self.position = cpos + self.block.size();
+            self.inner.get_mut().seek(SeekFrom::Start(self.position))?;
+        } else {
+            self.inner.get_mut().seek(SeekFrom::Start(cpos))?;
+            self.position = cpos;
+            self.read_block()?;
+
+            // Only blocks that are seeked to are cached. Blocks read sequentially are typically
+            // not read again.
+            if let Some(block_cache) = self.block_cache.as_mut() {
+                if self.block.data().len() > 0 {
+                    block_cache.insert(&self.block);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<R> Read for Reader<R>
@@ -353,4 +376,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_with_block_cache() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Builder::default()
+            .set_block_cache_capacity(NonZeroUsize::try_from(1)?)
+            .build_from_reader(Cursor::new(data));
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+
+        // Invalidate block 0 in the underlying stream. It can then only be read from the cache.
+        reader.get_mut().get_mut()[0] = 0x00;
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"dlesbgzf");
+
+        // Reading block 1 sequentially did not cache it.
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+
+        // Seeking to block 1 evicts block 0 from the cache.
+        reader.seek(VirtualPosition::try_from((35, 0))?)?;
+        assert!(reader.seek(VirtualPosition::try_from((0, 3))?).is_err());
+
+        Ok(())
+    }
 }
@@ -101,7 +101,7 @@ fn split_frame(buf: &[u8]) -> (&[u8], &[u8], &[u8]) {
     (header, cdata, trailer)
 }
 
-fn parse_header(src: &[u8]) -> io::Result<()> {
+pub(crate) fn parse_header(src: &[u8]) -> io::Result<()> {
     if is_valid_header(src) {
         Ok(())
     } else {
@@ -112,7 +112,7 @@ fn parse_header(src: &[u8]) -> io::Result<()> {
     }
 }
 
-fn is_valid_header<B>(mut src: B) -> bool
+pub(crate) fn is_valid_header<B>(mut src: B) -> bool
 where
     B: Buf,
 {
@@ -0,0 +1,98 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    num::NonZeroUsize,
+};
+
+use crate::Block;
+
+/// A least recently used (LRU) cache of inflated blocks.
+///
+/// Blocks are keyed by their compressed position.
+#[derive(Debug)]
+pub(crate) struct BlockCache {
+    capacity: NonZeroUsize,
+    // Blocks and the ticks they were last used at, keyed by position.
+    blocks: HashMap<u64, (u64, Block)>,
+    // Block positions, keyed by the tick they were last used at.
+    positions: BTreeMap<u64, u64>,
+    tick: u64,
+}
+
+impl BlockCache {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            blocks: HashMap::with_capacity(capacity.get()),
+            positions: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    pub fn get(&mut self, position: u64) -> Option<&Block> {
+        let tick = self.next_tick();
+        let (last_tick, block) = self.blocks.get_mut(&position)?;
+
+        self.positions.remove(last_tick);
+        self.positions.insert(tick, position);
+        *last_tick = tick;
+
+        Some(block)
+    }
+
+    pub fn insert(&mut self, block: &Block) {
+        let position = block.position();
+        let tick = self.next_tick();
+
+        if let Some((last_tick, _)) = self.blocks.get(&position) {
+            self.positions.remove(last_tick);
+        } else if self.blocks.len() >= self.capacity.get() {
+            self.evict();
+        }
+
+        self.positions.insert(tick, position);
+        self.blocks.insert(position, (tick, block.clone()));
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        let tick = self.tick;
+        self.tick += 1;
+        tick
+    }
+
+    fn evict(&mut self) {
+        if let Some((_, position)) = self.positions.pop_first() {
+            self.blocks.remove(&position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_block(position: u64) -> Block {
+        let mut block = Block::default();
+        block.set_position(position);
+        block
+    }
+
+    #[test]
+    fn test_insert() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cache = BlockCache::new(NonZeroUsize::try_from(2)?);
+
+        cache.insert(&build_block(0));
+        cache.insert(&build_block(8));
+        assert!(cache.get(0).is_some());
+
+        cache.insert(&build_block(13));
+        assert!(cache.get(0).is_some());
+        assert!(cache.get(8).is_none());
+        assert!(cache.get(13).is_some());
+
+        cache.insert(&build_block(13));
+        assert_eq!(cache.blocks.len(), 2);
+        assert!(cache.positions.values().eq(&[0, 13]));
+
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use std::{
     path::Path,
 };
 
-use super::{block, Reader};
+use super::{block, BlockCache, Reader};
 use crate::Block;
 
 const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
@@ -17,6 +17,7 @@ const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
 #[derive(Debug)]
 pub struct Builder {
     worker_count: NonZeroUsize,
+    block_cache_capacity: Option<NonZeroUsize>,
 }
 
 impl Builder {
@@ -40,6 +41,31 @@ impl Builder {
         self
     }
 
+    /// Sets the capacity of the block cache.
+    ///
+    /// When set, the reader keeps up to the given number of the most recently read blocks in
+    /// memory, keyed by their compressed positions. Seeking to a cached block then avoids reading
+    /// and decompressing it again, which is useful for many small nearby queries. Each cached
+    /// block uses up to 64 KiB.
+    ///
+    /// By default, the block cache is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let capacity = NonZeroUsize::try_from(64)?;
+    /// let builder = bgzf::reader::Builder::default().set_block_cache_capacity(capacity);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_block_cache_capacity(mut self, capacity: NonZeroUsize) -> Self {
+        self.block_cache_capacity = Some(capacity);
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -84,6 +110,7 @@ impl Builder {
             inner: block_reader,
             position: 0,
             block: Block::default(),
+            block_cache: self.block_cache_capacity.map(BlockCache::new),
         }
     }
 }
@@ -92,6 +119,7 @@ impl Default for Builder {
     fn default() -> Self {
         Self {
             worker_count: DEFAULT_WORKER_COUNT,
+            block_cache_capacity: None,
         }
     }
 }
@@ -0,0 +1,421 @@
+//! BGZF validation.
+//!
+//! A BGZF stream is validated by walking each block and checking its gzip header, the BGZF extra
+//! subfield (`BC`), the block size (`BSIZE`), the uncompressed data size (`ISIZE`), and the data
+//! checksum (`CRC32`). The stream is also expected to end with an EOF marker.
+
+use std::{
+    error, fmt,
+    io::{self, Read},
+};
+
+use bytes::Buf;
+use flate2::Crc;
+
+use super::{
+    gz, reader::block::is_valid_header, writer::BGZF_EOF, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE,
+};
+
+/// A problem found in a BGZF stream.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    position: u64,
+    kind: Kind,
+}
+
+impl Diagnostic {
+    /// Returns the compressed position of the block with the problem.
+    ///
+    /// For a missing EOF marker, this is the end of the stream.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the kind of problem.
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+}
+
+impl error::Error for Diagnostic {}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "block at {}: {}", self.position, self.kind)
+    }
+}
+
+/// The kind of problem found in a BGZF stream.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// The gzip header or its BGZF extra subfield (`BC`) is invalid.
+    InvalidHeader,
+    /// The block size (`BSIZE` + 1) is invalid.
+    InvalidBlockSize(usize),
+    /// The block is truncated.
+    UnexpectedEof,
+    /// The compressed data (`CDATA`) is invalid.
+    InvalidCompressedData,
+    /// The uncompressed data size (`ISIZE`) is > 65536.
+    InvalidUncompressedSize(u32),
+    /// The uncompressed data size (`ISIZE`) does not match the size of the inflated data.
+    UncompressedSizeMismatch {
+        /// The uncompressed data size (`ISIZE`) read from the block trailer.
+        expected: u32,
+        /// The size of the inflated data.
+        actual: usize,
+    },
+    /// The data checksum (`CRC32`) does not match the checksum of the inflated data.
+    ChecksumMismatch {
+        /// The checksum (`CRC32`) read from the block trailer.
+        expected: u32,
+        /// The checksum of the inflated data.
+        actual: u32,
+    },
+    /// The stream does not end with an EOF marker.
+    MissingEofMarker,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "invalid header"),
+            Self::InvalidBlockSize(size) => write!(f, "invalid block size: {size}"),
+            Self::UnexpectedEof => write!(f, "unexpected EOF"),
+            Self::InvalidCompressedData => write!(f, "invalid compressed data"),
+            Self::InvalidUncompressedSize(r#isize) => {
+                write!(f, "invalid uncompressed size: {isize}")
+            }
+            Self::UncompressedSizeMismatch { expected, actual } => write!(
+                f,
+                "uncompressed size mismatch: expected {expected}, got {actual}"
+            ),
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {expected:#010x}, got {actual:#010x}"
+            ),
+            Self::MissingEofMarker => write!(f, "missing EOF marker"),
+        }
+    }
+}
+
+/// Validates every block of a BGZF stream.
+///
+/// This returns a list of problems found in the stream, each with the compressed position of the
+/// block it was found in. The stream is valid if the list is empty.
+///
+/// Problems in the block data (e.g., a checksum mismatch) do not stop the validation. However,
+/// when a block header or block size is invalid or a block is truncated, the start of the next
+/// block cannot be determined, and validation stops at that block.
+///
+/// An I/O error is only returned when the underlying reader fails.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles-bgzf")?;
+/// let data = writer.finish()?;
+///
+/// let diagnostics = bgzf::validation::validate(&data[..])?;
+/// assert!(diagnostics.is_empty());
+///
+/// let diagnostics = bgzf::validation::validate(&data[..data.len() - 1])?;
+/// assert_eq!(diagnostics.len(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn validate<R>(mut reader: R) -> io::Result<Vec<Diagnostic>>
+where
+    R: Read,
+{
+    const MIN_FRAME_SIZE: usize = BGZF_HEADER_SIZE + gz::TRAILER_SIZE;
+
+    let mut diagnostics = Vec::new();
+    let mut position = 0;
+    let mut buf = Vec::new();
+    let mut dst = Vec::new();
+    let mut is_eof_marker = false;
+
+    loop {
+        buf.resize(BGZF_HEADER_SIZE, 0);
+
+        let len = read_full(&mut reader, &mut buf)?;
+
+        if len == 0 {
+            break;
+        }
+
+        let diagnostic = |kind| Diagnostic { position, kind };
+
+        if len < BGZF_HEADER_SIZE {
+            diagnostics.push(diagnostic(Kind::UnexpectedEof));
+            break;
+        }
+
+        if !is_valid_header(&buf[..]) {
+            diagnostics.push(diagnostic(Kind::InvalidHeader));
+            break;
+        }
+
+        let block_size = usize::from((&buf[BSIZE_POSITION..]).get_u16_le()) + 1;
+
+        if block_size < MIN_FRAME_SIZE {
+            diagnostics.push(diagnostic(Kind::InvalidBlockSize(block_size)));
+            break;
+        }
+
+        buf.resize(block_size, 0);
+
+        if read_full(&mut reader, &mut buf[BGZF_HEADER_SIZE..])? < block_size - BGZF_HEADER_SIZE {
+            diagnostics.push(diagnostic(Kind::UnexpectedEof));
+            break;
+        }
+
+        if let Err(kind) = validate_data(&buf, &mut dst) {
+            diagnostics.push(diagnostic(kind));
+        }
+
+        is_eof_marker = buf == BGZF_EOF;
+        position += block_size as u64;
+    }
+
+    if !is_eof_marker {
+        diagnostics.push(Diagnostic {
+            position,
+            kind: Kind::MissingEofMarker,
+        });
+    }
+
+    Ok(diagnostics)
+}
+
+const BSIZE_POSITION: usize = 16;
+
+fn read_full<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<usize>
+where
+    R: Read,
+{
+    let mut len = 0;
+
+    while !buf.is_empty() {
+        match reader.read(buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                len += n;
+                buf = &mut buf[n..];
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(len)
+}
+
+fn validate_data(frame: &[u8], dst: &mut Vec<u8>) -> Result<(), Kind> {
+    use flate2::bufread::DeflateDecoder;
+
+    let n = frame.len() - gz::TRAILER_SIZE;
+    let cdata = &frame[BGZF_HEADER_SIZE..n];
+
+    let mut trailer = &frame[n..];
+    let crc32 = trailer.get_u32_le();
+    let r#isize = trailer.get_u32_le();
+
+    if usize::try_from(r#isize).map_or(true, |n| n > BGZF_MAX_ISIZE) {
+        return Err(Kind::InvalidUncompressedSize(r#isize));
+    }
+
+    dst.clear();
+
+    let decoder = DeflateDecoder::new(cdata);
+    let max_len = (BGZF_MAX_ISIZE + 1) as u64;
+
+    decoder
+        .take(max_len)
+        .read_to_end(dst)
+        .map_err(|_| Kind::InvalidCompressedData)?;
+
+    if dst.len() != r#isize as usize {
+        return Err(Kind::UncompressedSizeMismatch {
+            expected: r#isize,
+            actual: dst.len(),
+        });
+    }
+
+    let mut crc = Crc::new();
+    crc.update(dst);
+
+    if crc.sum() == crc32 {
+        Ok(())
+    } else {
+        Err(Kind::ChecksumMismatch {
+            expected: crc32,
+            actual: crc.sum(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_data() -> Vec<u8> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        data.to_vec()
+    }
+
+    #[test]
+    fn test_validate() -> io::Result<()> {
+        let data = build_data();
+        assert!(validate(&data[..])?.is_empty());
+
+        assert_eq!(
+            validate(&[][..])?,
+            [Diagnostic {
+                position: 0,
+                kind: Kind::MissingEofMarker
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_truncated_stream() -> io::Result<()> {
+        let data = build_data();
+
+        assert_eq!(
+            validate(&data[..40])?,
+            [
+                Diagnostic {
+                    position: 35,
+                    kind: Kind::UnexpectedEof
+                },
+                Diagnostic {
+                    position: 35,
+                    kind: Kind::MissingEofMarker
+                },
+            ]
+        );
+
+        let len = data.len() - BGZF_EOF.len();
+
+        assert_eq!(
+            validate(&data[..len])?,
+            [Diagnostic {
+                position: len as u64,
+                kind: Kind::MissingEofMarker
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_header() -> io::Result<()> {
+        let mut data = build_data();
+        data[35] = 0x00;
+
+        assert_eq!(
+            validate(&data[..])?,
+            [
+                Diagnostic {
+                    position: 35,
+                    kind: Kind::InvalidHeader
+                },
+                Diagnostic {
+                    position: 35,
+                    kind: Kind::MissingEofMarker
+                },
+            ]
+        );
+
+        let mut data = build_data();
+        data[12] = b'X'; // SI1
+
+        assert_eq!(
+            validate(&data[..])?[0],
+            Diagnostic {
+                position: 0,
+                kind: Kind::InvalidHeader
+            }
+        );
+
+        let mut data = build_data();
+        data[16] = 0x00; // BSIZE
+        data[17] = 0x00;
+
+        assert_eq!(
+            validate(&data[..])?[0],
+            Diagnostic {
+                position: 0,
+                kind: Kind::InvalidBlockSize(1)
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_trailer() -> io::Result<()> {
+        // block 0 trailer: CRC32 = 0x802a58a1, ISIZE = 7
+        let mut data = build_data();
+        data[27] ^= 0xff;
+
+        assert_eq!(
+            validate(&data[..])?,
+            [Diagnostic {
+                position: 0,
+                kind: Kind::ChecksumMismatch {
+                    expected: 0x802a585e,
+                    actual: 0x802a58a1
+                }
+            }]
+        );
+
+        let mut data = build_data();
+        data[31] = 0x08;
+
+        assert_eq!(
+            validate(&data[..])?,
+            [Diagnostic {
+                position: 0,
+                kind: Kind::UncompressedSizeMismatch {
+                    expected: 8,
+                    actual: 7
+                }
+            }]
+        );
+
+        let mut data = build_data();
+        data[33] = 0x02;
+
+        assert_eq!(
+            validate(&data[..])?,
+            [Diagnostic {
+                position: 0,
+                kind: Kind::InvalidUncompressedSize(131079)
+            }]
+        );
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use std::{
 
 use flate2::Crc;
 
-use super::{gz, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+use super::{gz, gzi, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
 
 // The max DEFLATE overhead for 65536 bytes of data at compression level 0.
 //
@@ -73,8 +73,10 @@ where
 {
     inner: Option<W>,
     position: u64,
+    uncompressed_position: u64,
     buf: Vec<u8>,
     compression_level: CompressionLevelImpl,
+    gzi_index: Option<gzi::Index>,
 }
 
 impl<W> Writer<W>
@@ -151,9 +153,41 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
+    /// Returns the gzip index built from the blocks written so far.
+    ///
+    /// This is only available if the writer was built with GZ index building enabled (see
+    /// [`Builder::set_build_gzi_index`]). The index is complete after the stream is finished,
+    /// e.g., by calling [`Self::try_finish`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::writer::Builder::default()
+    ///     .set_build_gzi_index(true)
+    ///     .build_with_writer(Vec::new());
+    ///
+    /// writer.write_all(b"noodles-bgzf")?;
+    /// writer.try_finish()?;
+    ///
+    /// assert_eq!(writer.gzi_index(), Some(&vec![(0, 0)]));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn gzi_index(&self) -> Option<&gzi::Index> {
+        self.gzi_index.as_ref()
+    }
+
     fn flush_block(&mut self) -> io::Result<()> {
         use self::frame::{write_header, write_trailer};
 
+        if let Some(index) = self.gzi_index.as_mut() {
+            if self.position > 0 {
+                index.push((self.position, self.uncompressed_position));
+            }
+        }
+
         let (cdata, crc32, r#isize) = deflate_data(&self.buf, self.compression_level)?;
 
         let inner = self.inner.as_mut().unwrap();
@@ -164,6 +198,7 @@ where
         write_trailer(inner, crc32, r#isize)?;
 
         self.position += block_size as u64;
+        self.uncompressed_position += self.buf.len() as u64;
 
         self.buf.clear();
 
@@ -336,4 +371,25 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_gzi_index() -> io::Result<()> {
+        let mut writer = Builder::default()
+            .set_build_gzi_index(true)
+            .build_with_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.position();
+
+        writer.write_all(b"bgzf")?;
+        writer.try_finish()?;
+
+        assert_eq!(writer.gzi_index(), Some(&vec![(0, 0), (position, 7)]));
+
+        let writer = Writer::new(Vec::new());
+        assert!(writer.gzi_index().is_none());
+
+        Ok(())
+    }
 }
@@ -1,11 +1,17 @@
-use std::io::Write;
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
 
-use super::{CompressionLevel, Writer, MAX_BUF_SIZE};
+use super::{CompressionLevel, Writer, BGZF_EOF, MAX_BUF_SIZE};
+use crate::gzi;
 
 /// A BGZF writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_level: CompressionLevel,
+    build_gzi_index: bool,
 }
 
 impl Builder {
@@ -26,6 +32,24 @@ impl Builder {
         self
     }
 
+    /// Sets whether to build a gzip index (GZI) while writing.
+    ///
+    /// When enabled, the compressed and uncompressed positions of each block are recorded as it
+    /// is flushed. The resulting index can be retrieved using [`Writer::gzi_index`].
+    ///
+    /// By default, building a gzip index is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::writer::Builder::default().set_build_gzi_index(true);
+    /// ```
+    pub fn set_build_gzi_index(mut self, build_gzi_index: bool) -> Self {
+        self.build_gzi_index = build_gzi_index;
+        self
+    }
+
     /// Builds a BGZF writer from a writer.
     ///
     /// # Examples
@@ -42,8 +66,276 @@ impl Builder {
         Writer {
             inner: Some(writer),
             position: 0,
+            uncompressed_position: 0,
             buf: Vec::with_capacity(MAX_BUF_SIZE),
             compression_level: self.compression_level.into(),
+            gzi_index: self.build_gzi_index.then(|| vec![(0, 0)]),
         }
     }
+
+    /// Builds a BGZF writer that appends to an existing BGZF file.
+    ///
+    /// The file is created if it does not exist. See [`Self::build_for_append_with_writer`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::writer::Builder::default().build_for_append_from_path("sample.gz")?;
+    /// let start_position = writer.virtual_position();
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_for_append_from_path<P>(self, dst: P) -> io::Result<Writer<File>>
+    where
+        P: AsRef<Path>,
+    {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(dst)
+            .and_then(|file| self.build_for_append_with_writer(file))
+    }
+
+    /// Builds a BGZF writer that appends to an existing BGZF stream.
+    ///
+    /// The stream must either be empty or end with a BGZF EOF block. The EOF block is overwritten
+    /// by new blocks, and a new EOF block is written when the writer is finished. The virtual
+    /// position where appended data begins is the virtual position of the writer
+    /// ([`Writer::virtual_position`]) before any writes.
+    ///
+    /// When building a gzip index (see [`Self::set_build_gzi_index`]), the positions of the
+    /// existing blocks are read from their headers and trailers, i.e., without decompressing
+    /// them, and the resulting index covers the entire stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Read, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut writer = bgzf::writer::Builder::default()
+    ///     .build_for_append_with_writer(Cursor::new(data))?;
+    /// let start_position = writer.virtual_position();
+    /// assert_eq!(start_position.uncompressed(), 0);
+    ///
+    /// writer.write_all(b"-bgzf")?;
+    /// let data = writer.finish()?.into_inner();
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf)?;
+    /// assert_eq!(buf, b"noodles-bgzf");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_for_append_with_writer<W>(self, mut writer: W) -> io::Result<Writer<W>>
+    where
+        W: Read + Write + Seek,
+    {
+        let len = writer.seek(SeekFrom::End(0))?;
+
+        let position = if len == 0 {
+            0
+        } else {
+            let eof_len = BGZF_EOF.len() as u64;
+
+            if len < eof_len {
+                return Err(missing_eof_block_error());
+            }
+
+            let position = len - eof_len;
+
+            writer.seek(SeekFrom::Start(position))?;
+
+            let mut buf = [0; BGZF_EOF.len()];
+            writer.read_exact(&mut buf)?;
+
+            if buf != BGZF_EOF {
+                return Err(missing_eof_block_error());
+            }
+
+            position
+        };
+
+        let (uncompressed_position, gzi_index) = if self.build_gzi_index {
+            let (uncompressed_position, index) = read_gzi_index(&mut writer, position)?;
+            (uncompressed_position, Some(index))
+        } else {
+            (0, None)
+        };
+
+        writer.seek(SeekFrom::Start(position))?;
+
+        Ok(Writer {
+            inner: Some(writer),
+            position,
+            uncompressed_position,
+            buf: Vec::with_capacity(MAX_BUF_SIZE),
+            compression_level: self.compression_level.into(),
+            gzi_index,
+        })
+    }
+}
+
+fn missing_eof_block_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "missing BGZF EOF block")
+}
+
+// Reads the positions of the blocks in `[0, end)` and returns the uncompressed length of the
+// blocks and their gzip index.
+fn read_gzi_index<R>(reader: &mut R, end: u64) -> io::Result<(u64, gzi::Index)>
+where
+    R: Read + Seek,
+{
+    use crate::{gz, reader::block::parse_header, BGZF_HEADER_SIZE};
+
+    const BSIZE_POSITION: usize = 16;
+
+    let mut index = vec![(0, 0)];
+
+    let mut position = 0;
+    let mut uncompressed_position = 0;
+
+    let mut header = [0; BGZF_HEADER_SIZE];
+    let mut isize_buf = [0; 4];
+
+    while position < end {
+        reader.seek(SeekFrom::Start(position))?;
+        reader.read_exact(&mut header)?;
+        parse_header(&header)?;
+
+        let bsize = u16::from_le_bytes([header[BSIZE_POSITION], header[BSIZE_POSITION + 1]]);
+        let block_size = u64::from(bsize) + 1;
+
+        if block_size < (BGZF_HEADER_SIZE + gz::TRAILER_SIZE) as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid BGZF block size",
+            ));
+        }
+
+        reader.seek(SeekFrom::Start(
+            position + block_size - isize_buf.len() as u64,
+        ))?;
+        reader.read_exact(&mut isize_buf)?;
+        let r#isize = u64::from(u32::from_le_bytes(isize_buf));
+
+        if r#isize > 0 {
+            if position > 0 {
+                index.push((position, uncompressed_position));
+            }
+
+            uncompressed_position += r#isize;
+        }
+
+        position += block_size;
+    }
+
+    if position != end {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "BGZF block extends past EOF block",
+        ));
+    }
+
+    Ok((uncompressed_position, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{Reader, VirtualPosition};
+
+    fn read_to_end(src: &[u8]) -> io::Result<Vec<u8>> {
+        let mut reader = Reader::new(src);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    #[test]
+    fn test_build_for_append_with_writer() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-")?;
+        let data = writer.finish()?;
+        let len = data.len() as u64;
+
+        let mut writer = Builder::default()
+            .set_build_gzi_index(true)
+            .build_for_append_with_writer(Cursor::new(data))?;
+
+        let eof_position = len - BGZF_EOF.len() as u64;
+        assert_eq!(
+            writer.virtual_position(),
+            VirtualPosition::try_from((eof_position, 0))?
+        );
+
+        writer.write_all(b"bgzf")?;
+        writer.flush()?;
+
+        let index = writer.gzi_index().cloned();
+        let data = writer.finish()?.into_inner();
+
+        assert!(data.ends_with(BGZF_EOF));
+        assert_eq!(read_to_end(&data)?, b"noodles-bgzf");
+
+        let mut writer = Builder::default()
+            .set_build_gzi_index(true)
+            .build_with_writer(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        writer.flush()?;
+        assert_eq!(index.as_ref(), writer.gzi_index());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_for_append_with_writer_with_empty_stream() -> io::Result<()> {
+        let mut writer =
+            Builder::default().build_for_append_with_writer(Cursor::new(Vec::new()))?;
+        assert_eq!(writer.virtual_position(), VirtualPosition::default());
+
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?.into_inner();
+
+        assert_eq!(read_to_end(&data)?, b"noodles");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_for_append_with_writer_with_missing_eof_block() {
+        let mut data = BGZF_EOF.to_vec();
+        data.pop();
+
+        assert!(matches!(
+            Builder::default().build_for_append_with_writer(Cursor::new(data)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = b"noodles".to_vec();
+
+        assert!(matches!(
+            Builder::default().build_for_append_with_writer(Cursor::new(data)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
 }
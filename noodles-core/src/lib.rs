@@ -5,8 +5,11 @@
 pub mod error;
 pub mod position;
 pub mod region;
+pub mod sequence_dictionary;
 
-pub use self::{error::Error, position::Position, region::Region};
+pub use self::{
+    error::Error, position::Position, region::Region, sequence_dictionary::SequenceDictionary,
+};
 
 /// A specialized [`std::result::Result`] type for results in noodles.
 pub type Result<T> = std::result::Result<T, error::Error>;
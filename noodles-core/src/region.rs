@@ -1,8 +1,15 @@
 //! Genomic region.
 
 pub mod interval;
-
-pub use self::interval::Interval;
+pub mod interval_set;
+pub mod interval_tree;
+mod parser;
+pub mod region_set;
+
+pub use self::{
+    interval::Interval, interval_set::IntervalSet, interval_tree::IntervalTree, parser::Parser,
+    region_set::RegionSet,
+};
 
 use std::{
     error, fmt,
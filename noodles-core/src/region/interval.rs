@@ -1,6 +1,7 @@
 //! Genomic region interval.
 
 use std::{
+    borrow::Cow,
     error, fmt,
     ops::{Bound, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeToInclusive},
     str::FromStr,
@@ -19,6 +20,39 @@ pub struct Interval {
 }
 
 impl Interval {
+    /// Creates an interval from 0-based, half-open coordinates.
+    ///
+    /// This is the coordinate system used by, e.g., BED and BAM. The interval [`start`, `end`)
+    /// is converted to the 1-based, closed interval [`start` + 1, `end`].
+    ///
+    /// This returns `None` if the interval is empty, i.e., `start` >= `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position, Region};
+    ///
+    /// let interval = Interval::from_zero_based_half_open(7, 13).expect("empty interval");
+    /// assert_eq!(interval.start(), Some(Position::try_from(8)?));
+    /// assert_eq!(interval.end(), Some(Position::try_from(13)?));
+    ///
+    /// let region = Region::new("sq0", interval);
+    /// assert_eq!(region.to_string(), "sq0:8-13");
+    ///
+    /// assert!(Interval::from_zero_based_half_open(8, 8).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn from_zero_based_half_open(start: usize, end: usize) -> Option<Self> {
+        if start >= end {
+            return None;
+        }
+
+        let start = Position::new(start + 1)?;
+        let end = Position::new(end)?;
+
+        Some(Self::from(start..=end))
+    }
+
     /// Returns the start.
     ///
     /// # Examples
@@ -142,12 +176,15 @@ pub enum ParseError {
     InvalidStartPosition(position::ParseError),
     /// The end position is invalid.
     InvalidEndPosition(position::ParseError),
+    /// The length is invalid.
+    InvalidLength,
 }
 
 impl error::Error for ParseError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::InvalidStartPosition(e) | Self::InvalidEndPosition(e) => Some(e),
+            Self::InvalidLength => None,
         }
     }
 }
@@ -157,6 +194,7 @@ impl fmt::Display for ParseError {
         match self {
             Self::InvalidStartPosition(_) => f.write_str("invalid start position"),
             Self::InvalidEndPosition(_) => f.write_str("invalid end position"),
+            Self::InvalidLength => f.write_str("invalid length"),
         }
     }
 }
@@ -164,24 +202,41 @@ impl fmt::Display for ParseError {
 impl FromStr for Interval {
     type Err = ParseError;
 
+    /// Parses a raw interval.
+    ///
+    /// The raw interval is either `<start>`, `<start>-<end>`, or `<start>+<length>`. Positions and
+    /// lengths may include thousands separators (`,`), e.g., `1,000,000-2,000,000`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
             return Ok(Self::from(..));
         }
 
+        if let Some((raw_start, raw_length)) = s.split_once('+') {
+            let start = parse_position(raw_start).map_err(ParseError::InvalidStartPosition)?;
+
+            let length: usize = strip_thousands_separators(raw_length)
+                .parse()
+                .map_err(|_| ParseError::InvalidLength)?;
+
+            let end = length
+                .checked_sub(1)
+                .and_then(|n| start.checked_add(n))
+                .ok_or(ParseError::InvalidLength)?;
+
+            return Ok(Self::from(start..=end));
+        }
+
         let mut components = s.splitn(2, '-');
 
         let start = match components.next() {
-            Some(t) => t
-                .parse()
+            Some(t) => parse_position(t)
                 .map(Some)
                 .map_err(ParseError::InvalidStartPosition)?,
             None => None,
         };
 
         let end = match components.next() {
-            Some(t) => t
-                .parse()
+            Some(t) => parse_position(t)
                 .map(Some)
                 .map_err(ParseError::InvalidEndPosition)?,
             None => None,
@@ -191,6 +246,18 @@ impl FromStr for Interval {
     }
 }
 
+fn parse_position(s: &str) -> Result<Position, position::ParseError> {
+    strip_thousands_separators(s).parse()
+}
+
+fn strip_thousands_separators(s: &str) -> Cow<'_, str> {
+    if s.contains(',') {
+        Cow::from(s.replace(',', ""))
+    } else {
+        Cow::from(s)
+    }
+}
+
 impl From<RangeFrom<Position>> for Interval {
     fn from(range: RangeFrom<Position>) -> Self {
         Self {
@@ -343,6 +410,31 @@ mod tests {
             Err(ParseError::InvalidEndPosition(_))
         ));
 
+        let start = Position::try_from(1000000)?;
+        let end = Position::try_from(2000000)?;
+        assert_eq!(
+            "1,000,000-2,000,000".parse(),
+            Ok(Interval::from(start..=end))
+        );
+
+        let start = Position::try_from(1000)?;
+        let end = Position::try_from(1499)?;
+        assert_eq!("1000+500".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!("1,000+500".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!(
+            "8+1".parse(),
+            Ok(Interval::from(
+                Position::try_from(8)?..=Position::try_from(8)?
+            ))
+        );
+
+        assert_eq!("8+0".parse::<Interval>(), Err(ParseError::InvalidLength));
+        assert_eq!("8+x".parse::<Interval>(), Err(ParseError::InvalidLength));
+        assert!(matches!(
+            "x+8".parse::<Interval>(),
+            Err(ParseError::InvalidStartPosition(_))
+        ));
+
         Ok(())
     }
 }
@@ -0,0 +1,597 @@
+//! Genomic region interval set.
+
+use std::cmp;
+
+use super::Interval;
+use crate::Position;
+
+/// A set of intervals.
+///
+/// The intervals are stored sorted and normalized, i.e., overlapping and adjacent intervals are
+/// merged on insertion. Unbounded starts and ends are resolved to [`Position::MIN`] and
+/// [`Position::MAX`], respectively.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet {
+    intervals: Vec<(Position, Position)>,
+}
+
+impl IntervalSet {
+    /// Creates an empty interval set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalSet;
+    /// let intervals = IntervalSet::new();
+    /// assert!(intervals.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of disjoint intervals in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::IntervalSet, Position};
+    ///
+    /// let mut intervals = IntervalSet::new();
+    /// intervals.insert(Position::try_from(5)?..=Position::try_from(8)?);
+    /// intervals.insert(Position::try_from(8)?..=Position::try_from(13)?);
+    /// intervals.insert(Position::try_from(21)?..=Position::try_from(34)?);
+    ///
+    /// assert_eq!(intervals.len(), 2);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns whether the set is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalSet;
+    /// let intervals = IntervalSet::new();
+    /// assert!(intervals.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns an iterator over the disjoint intervals in the set, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let intervals: IntervalSet = [
+    ///     Interval::from(Position::try_from(21)?..=Position::try_from(34)?),
+    ///     Interval::from(Position::try_from(5)?..=Position::try_from(13)?),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut iter = intervals.iter();
+    /// assert_eq!(iter.next(), Some(Interval::from(Position::try_from(5)?..=Position::try_from(13)?)));
+    /// assert_eq!(iter.next(), Some(Interval::from(Position::try_from(21)?..=Position::try_from(34)?)));
+    /// assert!(iter.next().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals
+            .iter()
+            .map(|&(start, end)| Interval::from(start..=end))
+    }
+
+    /// Adds an interval to the set.
+    ///
+    /// Intervals in the set that overlap or are adjacent to the given interval are merged with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let mut intervals = IntervalSet::new();
+    /// intervals.insert(Position::try_from(5)?..=Position::try_from(8)?);
+    /// intervals.insert(Position::try_from(9)?..=Position::try_from(13)?);
+    ///
+    /// assert_eq!(
+    ///     intervals.iter().collect::<Vec<_>>(),
+    ///     [Interval::from(Position::try_from(5)?..=Position::try_from(13)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn insert<I>(&mut self, interval: I)
+    where
+        I: Into<Interval>,
+    {
+        let (mut start, mut end) = resolve(interval.into());
+
+        // The first interval that ends at or after the position before the start.
+        let i = self
+            .intervals
+            .partition_point(|&(_, e)| e.checked_add(1).map(|p| p < start).unwrap_or(false));
+
+        // The first interval that starts after the position after the end.
+        let j = self
+            .intervals
+            .partition_point(|&(s, _)| end.checked_add(1).map(|p| s <= p).unwrap_or(true));
+
+        if i < j {
+            start = cmp::min(start, self.intervals[i].0);
+            end = cmp::max(end, self.intervals[j - 1].1);
+        }
+
+        self.intervals.splice(i..j, [(start, end)]);
+    }
+
+    /// Returns whether the given position is in any interval of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::IntervalSet, Position};
+    ///
+    /// let mut intervals = IntervalSet::new();
+    /// intervals.insert(Position::try_from(5)?..=Position::try_from(13)?);
+    ///
+    /// assert!(intervals.contains(Position::try_from(8)?));
+    /// assert!(!intervals.contains(Position::try_from(21)?));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains(&self, position: Position) -> bool {
+        let i = self.intervals.partition_point(|&(_, end)| end < position);
+
+        self.intervals
+            .get(i)
+            .map(|&(start, _)| start <= position)
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the given interval intersects any interval of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let mut intervals = IntervalSet::new();
+    /// intervals.insert(Position::try_from(5)?..=Position::try_from(13)?);
+    ///
+    /// assert!(intervals.intersects(Interval::from(Position::try_from(8)?..=Position::try_from(21)?)));
+    /// assert!(!intervals.intersects(Interval::from(Position::try_from(21)?..=Position::try_from(34)?)));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersects(&self, interval: Interval) -> bool {
+        let (start, end) = resolve(interval);
+        let i = self.intervals.partition_point(|&(_, e)| e < start);
+
+        self.intervals
+            .get(i)
+            .map(|&(s, _)| s <= end)
+            .unwrap_or(false)
+    }
+
+    /// Returns the total number of positions covered by the set.
+    ///
+    /// This saturates at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::IntervalSet, Position};
+    ///
+    /// let mut intervals = IntervalSet::new();
+    /// intervals.insert(Position::try_from(5)?..=Position::try_from(13)?);
+    /// intervals.insert(Position::try_from(8)?..=Position::try_from(21)?);
+    /// intervals.insert(Position::try_from(34)?..=Position::try_from(55)?);
+    ///
+    /// assert_eq!(intervals.covered_length(), 39);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn covered_length(&self) -> usize {
+        self.intervals.iter().fold(0, |n, &(start, end)| {
+            let len = (end.get() - start.get()).saturating_add(1);
+            n.saturating_add(len)
+        })
+    }
+
+    /// Returns the union of this set and the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let a: IntervalSet = [Interval::from(Position::try_from(5)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let b: IntervalSet = [Interval::from(Position::try_from(8)?..=Position::try_from(21)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     a.union(&b).iter().collect::<Vec<_>>(),
+    ///     [Interval::from(Position::try_from(5)?..=Position::try_from(21)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut intervals = self.clone();
+        intervals.extend(other.iter());
+        intervals
+    }
+
+    /// Returns the intersection of this set and the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let a: IntervalSet = [Interval::from(Position::try_from(5)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let b: IntervalSet = [Interval::from(Position::try_from(8)?..=Position::try_from(21)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     a.intersection(&b).iter().collect::<Vec<_>>(),
+    ///     [Interval::from(Position::try_from(8)?..=Position::try_from(13)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+
+        let mut i = 0;
+        let mut j = 0;
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.intervals.get(i), other.intervals.get(j))
+        {
+            let start = cmp::max(a_start, b_start);
+            let end = cmp::min(a_end, b_end);
+
+            if start <= end {
+                intervals.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// Returns the positions in this set that are not in the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let a: IntervalSet = [Interval::from(Position::try_from(5)?..=Position::try_from(21)?)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let b: IntervalSet = [Interval::from(Position::try_from(8)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     a.difference(&b).iter().collect::<Vec<_>>(),
+    ///     [
+    ///         Interval::from(Position::try_from(5)?..=Position::try_from(7)?),
+    ///         Interval::from(Position::try_from(14)?..=Position::try_from(21)?),
+    ///     ],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+
+        for &(a_start, a_end) in &self.intervals {
+            let mut start = Some(a_start);
+
+            while let Some(&(b_start, b_end)) = other.intervals.get(j) {
+                if b_end < a_start {
+                    j += 1;
+                    continue;
+                }
+
+                if b_start > a_end {
+                    break;
+                }
+
+                if let Some(s) = start {
+                    if s < b_start {
+                        let e = Position::new(b_start.get() - 1).expect("b_start > s >= 1");
+                        intervals.push((s, e));
+                    }
+                }
+
+                start = b_end.checked_add(1);
+
+                if b_end > a_end {
+                    break;
+                }
+
+                j += 1;
+            }
+
+            if let Some(s) = start {
+                if s <= a_end {
+                    intervals.push((s, a_end));
+                }
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// Returns the positions in [1, `length`] that are not in this set.
+    ///
+    /// This is typically used with the length of a reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let intervals: IntervalSet = [Interval::from(Position::try_from(8)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     intervals.complement(21).iter().collect::<Vec<_>>(),
+    ///     [
+    ///         Interval::from(Position::MIN..=Position::try_from(7)?),
+    ///         Interval::from(Position::try_from(14)?..=Position::try_from(21)?),
+    ///     ],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn complement(&self, length: usize) -> Self {
+        match Position::new(length) {
+            Some(end) => {
+                let mut universe = Self::new();
+                universe.insert(Position::MIN..=end);
+                universe.difference(self)
+            }
+            None => Self::new(),
+        }
+    }
+
+    /// Returns a set with intervals separated by at most `max_gap` positions merged.
+    ///
+    /// A gap is the number of positions between two intervals. Adjacent intervals have a gap of
+    /// 0 and are always merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let intervals: IntervalSet = [
+    ///     Interval::from(Position::try_from(5)?..=Position::try_from(8)?),
+    ///     Interval::from(Position::try_from(13)?..=Position::try_from(21)?),
+    ///     Interval::from(Position::try_from(34)?..=Position::try_from(55)?),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     intervals.merge(4).iter().collect::<Vec<_>>(),
+    ///     [
+    ///         Interval::from(Position::try_from(5)?..=Position::try_from(21)?),
+    ///         Interval::from(Position::try_from(34)?..=Position::try_from(55)?),
+    ///     ],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn merge(&self, max_gap: usize) -> Self {
+        let mut intervals: Vec<(Position, Position)> = Vec::with_capacity(self.intervals.len());
+
+        for &(start, end) in &self.intervals {
+            if let Some((_, last_end)) = intervals.last_mut() {
+                let gap = start.get() - last_end.get() - 1;
+
+                if gap <= max_gap {
+                    *last_end = end;
+                    continue;
+                }
+            }
+
+            intervals.push((start, end));
+        }
+
+        Self { intervals }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<T: IntoIterator<Item = Interval>>(&mut self, iter: T) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut intervals = Self::new();
+        intervals.extend(iter);
+        intervals
+    }
+}
+
+fn resolve(interval: Interval) -> (Position, Position) {
+    (
+        interval.start().unwrap_or(Position::MIN),
+        interval.end().unwrap_or(Position::MAX),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(
+        intervals: &[(usize, usize)],
+    ) -> Result<IntervalSet, crate::position::TryFromIntError> {
+        intervals
+            .iter()
+            .map(|&(start, end)| {
+                let start = Position::try_from(start)?;
+                let end = Position::try_from(end)?;
+                Ok(Interval::from(start..=end))
+            })
+            .collect()
+    }
+
+    fn unbuild(intervals: &IntervalSet) -> Vec<(usize, usize)> {
+        intervals
+            .intervals
+            .iter()
+            .map(|(start, end)| (start.get(), end.get()))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert() -> Result<(), crate::position::TryFromIntError> {
+        let mut intervals = build(&[(5, 8), (21, 34), (55, 89)])?;
+
+        intervals.insert(Position::try_from(13)?..=Position::try_from(13)?);
+        assert_eq!(unbuild(&intervals), [(5, 8), (13, 13), (21, 34), (55, 89)]);
+
+        intervals.insert(Position::try_from(9)?..=Position::try_from(20)?);
+        assert_eq!(unbuild(&intervals), [(5, 34), (55, 89)]);
+
+        intervals.insert(Position::try_from(1)?..=Position::try_from(2)?);
+        assert_eq!(unbuild(&intervals), [(1, 2), (5, 34), (55, 89)]);
+
+        intervals.insert(Position::try_from(34)?..=Position::try_from(144)?);
+        assert_eq!(unbuild(&intervals), [(1, 2), (5, 144)]);
+
+        intervals.insert(Position::try_from(3)?..);
+        assert_eq!(unbuild(&intervals), [(1, usize::MAX)]);
+
+        let mut intervals = IntervalSet::new();
+        intervals.insert(..);
+        assert_eq!(unbuild(&intervals), [(1, usize::MAX)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains() -> Result<(), crate::position::TryFromIntError> {
+        let intervals = build(&[(5, 8), (13, 21)])?;
+
+        for (n, expected) in [(1, false), (5, true), (8, true), (9, false), (21, true)] {
+            assert_eq!(intervals.contains(Position::try_from(n)?), expected, "{n}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects() -> Result<(), crate::position::TryFromIntError> {
+        let intervals = build(&[(5, 8), (13, 21)])?;
+
+        let t = |start, end| -> Result<bool, crate::position::TryFromIntError> {
+            let interval = Interval::from(Position::try_from(start)?..=Position::try_from(end)?);
+            Ok(intervals.intersects(interval))
+        };
+
+        assert!(t(1, 5)?);
+        assert!(t(9, 13)?);
+        assert!(!t(9, 12)?);
+        assert!(!t(22, 34)?);
+        assert!(intervals.intersects(Interval::from(..)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_covered_length() -> Result<(), crate::position::TryFromIntError> {
+        assert_eq!(IntervalSet::new().covered_length(), 0);
+        assert_eq!(build(&[(5, 8), (13, 21)])?.covered_length(), 13);
+
+        let mut intervals = IntervalSet::new();
+        intervals.insert(..);
+        assert_eq!(intervals.covered_length(), usize::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union() -> Result<(), crate::position::TryFromIntError> {
+        let a = build(&[(5, 8), (21, 34)])?;
+        let b = build(&[(9, 13), (55, 89)])?;
+        assert_eq!(unbuild(&a.union(&b)), [(5, 13), (21, 34), (55, 89)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersection() -> Result<(), crate::position::TryFromIntError> {
+        let a = build(&[(5, 13), (21, 34), (55, 89)])?;
+        let b = build(&[(8, 25), (30, 60), (89, 144)])?;
+        assert_eq!(
+            unbuild(&a.intersection(&b)),
+            [(8, 13), (21, 25), (30, 34), (55, 60), (89, 89)]
+        );
+
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_difference() -> Result<(), crate::position::TryFromIntError> {
+        let a = build(&[(5, 13), (21, 34), (55, 89)])?;
+        let b = build(&[(1, 5), (8, 8), (13, 25), (34, 34), (60, 144)])?;
+        assert_eq!(
+            unbuild(&a.difference(&b)),
+            [(6, 7), (9, 12), (26, 33), (55, 59)]
+        );
+
+        assert_eq!(unbuild(&a.difference(&IntervalSet::new())), unbuild(&a));
+        assert!(a.difference(&a).is_empty());
+
+        let mut b = IntervalSet::new();
+        b.insert(Position::try_from(8)?..);
+        assert_eq!(unbuild(&a.difference(&b)), [(5, 7)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement() -> Result<(), crate::position::TryFromIntError> {
+        let intervals = build(&[(1, 5), (8, 13)])?;
+        assert_eq!(unbuild(&intervals.complement(21)), [(6, 7), (14, 21)]);
+        assert_eq!(unbuild(&intervals.complement(10)), [(6, 7)]);
+        assert!(intervals.complement(0).is_empty());
+        assert_eq!(unbuild(&IntervalSet::new().complement(8)), [(1, 8)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), crate::position::TryFromIntError> {
+        let intervals = build(&[(5, 8), (13, 21), (34, 55)])?;
+        assert_eq!(unbuild(&intervals.merge(0)), [(5, 8), (13, 21), (34, 55)]);
+        assert_eq!(unbuild(&intervals.merge(3)), [(5, 8), (13, 21), (34, 55)]);
+        assert_eq!(unbuild(&intervals.merge(4)), [(5, 21), (34, 55)]);
+        assert_eq!(unbuild(&intervals.merge(12)), [(5, 55)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,427 @@
+//! Genomic region interval tree.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::collections::HashMap;
+
+use super::{Interval, Region};
+use crate::Position;
+
+// Subtrees with a height less than or equal to this are scanned linearly.
+const MAX_SCAN_HEIGHT: u32 = 3;
+
+/// An immutable interval tree.
+///
+/// This maps genomic regions, i.e., reference sequence names and intervals, to values, e.g.,
+/// features from a BED, GFF, or GTF file. It allows querying all values that overlap a position
+/// or interval.
+///
+/// The entries of each reference sequence are stored as an implicit augmented interval tree over
+/// an array sorted by start position, similar to [cgranges].
+///
+/// [cgranges]: https://github.com/lh3/cgranges
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{region::IntervalTree, Position, Region};
+///
+/// let tree: IntervalTree<&str> = [
+///     ("sq0:5-13".parse()?, "gene0"),
+///     ("sq0:8-21".parse()?, "gene1"),
+///     ("sq1:34-55".parse()?, "gene2"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let genes: Vec<_> = tree
+///     .query_position(b"sq0", Position::try_from(13)?)
+///     .map(|(_, gene)| *gene)
+///     .collect();
+///
+/// assert_eq!(genes, ["gene0", "gene1"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntervalTree<T> {
+    reference_sequences: HashMap<Vec<u8>, Vec<Node<T>>>,
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    start: Position,
+    end: Position,
+    max_end: Position,
+    value: T,
+}
+
+impl<T> IntervalTree<T> {
+    /// Returns a builder to create an interval tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalTree;
+    /// let builder = IntervalTree::<()>::builder();
+    /// ```
+    pub fn builder() -> Builder<T> {
+        Builder::default()
+    }
+
+    /// Returns the number of entries in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalTree;
+    /// let tree = IntervalTree::<()>::builder().build();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.reference_sequences
+            .values()
+            .map(|nodes| nodes.len())
+            .sum()
+    }
+
+    /// Returns whether the tree has any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalTree;
+    /// let tree = IntervalTree::<()>::builder().build();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over all entries on the given reference sequence that intersect the
+    /// given interval.
+    ///
+    /// Entries are returned in order of their start positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalTree}, Position};
+    ///
+    /// let tree: IntervalTree<&str> = [
+    ///     ("sq0:5-13".parse()?, "gene0"),
+    ///     ("sq0:21-34".parse()?, "gene1"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let interval = Interval::from(Position::try_from(8)?..=Position::try_from(21)?);
+    /// let genes: Vec<_> = tree.query(b"sq0", interval).map(|(_, gene)| *gene).collect();
+    /// assert_eq!(genes, ["gene0", "gene1"]);
+    ///
+    /// assert_eq!(tree.query(b"sq1", interval).count(), 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<I>(&self, name: &[u8], interval: I) -> Query<'_, T>
+    where
+        I: Into<Interval>,
+    {
+        let nodes = self
+            .reference_sequences
+            .get(name)
+            .map(|nodes| nodes.as_slice())
+            .unwrap_or_default();
+
+        Query::new(nodes, interval.into())
+    }
+
+    /// Returns an iterator over all entries on the given reference sequence that contain the
+    /// given position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::IntervalTree, Position};
+    ///
+    /// let tree: IntervalTree<&str> = [("sq0:5-13".parse()?, "gene0")].into_iter().collect();
+    ///
+    /// assert_eq!(tree.query_position(b"sq0", Position::try_from(8)?).count(), 1);
+    /// assert_eq!(tree.query_position(b"sq0", Position::try_from(21)?).count(), 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_position(&self, name: &[u8], position: Position) -> Query<'_, T> {
+        self.query(name, position..=position)
+    }
+
+    fn from_entries(entries: HashMap<Vec<u8>, Vec<(Position, Position, T)>>) -> Self {
+        let reference_sequences = entries
+            .into_iter()
+            .map(|(name, mut entries)| {
+                entries.sort_by_key(|(start, end, _)| (*start, *end));
+
+                let mut nodes: Vec<_> = entries
+                    .into_iter()
+                    .map(|(start, end, value)| Node {
+                        start,
+                        end,
+                        max_end: end,
+                        value,
+                    })
+                    .collect();
+
+                index(&mut nodes);
+
+                (name, nodes)
+            })
+            .collect();
+
+        Self {
+            reference_sequences,
+        }
+    }
+}
+
+impl<T> FromIterator<(Region, T)> for IntervalTree<T> {
+    fn from_iter<I: IntoIterator<Item = (Region, T)>>(iter: I) -> Self {
+        let mut builder = Self::builder();
+
+        for (region, value) in iter {
+            builder = builder.add_entry(region.name, region.interval, value);
+        }
+
+        builder.build()
+    }
+}
+
+/// An iterator over entries of an interval tree that intersect an interval.
+///
+/// This is created by calling [`IntervalTree::query`].
+pub struct Query<'t, T> {
+    nodes: &'t [Node<T>],
+    start: Position,
+    end: Position,
+    stack: Vec<(usize, u32, bool)>,
+    scan: Option<(usize, usize)>,
+}
+
+impl<'t, T> Query<'t, T> {
+    fn new(nodes: &'t [Node<T>], interval: Interval) -> Self {
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(Position::MAX);
+
+        let mut stack = Vec::new();
+
+        if !nodes.is_empty() {
+            let height = root_height(nodes.len());
+            stack.push(((1 << height) - 1, height, false));
+        }
+
+        Self {
+            nodes,
+            start,
+            end,
+            stack,
+            scan: None,
+        }
+    }
+}
+
+impl<'t, T> Iterator for Query<'t, T> {
+    type Item = (Interval, &'t T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.nodes.len();
+
+        loop {
+            if let Some((i, j)) = self.scan {
+                if i < j && self.nodes[i].start <= self.end {
+                    self.scan = Some((i + 1, j));
+
+                    let node = &self.nodes[i];
+
+                    if node.end >= self.start {
+                        return Some(entry(node));
+                    }
+
+                    continue;
+                }
+
+                self.scan = None;
+            }
+
+            let (x, height, is_left_visited) = self.stack.pop()?;
+
+            if height <= MAX_SCAN_HEIGHT {
+                let i = x >> height << height;
+                let j = (i + (1 << (height + 1)) - 1).min(n);
+                self.scan = Some((i, j));
+            } else if !is_left_visited {
+                self.stack.push((x, height, true));
+
+                let y = x - (1 << (height - 1));
+
+                if y >= n || self.nodes[y].max_end >= self.start {
+                    self.stack.push((y, height - 1, false));
+                }
+            } else if x < n && self.nodes[x].start <= self.end {
+                self.stack
+                    .push((x + (1 << (height - 1)), height - 1, false));
+
+                let node = &self.nodes[x];
+
+                if node.end >= self.start {
+                    return Some(entry(node));
+                }
+            }
+        }
+    }
+}
+
+fn entry<T>(node: &Node<T>) -> (Interval, &T) {
+    (Interval::from(node.start..=node.end), &node.value)
+}
+
+// Computes the max end of each subtree of the implicit tree over the sorted nodes.
+//
+// Node `i` is at height `h`, where `h` is the number of trailing ones in `i`. Its children are at
+// `i - 2^(h - 1)` and `i + 2^(h - 1)`.
+fn index<T>(nodes: &mut [Node<T>]) {
+    let n = nodes.len();
+
+    if n == 0 {
+        return;
+    }
+
+    let mut last_i = 0;
+    let mut last = nodes[0].max_end;
+
+    for i in (0..n).step_by(2) {
+        last_i = i;
+        last = nodes[i].end;
+    }
+
+    let mut height = 1;
+
+    while 1 << height <= n {
+        let x = 1 << (height - 1);
+        let i0 = (x << 1) - 1;
+        let step = x << 2;
+
+        for i in (i0..n).step_by(step) {
+            let left_max_end = nodes[i - x].max_end;
+            let right_max_end = if i + x < n {
+                nodes[i + x].max_end
+            } else {
+                last
+            };
+
+            nodes[i].max_end = nodes[i].end.max(left_max_end).max(right_max_end);
+        }
+
+        last_i = if (last_i >> height) & 1 == 1 {
+            last_i - x
+        } else {
+            last_i + x
+        };
+
+        if last_i < n && nodes[last_i].max_end > last {
+            last = nodes[last_i].max_end;
+        }
+
+        height += 1;
+    }
+}
+
+fn root_height(n: usize) -> u32 {
+    let mut height = 0;
+
+    while 1 << (height + 1) <= n {
+        height += 1;
+    }
+
+    height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(tree: &IntervalTree<usize>, name: &[u8], start: usize, end: usize) -> Vec<usize> {
+        let start = Position::try_from(start).unwrap();
+        let end = Position::try_from(end).unwrap();
+        tree.query(name, start..=end).map(|(_, i)| *i).collect()
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let tree: IntervalTree<usize> = [
+            ("sq0:21-34".parse()?, 2),
+            ("sq0:5-13".parse()?, 0),
+            ("sq0:8-89".parse()?, 1),
+            ("sq0:55-55".parse()?, 3),
+            ("sq1:8-13".parse()?, 4),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(tree.len(), 5);
+
+        assert_eq!(query(&tree, b"sq0", 1, 4), []);
+        assert_eq!(query(&tree, b"sq0", 13, 21), [0, 1, 2]);
+        assert_eq!(query(&tree, b"sq0", 35, 54), [1]);
+        assert_eq!(query(&tree, b"sq0", 55, 144), [1, 3]);
+        assert_eq!(query(&tree, b"sq0", 90, 144), []);
+        assert_eq!(query(&tree, b"sq1", 1, 8), [4]);
+        assert_eq!(query(&tree, b"sq2", 1, 8), []);
+
+        let actual: Vec<_> = tree.query(b"sq0", ..).map(|(_, i)| *i).collect();
+        assert_eq!(actual, [0, 1, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_many_entries() -> Result<(), Box<dyn std::error::Error>> {
+        // Compares the tree against a linear scan with enough entries to exceed the scan height.
+        let mut entries = Vec::new();
+
+        for i in 0..1000 {
+            let start = (i * 7919) % 10000 + 1;
+            let len = (i * 104729) % 500;
+            entries.push((start, start + len));
+        }
+
+        let tree: IntervalTree<usize> = entries
+            .iter()
+            .enumerate()
+            .map(|(i, &(start, end))| {
+                let start = Position::try_from(start)?;
+                let end = Position::try_from(end)?;
+                Ok((Region::new("sq0", start..=end), i))
+            })
+            .collect::<Result<_, crate::position::TryFromIntError>>()?;
+
+        for (start, end) in [
+            (1, 1),
+            (500, 600),
+            (2500, 2500),
+            (9000, 12000),
+            (11000, 11000),
+        ] {
+            let mut actual = query(&tree, b"sq0", start, end);
+            actual.sort_unstable();
+
+            let expected: Vec<_> = entries
+                .iter()
+                .enumerate()
+                .filter(|(_, &(s, e))| s <= end && start <= e)
+                .map(|(i, _)| i)
+                .collect();
+
+            assert_eq!(actual, expected, "{start}-{end}");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use super::IntervalTree;
+use crate::{region::Interval, Position};
+
+/// An interval tree builder.
+pub struct Builder<T> {
+    entries: HashMap<Vec<u8>, Vec<(Position, Position, T)>>,
+}
+
+impl<T> Builder<T> {
+    /// Adds an entry.
+    ///
+    /// Unbounded starts and ends are resolved to [`Position::MIN`] and [`Position::MAX`],
+    /// respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::IntervalTree, Position};
+    ///
+    /// let tree = IntervalTree::builder()
+    ///     .add_entry("sq0", Position::try_from(5)?..=Position::try_from(13)?, "gene0")
+    ///     .build();
+    ///
+    /// assert_eq!(tree.len(), 1);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn add_entry<N, I>(mut self, name: N, interval: I, value: T) -> Self
+    where
+        N: Into<Vec<u8>>,
+        I: Into<Interval>,
+    {
+        let interval = interval.into();
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(Position::MAX);
+
+        self.entries
+            .entry(name.into())
+            .or_default()
+            .push((start, end, value));
+
+        self
+    }
+
+    /// Builds an interval tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalTree;
+    /// let tree = IntervalTree::<()>::builder().build();
+    /// ```
+    pub fn build(self) -> IntervalTree<T> {
+        IntervalTree::from_entries(self.entries)
+    }
+}
+
+impl<T> Default for Builder<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Genomic region parser.
+
+use std::collections::HashSet;
+
+use super::{Interval, ParseError, Region};
+
+/// A genomic region parser.
+///
+/// This extends [`Region::from_str`](std::str::FromStr) with options to
+///
+///   * parse a lone position, e.g., `sq0:8`, as a single position rather than as a start position
+///     to the end of the reference sequence; and
+///   * resolve names that contain colons against a list of known reference sequence names.
+///
+/// It can also parse lists of regions.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{region::Parser, Position, Region};
+///
+/// let parser = Parser::default().set_single_position(true);
+///
+/// let region = parser.parse("sq0:8")?;
+/// let position = Position::try_from(8)?;
+/// assert_eq!(region, Region::new("sq0", position..=position));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+    is_single_position: bool,
+    reference_sequence_names: Option<HashSet<Vec<u8>>>,
+}
+
+impl Parser {
+    /// Sets whether a lone position is parsed as a single position.
+    ///
+    /// By default, a lone position, e.g., `sq0:8`, is parsed as the start position to the end of
+    /// the reference sequence, i.e., `[8, ∞)`. When enabled, it is parsed as `[8, 8]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Parser, Position, Region};
+    ///
+    /// let position = Position::try_from(8)?;
+    ///
+    /// let parser = Parser::default();
+    /// assert_eq!(parser.parse("sq0:8")?, Region::new("sq0", position..));
+    ///
+    /// let parser = Parser::default().set_single_position(true);
+    /// assert_eq!(parser.parse("sq0:8")?, Region::new("sq0", position..=position));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_single_position(mut self, is_single_position: bool) -> Self {
+        self.is_single_position = is_single_position;
+        self
+    }
+
+    /// Sets the known reference sequence names.
+    ///
+    /// These are typically the reference sequence names in a header. When set, a reference
+    /// sequence name must be one of these names, and a name that contains colons, e.g.,
+    /// `HLA-A*01:01`, is resolved similar to htslib:
+    ///
+    ///   1. If the entire input is a known name, the region is the entire reference sequence.
+    ///   2. If the input before the last colon is a known name, the input after the last colon is
+    ///      parsed as the interval.
+    ///
+    /// If both match, the input is ambiguous. A name can be wrapped in braces to force it to be
+    /// read as a name, e.g., `{sq0:5}:8-13`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{ParseError, Parser}, Position, Region};
+    ///
+    /// let parser = Parser::default().set_reference_sequence_names(["sq0", "sq0:5", "sq1:8-13"]);
+    ///
+    /// assert_eq!(parser.parse("sq1:8-13")?, Region::new("sq1:8-13", ..));
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// assert_eq!(parser.parse("{sq0:5}:8-13")?, Region::new("sq0:5", start..=end));
+    ///
+    /// assert_eq!(parser.parse("sq0:5"), Err(ParseError::Ambiguous));
+    /// assert_eq!(parser.parse("sq2:8-13"), Err(ParseError::Invalid));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_sequence_names<I, N>(mut self, reference_sequence_names: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<Vec<u8>>,
+    {
+        self.reference_sequence_names = Some(
+            reference_sequence_names
+                .into_iter()
+                .map(|name| name.into())
+                .collect(),
+        );
+
+        self
+    }
+
+    /// Parses a raw region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Parser, Position, Region};
+    ///
+    /// let parser = Parser::default();
+    ///
+    /// let start = Position::try_from(1000000)?;
+    /// let end = Position::try_from(2000000)?;
+    /// assert_eq!(parser.parse("sq0:1,000,000-2,000,000")?, Region::new("sq0", start..=end));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse(&self, s: &str) -> Result<Region, ParseError> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (name, raw_interval) = self.split(s)?;
+
+        let interval = match raw_interval {
+            Some(t) => self.parse_interval(t)?,
+            None => Interval::from(..),
+        };
+
+        Ok(Region::new(name, interval))
+    }
+
+    /// Parses a list of raw regions.
+    ///
+    /// Regions are separated by newlines or commas. Blank lines and surrounding whitespace are
+    /// ignored. A comma is read as a thousands separator rather than a region separator when it
+    /// is in an interval, follows a digit, and is followed by exactly three digits, e.g., the
+    /// first comma in `sq0:1,000-2000,sq1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Parser, Region};
+    ///
+    /// let parser = Parser::default();
+    /// let regions = parser.parse_list("sq0:1,000-2,000,sq1\nsq2:8+5\n")?;
+    ///
+    /// let expected: Vec<Region> = vec![
+    ///     "sq0:1000-2000".parse()?,
+    ///     "sq1".parse()?,
+    ///     "sq2:8-12".parse()?,
+    /// ];
+    ///
+    /// assert_eq!(regions, expected);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_list(&self, s: &str) -> Result<Vec<Region>, ParseError> {
+        let mut regions = Vec::new();
+
+        for line in s.lines() {
+            for raw_region in split_list(line) {
+                let raw_region = raw_region.trim();
+
+                if !raw_region.is_empty() {
+                    regions.push(self.parse(raw_region)?);
+                }
+            }
+        }
+
+        Ok(regions)
+    }
+
+    fn split<'a>(&self, s: &'a str) -> Result<(&'a str, Option<&'a str>), ParseError> {
+        if let Some(t) = s.strip_prefix('{') {
+            let (name, suffix) = t.split_once('}').ok_or(ParseError::Invalid)?;
+
+            let raw_interval = if suffix.is_empty() {
+                None
+            } else {
+                Some(suffix.strip_prefix(':').ok_or(ParseError::Invalid)?)
+            };
+
+            return self.validate_name(name).map(|_| (name, raw_interval));
+        }
+
+        let Some(reference_sequence_names) = &self.reference_sequence_names else {
+            return Ok(match s.rsplit_once(':') {
+                Some((name, raw_interval)) => (name, Some(raw_interval)),
+                None => (s, None),
+            });
+        };
+
+        let is_name = reference_sequence_names.contains(s.as_bytes());
+
+        let prefix = s.rsplit_once(':').filter(|(name, raw_interval)| {
+            reference_sequence_names.contains(name.as_bytes())
+                && self.parse_interval(raw_interval).is_ok()
+        });
+
+        match (is_name, prefix) {
+            (true, Some(_)) => Err(ParseError::Ambiguous),
+            (true, None) => Ok((s, None)),
+            (false, Some((name, raw_interval))) => Ok((name, Some(raw_interval))),
+            (false, None) => Err(ParseError::Invalid),
+        }
+    }
+
+    fn validate_name(&self, name: &str) -> Result<(), ParseError> {
+        match &self.reference_sequence_names {
+            Some(names) if !names.contains(name.as_bytes()) => Err(ParseError::Invalid),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_interval(&self, s: &str) -> Result<Interval, ParseError> {
+        let interval: Interval = s.parse().map_err(ParseError::InvalidInterval)?;
+
+        if self.is_single_position {
+            if let (Some(start), None) = (interval.start(), interval.end()) {
+                return Ok(Interval::from(start..=start));
+            }
+        }
+
+        Ok(interval)
+    }
+}
+
+fn split_list(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut in_interval = false;
+    let mut start = 0;
+    let mut items = Vec::new();
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match b {
+            b':' => in_interval = true,
+            b',' if !(in_interval && is_thousands_separator(bytes, i)) => {
+                items.push(&s[start..i]);
+                start = i + 1;
+                in_interval = false;
+            }
+            _ => {}
+        }
+    }
+
+    items.push(&s[start..]);
+
+    items.into_iter()
+}
+
+fn is_thousands_separator(bytes: &[u8], i: usize) -> bool {
+    let is_preceded_by_digit = i > 0 && bytes[i - 1].is_ascii_digit();
+
+    let digits = &bytes[i + 1..];
+    let is_followed_by_three_digits = digits.len() >= 3
+        && digits[..3].iter().all(|b| b.is_ascii_digit())
+        && digits.get(3).map(|b| !b.is_ascii_digit()).unwrap_or(true);
+
+    is_preceded_by_digit && is_followed_by_three_digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn std::error::Error>> {
+        let parser = Parser::default();
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        assert_eq!(parser.parse("sq0")?, Region::new("sq0", ..));
+        assert_eq!(parser.parse("sq0:8")?, Region::new("sq0", start..));
+        assert_eq!(parser.parse("sq0:8-13")?, Region::new("sq0", start..=end));
+        assert_eq!(parser.parse("sq0:8+6")?, Region::new("sq0", start..=end));
+        assert_eq!(
+            parser.parse("{sq0:1}:8-13")?,
+            Region::new("sq0:1", start..=end)
+        );
+        assert_eq!(parser.parse("{sq0:1}")?, Region::new("sq0:1", ..));
+
+        assert_eq!(parser.parse(""), Err(ParseError::Empty));
+        assert_eq!(parser.parse("{sq0"), Err(ParseError::Invalid));
+        assert_eq!(parser.parse("{sq0}8"), Err(ParseError::Invalid));
+
+        let parser = Parser::default().set_single_position(true);
+        assert_eq!(parser.parse("sq0:8")?, Region::new("sq0", start..=start));
+        assert_eq!(parser.parse("sq0:8-13")?, Region::new("sq0", start..=end));
+        assert_eq!(parser.parse("sq0")?, Region::new("sq0", ..));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with_reference_sequence_names() -> Result<(), Box<dyn std::error::Error>> {
+        let parser =
+            Parser::default().set_reference_sequence_names(["sq0", "HLA-A*01:01", "sq1", "sq1:8"]);
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        assert_eq!(parser.parse("sq0:8-13")?, Region::new("sq0", start..=end));
+        assert_eq!(parser.parse("HLA-A*01:01")?, Region::new("HLA-A*01:01", ..));
+        assert_eq!(
+            parser.parse("HLA-A*01:01:8-13")?,
+            Region::new("HLA-A*01:01", start..=end)
+        );
+        assert_eq!(parser.parse("{sq1:8}")?, Region::new("sq1:8", ..));
+        assert_eq!(parser.parse("{sq1}:8")?, Region::new("sq1", start..));
+
+        assert_eq!(parser.parse("sq1:8"), Err(ParseError::Ambiguous));
+        assert_eq!(parser.parse("sq2"), Err(ParseError::Invalid));
+        assert_eq!(parser.parse("sq2:8-13"), Err(ParseError::Invalid));
+        assert_eq!(parser.parse("{sq2}:8-13"), Err(ParseError::Invalid));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_list() -> Result<(), Box<dyn std::error::Error>> {
+        let parser = Parser::default();
+
+        let actual = parser.parse_list("sq0:1,000-2,000,sq1, sq2:5\n\n  sq3:8-13  \r\nsq4,5:8")?;
+        let expected: Vec<Region> = vec![
+            "sq0:1000-2000".parse()?,
+            "sq1".parse()?,
+            "sq2:5".parse()?,
+            "sq3:8-13".parse()?,
+            "sq4".parse()?,
+            "5:8".parse()?,
+        ];
+        assert_eq!(actual, expected);
+
+        assert!(parser.parse_list("")?.is_empty());
+        assert!(parser.parse_list("sq0:x").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_list() {
+        let actual: Vec<_> = split_list("sq0:1,000,000-2,000,000,sq1,sq2:1,00").collect();
+        assert_eq!(actual, ["sq0:1,000,000-2,000,000", "sq1", "sq2:1", "00"]);
+    }
+}
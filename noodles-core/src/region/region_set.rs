@@ -0,0 +1,407 @@
+//! Genomic region set.
+
+use std::collections::BTreeMap;
+
+use super::{IntervalSet, Region};
+use crate::Position;
+
+/// A set of regions.
+///
+/// This is a set of intervals for each reference sequence name. Reference sequence names are
+/// ordered lexicographically.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegionSet {
+    intervals: BTreeMap<Vec<u8>, IntervalSet>,
+}
+
+impl RegionSet {
+    /// Creates an empty region set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    /// let regions = RegionSet::new();
+    /// assert!(regions.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the set is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    /// let regions = RegionSet::new();
+    /// assert!(regions.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the interval set of the given reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let regions: RegionSet = ["sq0:5-8".parse()?].into_iter().collect();
+    ///
+    /// assert!(regions.get(b"sq0").is_some());
+    /// assert!(regions.get(b"sq1").is_none());
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn get(&self, name: &[u8]) -> Option<&IntervalSet> {
+        self.intervals.get(name)
+    }
+
+    /// Returns an iterator over reference sequence names and their interval sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let regions: RegionSet = ["sq1:5-8".parse()?, "sq0:13-21".parse()?].into_iter().collect();
+    /// let names: Vec<_> = regions.iter().map(|(name, _)| name).collect();
+    /// assert_eq!(names, [b"sq0", b"sq1"]);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], &IntervalSet)> {
+        self.intervals
+            .iter()
+            .map(|(name, intervals)| (name.as_slice(), intervals))
+    }
+
+    /// Returns an iterator over the disjoint regions in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::RegionSet, Region};
+    ///
+    /// let regions: RegionSet = ["sq0:5-13".parse()?, "sq0:8-21".parse()?].into_iter().collect();
+    /// let actual: Vec<_> = regions.regions().collect();
+    /// let expected: [Region; 1] = ["sq0:5-21".parse()?];
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn regions(&self) -> impl Iterator<Item = Region> + '_ {
+        self.iter().flat_map(|(name, intervals)| {
+            intervals
+                .iter()
+                .map(move |interval| Region::new(name, interval))
+        })
+    }
+
+    /// Adds a region to the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let mut regions = RegionSet::new();
+    /// regions.insert("sq0:5-8".parse()?);
+    ///
+    /// assert!(!regions.is_empty());
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn insert(&mut self, region: Region) {
+        let interval = region.interval();
+
+        self.intervals
+            .entry(region.name)
+            .or_default()
+            .insert(interval);
+    }
+
+    /// Returns whether the given position on the given reference sequence is in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::RegionSet, Position};
+    ///
+    /// let regions: RegionSet = ["sq0:5-13".parse()?].into_iter().collect();
+    ///
+    /// assert!(regions.contains(b"sq0", Position::try_from(8)?));
+    /// assert!(!regions.contains(b"sq1", Position::try_from(8)?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contains(&self, name: &[u8], position: Position) -> bool {
+        self.get(name)
+            .map(|intervals| intervals.contains(position))
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the given region intersects any region in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let regions: RegionSet = ["sq0:5-13".parse()?].into_iter().collect();
+    ///
+    /// assert!(regions.intersects(&"sq0:8-21".parse()?));
+    /// assert!(!regions.intersects(&"sq0:21-34".parse()?));
+    /// assert!(!regions.intersects(&"sq1".parse()?));
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn intersects(&self, region: &Region) -> bool {
+        self.get(region.name())
+            .map(|intervals| intervals.intersects(region.interval()))
+            .unwrap_or(false)
+    }
+
+    /// Returns the total number of positions covered by the set.
+    ///
+    /// This saturates at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    /// let regions: RegionSet = ["sq0:5-13".parse()?, "sq1:8-21".parse()?].into_iter().collect();
+    /// assert_eq!(regions.covered_length(), 23);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn covered_length(&self) -> usize {
+        self.intervals.values().fold(0, |n, intervals| {
+            n.saturating_add(intervals.covered_length())
+        })
+    }
+
+    /// Returns the union of this set and the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let a: RegionSet = ["sq0:5-13".parse()?].into_iter().collect();
+    /// let b: RegionSet = ["sq0:8-21".parse()?, "sq1:5-8".parse()?].into_iter().collect();
+    /// let expected: RegionSet = ["sq0:5-21".parse()?, "sq1:5-8".parse()?].into_iter().collect();
+    ///
+    /// assert_eq!(a.union(&b), expected);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut regions = self.clone();
+
+        for (name, intervals) in other.iter() {
+            let entry = regions.intervals.entry(name.to_vec()).or_default();
+            *entry = entry.union(intervals);
+        }
+
+        regions
+    }
+
+    /// Returns the intersection of this set and the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let a: RegionSet = ["sq0:5-13".parse()?, "sq1:5-8".parse()?].into_iter().collect();
+    /// let b: RegionSet = ["sq0:8-21".parse()?].into_iter().collect();
+    /// let expected: RegionSet = ["sq0:8-13".parse()?].into_iter().collect();
+    ///
+    /// assert_eq!(a.intersection(&b), expected);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let intervals = self
+            .iter()
+            .filter_map(|(name, a)| {
+                let b = other.get(name)?;
+                let intervals = a.intersection(b);
+                (!intervals.is_empty()).then(|| (name.to_vec(), intervals))
+            })
+            .collect();
+
+        Self { intervals }
+    }
+
+    /// Returns the regions in this set that are not in the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let a: RegionSet = ["sq0:5-21".parse()?, "sq1:5-8".parse()?].into_iter().collect();
+    /// let b: RegionSet = ["sq0:8-13".parse()?, "sq1".parse()?].into_iter().collect();
+    /// let expected: RegionSet = ["sq0:5-7".parse()?, "sq0:14-21".parse()?].into_iter().collect();
+    ///
+    /// assert_eq!(a.difference(&b), expected);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let intervals = self
+            .iter()
+            .filter_map(|(name, a)| {
+                let intervals = match other.get(name) {
+                    Some(b) => a.difference(b),
+                    None => a.clone(),
+                };
+
+                (!intervals.is_empty()).then(|| (name.to_vec(), intervals))
+            })
+            .collect();
+
+        Self { intervals }
+    }
+
+    /// Returns the regions of the given reference sequences that are not in this set.
+    ///
+    /// The reference sequences are given as pairs of names and lengths. Regions on reference
+    /// sequences that are not given are not included in the complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let regions: RegionSet = ["sq0:5-13".parse()?].into_iter().collect();
+    /// let expected: RegionSet = ["sq0:1-4".parse()?, "sq0:14-21".parse()?, "sq1:1-8".parse()?]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(regions.complement([("sq0", 21), ("sq1", 8)]), expected);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn complement<I, N>(&self, reference_sequence_lengths: I) -> Self
+    where
+        I: IntoIterator<Item = (N, usize)>,
+        N: AsRef<[u8]>,
+    {
+        let intervals = reference_sequence_lengths
+            .into_iter()
+            .filter_map(|(name, length)| {
+                let name = name.as_ref();
+
+                let intervals = match self.get(name) {
+                    Some(intervals) => intervals.complement(length),
+                    None => IntervalSet::new().complement(length),
+                };
+
+                (!intervals.is_empty()).then(|| (name.to_vec(), intervals))
+            })
+            .collect();
+
+        Self { intervals }
+    }
+
+    /// Returns a set with regions on the same reference sequence separated by at most `max_gap`
+    /// positions merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    ///
+    /// let regions: RegionSet = ["sq0:5-8".parse()?, "sq0:13-21".parse()?, "sq1:21-34".parse()?]
+    ///     .into_iter()
+    ///     .collect();
+    /// let expected: RegionSet = ["sq0:5-21".parse()?, "sq1:21-34".parse()?].into_iter().collect();
+    ///
+    /// assert_eq!(regions.merge(4), expected);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn merge(&self, max_gap: usize) -> Self {
+        let intervals = self
+            .iter()
+            .map(|(name, intervals)| (name.to_vec(), intervals.merge(max_gap)))
+            .collect();
+
+        Self { intervals }
+    }
+}
+
+impl Extend<Region> for RegionSet {
+    fn extend<T: IntoIterator<Item = Region>>(&mut self, iter: T) {
+        for region in iter {
+            self.insert(region);
+        }
+    }
+}
+
+impl FromIterator<Region> for RegionSet {
+    fn from_iter<T: IntoIterator<Item = Region>>(iter: T) -> Self {
+        let mut regions = Self::new();
+        regions.extend(iter);
+        regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(regions: &[&str]) -> Result<RegionSet, crate::region::ParseError> {
+        regions.iter().map(|s| s.parse()).collect()
+    }
+
+    #[test]
+    fn test_insert() -> Result<(), crate::region::ParseError> {
+        let mut regions = RegionSet::new();
+        regions.insert("sq1:8-13".parse()?);
+        regions.insert("sq0:5-8".parse()?);
+        regions.insert("sq1:1-8".parse()?);
+
+        let actual: Vec<_> = regions.regions().map(|region| region.to_string()).collect();
+        assert_eq!(actual, ["sq0:5-8", "sq1:1-13"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_operations() -> Result<(), crate::region::ParseError> {
+        let a = build(&["sq0:5-13", "sq1:21-34", "sq2:1-8"])?;
+        let b = build(&["sq0:8-21", "sq1:34-55", "sq3:1-8"])?;
+
+        assert_eq!(
+            a.union(&b),
+            build(&["sq0:5-21", "sq1:21-55", "sq2:1-8", "sq3:1-8",])?
+        );
+
+        assert_eq!(a.intersection(&b), build(&["sq0:8-13", "sq1:34-34"])?);
+
+        assert_eq!(
+            a.difference(&b),
+            build(&["sq0:5-7", "sq1:21-33", "sq2:1-8"])?
+        );
+
+        assert!(a.difference(&a).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement() -> Result<(), crate::region::ParseError> {
+        let regions = build(&["sq0:1-13", "sq1:1-8", "sq2:5-8"])?;
+
+        assert_eq!(
+            regions.complement([("sq0", 21), ("sq1", 8), ("sq3", 5)]),
+            build(&["sq0:14-21", "sq3:1-5"])?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_covered_length() -> Result<(), crate::region::ParseError> {
+        assert_eq!(RegionSet::new().covered_length(), 0);
+
+        let regions = build(&["sq0:5-13", "sq0:8-21", "sq1:1-8"])?;
+        assert_eq!(regions.covered_length(), 25);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,431 @@
+//! Sequence dictionary.
+
+mod sequence;
+
+pub use self::sequence::Sequence;
+
+use std::{collections::HashMap, fmt, mem, str};
+
+/// A sequence dictionary.
+///
+/// A sequence dictionary is an ordered list of reference sequences. It is a common description
+/// of the reference sequences in, e.g., SAM headers, VCF headers, FASTA indices, and tabix/CSI
+/// index headers.
+///
+/// Sequences can be looked up by their names or aliases.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequenceDictionary {
+    sequences: Vec<Sequence>,
+    indices: HashMap<Vec<u8>, usize>,
+}
+
+impl SequenceDictionary {
+    /// Creates an empty sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// let sequence_dictionary = SequenceDictionary::new();
+    /// assert!(sequence_dictionary.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of sequences in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// let sequence_dictionary = SequenceDictionary::new();
+    /// assert_eq!(sequence_dictionary.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.sequences.len()
+    }
+
+    /// Returns whether the dictionary has any sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// let sequence_dictionary = SequenceDictionary::new();
+    /// assert!(sequence_dictionary.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+
+    /// Returns an iterator over the sequences in the dictionary, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+    ///
+    /// let sequence_dictionary: SequenceDictionary =
+    ///     [Sequence::new("sq0"), Sequence::new("sq1")].into_iter().collect();
+    ///
+    /// let names: Vec<_> = sequence_dictionary.iter().map(|sequence| sequence.name()).collect();
+    /// assert_eq!(names, [b"sq0", b"sq1"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Sequence> {
+        self.sequences.iter()
+    }
+
+    /// Adds a sequence to the dictionary.
+    ///
+    /// If a sequence with the same name already exists, it is replaced, keeping its position, and
+    /// the old sequence is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+    ///
+    /// let mut sequence_dictionary = SequenceDictionary::new();
+    /// assert!(sequence_dictionary.insert(Sequence::new("sq0")).is_none());
+    /// assert!(sequence_dictionary.insert(Sequence::new("sq0").set_length(8)).is_some());
+    ///
+    /// assert_eq!(sequence_dictionary.len(), 1);
+    /// ```
+    pub fn insert(&mut self, sequence: Sequence) -> Option<Sequence> {
+        match self.get_index_of(sequence.name()) {
+            Some(i) if self.sequences[i].name() == sequence.name() => {
+                let old_sequence = mem::replace(&mut self.sequences[i], sequence);
+                self.reindex();
+                Some(old_sequence)
+            }
+            _ => {
+                let i = self.sequences.len();
+                self.index(i, &sequence);
+                self.sequences.push(sequence);
+                None
+            }
+        }
+    }
+
+    /// Returns the index of the sequence with the given name or alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+    ///
+    /// let sequence_dictionary: SequenceDictionary = [
+    ///     Sequence::new("chr1").set_aliases([b"1".to_vec()]),
+    ///     Sequence::new("chr2").set_aliases([b"2".to_vec()]),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(sequence_dictionary.get_index_of(b"chr2"), Some(1));
+    /// assert_eq!(sequence_dictionary.get_index_of(b"2"), Some(1));
+    /// assert!(sequence_dictionary.get_index_of(b"chr3").is_none());
+    /// ```
+    pub fn get_index_of(&self, name: &[u8]) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    /// Returns the sequence with the given name or alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+    ///
+    /// let sequence_dictionary: SequenceDictionary =
+    ///     [Sequence::new("chr1").set_aliases([b"1".to_vec()])].into_iter().collect();
+    ///
+    /// let sequence = sequence_dictionary.get(b"1").expect("missing sequence");
+    /// assert_eq!(sequence.name(), b"chr1");
+    /// ```
+    pub fn get(&self, name: &[u8]) -> Option<&Sequence> {
+        self.get_index_of(name).map(|i| &self.sequences[i])
+    }
+
+    /// Compares this dictionary to another dictionary.
+    ///
+    /// Sequences are matched by name or alias. This reports sequences that are only in one of the
+    /// dictionaries and matched sequences that have different lengths or MD5 checksums. Lengths
+    /// and MD5 checksums are only compared when they are set in both dictionaries.
+    ///
+    /// To check the compatibility of more than two inputs, e.g., a BAM, VCF, and FASTA, compare
+    /// each to the same dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{
+    ///     sequence_dictionary::{Difference, Sequence},
+    ///     SequenceDictionary,
+    /// };
+    ///
+    /// let reference: SequenceDictionary = [
+    ///     Sequence::new("sq0").set_length(8),
+    ///     Sequence::new("sq1").set_length(13),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let alignments: SequenceDictionary = [
+    ///     Sequence::new("sq0").set_length(8),
+    ///     Sequence::new("sq1").set_length(21),
+    ///     Sequence::new("sq2").set_length(34),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     reference.compare(&alignments),
+    ///     [
+    ///         Difference::LengthMismatch {
+    ///             name: b"sq1".to_vec(),
+    ///             first: 13,
+    ///             second: 21,
+    ///         },
+    ///         Difference::OnlyInSecond(b"sq2".to_vec()),
+    ///     ]
+    /// );
+    ///
+    /// assert!(reference.compare(&reference).is_empty());
+    /// ```
+    pub fn compare(&self, other: &Self) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        let mut is_matched = vec![false; other.len()];
+
+        for a in self.iter() {
+            let j = other.get_index_of(a.name()).or_else(|| {
+                a.aliases()
+                    .iter()
+                    .find_map(|alias| other.get_index_of(alias))
+            });
+
+            let Some(j) = j else {
+                differences.push(Difference::OnlyInFirst(a.name().to_vec()));
+                continue;
+            };
+
+            is_matched[j] = true;
+            let b = &other.sequences[j];
+
+            if let (Some(first), Some(second)) = (a.length(), b.length()) {
+                if first != second {
+                    differences.push(Difference::LengthMismatch {
+                        name: a.name().to_vec(),
+                        first,
+                        second,
+                    });
+                }
+            }
+
+            if let (Some(first), Some(second)) = (a.md5_checksum(), b.md5_checksum()) {
+                if first != second {
+                    differences.push(Difference::Md5ChecksumMismatch {
+                        name: a.name().to_vec(),
+                        first,
+                        second,
+                    });
+                }
+            }
+        }
+
+        for (b, _) in other
+            .iter()
+            .zip(is_matched)
+            .filter(|(_, is_matched)| !is_matched)
+        {
+            differences.push(Difference::OnlyInSecond(b.name().to_vec()));
+        }
+
+        differences
+    }
+
+    fn index(&mut self, i: usize, sequence: &Sequence) {
+        // Names take precedence over aliases.
+        for alias in sequence.aliases() {
+            self.indices.entry(alias.clone()).or_insert(i);
+        }
+
+        self.indices.insert(sequence.name().to_vec(), i);
+    }
+
+    fn reindex(&mut self) {
+        let sequences = mem::take(&mut self.sequences);
+        self.indices.clear();
+
+        for (i, sequence) in sequences.iter().enumerate() {
+            self.index(i, sequence);
+        }
+
+        self.sequences = sequences;
+    }
+}
+
+impl Extend<Sequence> for SequenceDictionary {
+    fn extend<T: IntoIterator<Item = Sequence>>(&mut self, iter: T) {
+        for sequence in iter {
+            self.insert(sequence);
+        }
+    }
+}
+
+impl FromIterator<Sequence> for SequenceDictionary {
+    fn from_iter<T: IntoIterator<Item = Sequence>>(iter: T) -> Self {
+        let mut sequence_dictionary = Self::new();
+        sequence_dictionary.extend(iter);
+        sequence_dictionary
+    }
+}
+
+/// A difference between two sequence dictionaries.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Difference {
+    /// The sequence is only in the first dictionary.
+    OnlyInFirst(Vec<u8>),
+    /// The sequence is only in the second dictionary.
+    OnlyInSecond(Vec<u8>),
+    /// The lengths of the sequence differ.
+    LengthMismatch {
+        /// The name of the sequence in the first dictionary.
+        name: Vec<u8>,
+        /// The length in the first dictionary.
+        first: usize,
+        /// The length in the second dictionary.
+        second: usize,
+    },
+    /// The MD5 checksums of the sequence differ.
+    Md5ChecksumMismatch {
+        /// The name of the sequence in the first dictionary.
+        name: Vec<u8>,
+        /// The MD5 checksum in the first dictionary.
+        first: [u8; 16],
+        /// The MD5 checksum in the second dictionary.
+        second: [u8; 16],
+    },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_name(f: &mut fmt::Formatter<'_>, name: &[u8]) -> fmt::Result {
+            let name = str::from_utf8(name).map_err(|_| fmt::Error)?;
+            f.write_str(name)
+        }
+
+        fn write_md5_checksum(f: &mut fmt::Formatter<'_>, md5_checksum: &[u8]) -> fmt::Result {
+            for b in md5_checksum {
+                write!(f, "{b:02x}")?;
+            }
+
+            Ok(())
+        }
+
+        match self {
+            Self::OnlyInFirst(name) => {
+                write_name(f, name)?;
+                f.write_str(": only in first")
+            }
+            Self::OnlyInSecond(name) => {
+                write_name(f, name)?;
+                f.write_str(": only in second")
+            }
+            Self::LengthMismatch {
+                name,
+                first,
+                second,
+            } => {
+                write_name(f, name)?;
+                write!(f, ": length mismatch: {first} != {second}")
+            }
+            Self::Md5ChecksumMismatch {
+                name,
+                first,
+                second,
+            } => {
+                write_name(f, name)?;
+                f.write_str(": MD5 checksum mismatch: ")?;
+                write_md5_checksum(f, first)?;
+                f.write_str(" != ")?;
+                write_md5_checksum(f, second)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut sequence_dictionary = SequenceDictionary::new();
+
+        sequence_dictionary.insert(Sequence::new("chr1").set_aliases([b"1".to_vec()]));
+        sequence_dictionary.insert(Sequence::new("chr2"));
+        assert_eq!(sequence_dictionary.get_index_of(b"1"), Some(0));
+
+        let old_sequence =
+            sequence_dictionary.insert(Sequence::new("chr1").set_aliases([b"01".to_vec()]));
+        assert_eq!(
+            old_sequence,
+            Some(Sequence::new("chr1").set_aliases([b"1".to_vec()]))
+        );
+
+        assert_eq!(sequence_dictionary.len(), 2);
+        assert_eq!(sequence_dictionary.get_index_of(b"chr1"), Some(0));
+        assert_eq!(sequence_dictionary.get_index_of(b"01"), Some(0));
+        assert!(sequence_dictionary.get_index_of(b"1").is_none());
+
+        // An alias does not shadow a name.
+        sequence_dictionary.insert(Sequence::new("chr3").set_aliases([b"chr2".to_vec()]));
+        assert_eq!(sequence_dictionary.get_index_of(b"chr2"), Some(1));
+    }
+
+    #[test]
+    fn test_compare() {
+        let a: SequenceDictionary = [
+            Sequence::new("chr1")
+                .set_length(8)
+                .set_md5_checksum([0; 16]),
+            Sequence::new("chr2").set_length(13),
+            Sequence::new("chr3"),
+        ]
+        .into_iter()
+        .collect();
+
+        let b: SequenceDictionary = [
+            Sequence::new("1")
+                .set_length(8)
+                .set_md5_checksum([1; 16])
+                .set_aliases([b"chr1".to_vec()]),
+            Sequence::new("chr2"),
+            Sequence::new("chrM").set_length(21),
+        ]
+        .into_iter()
+        .collect();
+
+        let differences = a.compare(&b);
+
+        assert_eq!(
+            differences,
+            [
+                Difference::Md5ChecksumMismatch {
+                    name: b"chr1".to_vec(),
+                    first: [0; 16],
+                    second: [1; 16],
+                },
+                Difference::OnlyInFirst(b"chr3".to_vec()),
+                Difference::OnlyInSecond(b"chrM".to_vec()),
+            ]
+        );
+
+        assert_eq!(
+            differences[0].to_string(),
+            "chr1: MD5 checksum mismatch: 00000000000000000000000000000000 != 01010101010101010101010101010101"
+        );
+        assert_eq!(differences[1].to_string(), "chr3: only in first");
+    }
+}
@@ -0,0 +1,131 @@
+/// A sequence in a sequence dictionary.
+///
+/// This describes a reference sequence by its name and, if known, its length, MD5 checksum, and
+/// alternative names.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sequence {
+    name: Vec<u8>,
+    length: Option<usize>,
+    md5_checksum: Option<[u8; 16]>,
+    aliases: Vec<Vec<u8>>,
+}
+
+impl Sequence {
+    /// Creates a sequence with a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0");
+    /// ```
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<Vec<u8>>,
+    {
+        Self {
+            name: name.into(),
+            length: None,
+            md5_checksum: None,
+            aliases: Vec::new(),
+        }
+    }
+
+    /// Sets the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0").set_length(13);
+    /// assert_eq!(sequence.length(), Some(13));
+    /// ```
+    pub fn set_length(mut self, length: usize) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the MD5 checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let md5_checksum = [0; 16];
+    /// let sequence = Sequence::new("sq0").set_md5_checksum(md5_checksum);
+    /// assert_eq!(sequence.md5_checksum(), Some(md5_checksum));
+    /// ```
+    pub fn set_md5_checksum(mut self, md5_checksum: [u8; 16]) -> Self {
+        self.md5_checksum = Some(md5_checksum);
+        self
+    }
+
+    /// Sets the alternative names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("chr1").set_aliases([b"1".to_vec()]);
+    /// assert_eq!(sequence.aliases(), [b"1"]);
+    /// ```
+    pub fn set_aliases<I>(mut self, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        self.aliases = aliases.into_iter().collect();
+        self
+    }
+
+    /// Returns the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0");
+    /// assert_eq!(sequence.name(), b"sq0");
+    /// ```
+    pub fn name(&self) -> &[u8] {
+        &self.name
+    }
+
+    /// Returns the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0");
+    /// assert!(sequence.length().is_none());
+    /// ```
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+
+    /// Returns the MD5 checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0");
+    /// assert!(sequence.md5_checksum().is_none());
+    /// ```
+    pub fn md5_checksum(&self) -> Option<[u8; 16]> {
+        self.md5_checksum
+    }
+
+    /// Returns the alternative names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0");
+    /// assert!(sequence.aliases().is_empty());
+    /// ```
+    pub fn aliases(&self) -> &[Vec<u8>] {
+        &self.aliases
+    }
+}
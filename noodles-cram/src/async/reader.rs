@@ -3,6 +3,7 @@ mod data_container;
 mod header_container;
 mod num;
 mod query;
+mod query_regions;
 mod records;
 
 pub use self::crc_reader::CrcReader;
@@ -14,7 +15,10 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
 
-use crate::{crai, file_definition::Version, DataContainer, FileDefinition, Record};
+use crate::{
+    crai, file_definition::Version, io::reader::resolve_region, DataContainer, FileDefinition,
+    Record,
+};
 
 /// An async CRAM reader.
 pub struct Reader<R> {
@@ -295,15 +299,7 @@ where
     ) -> io::Result<impl Stream<Item = io::Result<Record>> + '_> {
         use self::query::query;
 
-        let reference_sequence_id = header
-            .reference_sequences()
-            .get_index_of(region.name())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "invalid reference sequence name",
-                )
-            })?;
+        let reference_sequence_id = resolve_region(header, region)?;
 
         Ok(query(
             self,
@@ -314,6 +310,60 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns a stream over records that intersect any of the given regions.
+    ///
+    /// Containers are selected from the index across all regions, so each container is read
+    /// once, and each record is returned once, even when regions overlap. Each record is paired
+    /// with the indices of the regions it intersects.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_cram::{self as cram, crai};
+    /// use noodles_fasta as fasta;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.cram").await.map(cram::AsyncReader::new)?;
+    /// reader.read_file_definition().await?;
+    ///
+    /// let repository = fasta::Repository::default();
+    /// let header = reader.read_file_header().await?.parse()?;
+    /// let index = crai::r#async::read("sample.cram.crai").await?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&repository, &header, &index, &regions)?;
+    ///
+    /// while let Some((record, region_indices)) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_regions<'a>(
+        &'a mut self,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+        index: &crai::Index,
+        regions: &[Region],
+    ) -> io::Result<impl Stream<Item = io::Result<(Record, Vec<usize>)>> + 'a> {
+        use self::query_regions::query_regions;
+
+        let intervals = regions
+            .iter()
+            .map(|region| resolve_region(header, region).map(|id| (id, region.interval())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(query_regions(
+            self,
+            reference_sequence_repository,
+            header,
+            index,
+            intervals,
+        ))
+    }
 }
 
 async fn read_magic_number<R>(reader: &mut R) -> io::Result<()>
@@ -88,10 +88,26 @@ where
         return Some(Err(e));
     }
 
-    let container = match ctx.reader.read_data_container().await {
-        Ok(Some(c)) => c,
+    match read_container_records(ctx.reader, ctx.reference_sequence_repository, ctx.header).await {
+        Ok(Some(records)) => ctx.records = records.into_iter(),
         Ok(None) => return None,
         Err(e) => return Some(Err(e)),
+    }
+
+    Some(Ok(()))
+}
+
+pub(super) async fn read_container_records<R>(
+    reader: &mut Reader<R>,
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+) -> io::Result<Option<Vec<Record>>>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let container = match reader.read_data_container().await? {
+        Some(c) => c,
+        None => return Ok(None),
     };
 
     let records = container
@@ -102,8 +118,8 @@ where
 
             slice.records(compression_header).and_then(|mut records| {
                 slice.resolve_records(
-                    ctx.reference_sequence_repository,
-                    ctx.header,
+                    reference_sequence_repository,
+                    header,
                     compression_header,
                     &mut records,
                 )?;
@@ -111,18 +127,7 @@ where
                 Ok(records)
             })
         })
-        .collect::<Result<Vec<_>, _>>();
-
-    let records = match records {
-        Ok(records) => records,
-        Err(e) => return Some(Err(e)),
-    };
-
-    ctx.records = records
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>()
-        .into_iter();
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Some(Ok(()))
+    Ok(Some(records.into_iter().flatten().collect()))
 }
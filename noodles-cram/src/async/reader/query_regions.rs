@@ -0,0 +1,86 @@
+use std::{io::SeekFrom, vec};
+
+use futures::{stream, Stream};
+use noodles_core::region::Interval;
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+use tokio::io::{self, AsyncRead, AsyncSeek};
+
+use super::{query::read_container_records, Reader};
+use crate::{
+    crai,
+    io::reader::{container_offsets, update_region_indices},
+    Record,
+};
+
+struct Context<'a, R> {
+    reader: &'a mut Reader<R>,
+
+    reference_sequence_repository: &'a fasta::Repository,
+    header: &'a sam::Header,
+
+    offsets: vec::IntoIter<u64>,
+
+    intervals: Vec<(usize, Interval)>,
+
+    records: vec::IntoIter<Record>,
+}
+
+pub(super) fn query_regions<'a, R>(
+    reader: &'a mut Reader<R>,
+    reference_sequence_repository: &'a fasta::Repository,
+    header: &'a sam::Header,
+    index: &crai::Index,
+    intervals: Vec<(usize, Interval)>,
+) -> impl Stream<Item = io::Result<(Record, Vec<usize>)>> + 'a
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let offsets = container_offsets(index, &intervals);
+
+    let ctx = Context {
+        reader,
+
+        reference_sequence_repository,
+        header,
+
+        offsets: offsets.into_iter(),
+
+        intervals,
+
+        records: Vec::new().into_iter(),
+    };
+
+    Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            match ctx.records.next() {
+                Some(r) => {
+                    let mut region_indices = Vec::new();
+                    update_region_indices(&r, &ctx.intervals, &mut region_indices);
+
+                    if !region_indices.is_empty() {
+                        return Ok(Some(((r, region_indices), ctx)));
+                    }
+                }
+                None => {
+                    let Some(offset) = ctx.offsets.next() else {
+                        return Ok(None);
+                    };
+
+                    ctx.reader.seek(SeekFrom::Start(offset)).await?;
+
+                    match read_container_records(
+                        ctx.reader,
+                        ctx.reference_sequence_repository,
+                        ctx.header,
+                    )
+                    .await?
+                    {
+                        Some(records) => ctx.records = records.into_iter(),
+                        None => return Ok(None),
+                    }
+                }
+            }
+        }
+    }))
+}
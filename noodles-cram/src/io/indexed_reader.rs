@@ -11,7 +11,7 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::{
-    reader::{Query, Records},
+    reader::{Query, QueryRegions, Records},
     Reader,
 };
 use crate::{crai, DataContainer, FileDefinition};
@@ -97,4 +97,15 @@ where
     ) -> io::Result<Query<'a, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'a, R>> {
+        self.inner.query_regions(header, &self.index, regions)
+    }
 }
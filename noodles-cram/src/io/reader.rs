@@ -6,10 +6,14 @@ pub(crate) mod data_container;
 pub(crate) mod header_container;
 pub(crate) mod num;
 mod query;
+mod query_regions;
 pub(crate) mod record;
 mod records;
 
-pub use self::{builder::Builder, query::Query, records::Records};
+pub use self::{builder::Builder, query::Query, query_regions::QueryRegions, records::Records};
+
+#[cfg(feature = "async")]
+pub(crate) use self::query_regions::{container_offsets, update_region_indices};
 
 use std::io::{self, Read, Seek, SeekFrom};
 
@@ -306,15 +310,7 @@ where
         index: &'a crai::Index,
         region: &Region,
     ) -> io::Result<Query<'_, R>> {
-        let reference_sequence_id = header
-            .reference_sequences()
-            .get_index_of(region.name())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "invalid reference sequence name",
-                )
-            })?;
+        let reference_sequence_id = resolve_region(header, region)?;
 
         Ok(Query::new(
             self,
@@ -324,6 +320,60 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Each container is read at most once, and each record is returned at most once, even when
+    /// regions overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram::{self as cram, crai};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    /// let index = crai::read("sample.cram.crai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some(result) = query.next() {
+    ///     let record = result?;
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        index: &crai::Index,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'a, R>> {
+        let intervals = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header, region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(QueryRegions::new(self, header, index, intervals))
+    }
+}
+
+pub(crate) fn resolve_region(header: &sam::Header, region: &Region) -> io::Result<usize> {
+    header
+        .reference_sequences()
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid reference sequence name",
+            )
+        })
 }
 
 impl<R> sam::alignment::io::Read<R> for Reader<R>
@@ -64,46 +64,50 @@ where
             return Some(Err(e));
         }
 
-        let container = match self.reader.read_data_container() {
-            Ok(Some(c)) => c,
+        self.records = match read_container_records(self.reader, self.header) {
+            Ok(Some(records)) => records.into_iter(),
             Ok(None) => return None,
             Err(e) => return Some(Err(e)),
         };
 
-        let records = container
-            .slices()
-            .iter()
-            .map(|slice| {
-                let compression_header = container.compression_header();
-
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
-            })
-            .collect::<Result<Vec<_>, _>>();
-
-        let records = match records {
-            Ok(records) => records,
-            Err(e) => return Some(Err(e)),
-        };
-
-        self.records = records
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
-
         Some(Ok(()))
     }
 }
 
+// Reads and resolves the records of the data container at the current stream position.
+pub(super) fn read_container_records<R>(
+    reader: &mut Reader<R>,
+    header: &sam::Header,
+) -> io::Result<Option<Vec<Record>>>
+where
+    R: Read,
+{
+    let Some(container) = reader.read_data_container()? else {
+        return Ok(None);
+    };
+
+    let compression_header = container.compression_header();
+
+    let records = container
+        .slices()
+        .iter()
+        .map(|slice| {
+            slice.records(compression_header).and_then(|mut records| {
+                slice.resolve_records(
+                    reader.reference_sequence_repository(),
+                    header,
+                    compression_header,
+                    &mut records,
+                )?;
+
+                Ok(records)
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(Some(records.into_iter().flatten().collect()))
+}
+
 impl<'a, R> Iterator for Query<'a, R>
 where
     R: Read + Seek,
@@ -0,0 +1,264 @@
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    vec,
+};
+
+use noodles_core::region::Interval;
+use noodles_sam as sam;
+
+use super::{query::read_container_records, Reader};
+use crate::{crai, Record};
+
+/// An iterator over records of a CRAM reader that intersect any of a list of regions.
+///
+/// Each container is read at most once, and each record is returned at most once. The indices of
+/// the regions the last returned record intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`Reader::query_regions`].
+pub struct QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    reader: &'a mut Reader<R>,
+    header: &'a sam::Header,
+    offsets: vec::IntoIter<u64>,
+    intervals: Vec<(usize, Interval)>,
+    records: vec::IntoIter<Record>,
+    region_indices: Vec<usize>,
+}
+
+impl<'a, R> QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'a mut Reader<R>,
+        header: &'a sam::Header,
+        index: &crai::Index,
+        intervals: Vec<(usize, Interval)>,
+    ) -> Self {
+        let offsets = container_offsets(index, &intervals);
+
+        Self {
+            reader,
+            header,
+            offsets: offsets.into_iter(),
+            intervals,
+            records: Vec::new().into_iter(),
+            region_indices: Vec::new(),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        &self.region_indices
+    }
+
+    fn read_next_container(&mut self) -> Option<io::Result<()>> {
+        let offset = self.offsets.next()?;
+
+        if let Err(e) = self.reader.seek(SeekFrom::Start(offset)) {
+            return Some(Err(e));
+        }
+
+        self.records = match read_container_records(self.reader, self.header) {
+            Ok(Some(records)) => records.into_iter(),
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e)),
+        };
+
+        Some(Ok(()))
+    }
+}
+
+impl<'a, R> Iterator for QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next() {
+                Some(record) => {
+                    update_region_indices(&record, &self.intervals, &mut self.region_indices);
+
+                    if !self.region_indices.is_empty() {
+                        return Some(Ok(record));
+                    }
+                }
+                None => match self.read_next_container() {
+                    Some(Ok(())) => {}
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => return None,
+                },
+            }
+        }
+    }
+}
+
+/// Returns the sorted, deduplicated offsets of the containers that intersect any of the given
+/// intervals.
+pub(crate) fn container_offsets(index: &crai::Index, intervals: &[(usize, Interval)]) -> Vec<u64> {
+    let mut offsets: Vec<_> = index
+        .iter()
+        .filter(|record| {
+            intervals
+                .iter()
+                .any(|&(id, interval)| index_record_intersects(record, id, interval))
+        })
+        .map(|record| record.offset())
+        .collect();
+
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    offsets
+}
+
+/// Replaces the given region indices with the indices of the intervals the record intersects.
+pub(crate) fn update_region_indices(
+    record: &Record,
+    intervals: &[(usize, Interval)],
+    region_indices: &mut Vec<usize>,
+) {
+    region_indices.clear();
+
+    let (Some(id), Some(start), Some(end)) = (
+        record.reference_sequence_id(),
+        record.alignment_start(),
+        record.alignment_end(),
+    ) else {
+        return;
+    };
+
+    let alignment_interval = (start..=end).into();
+
+    for (i, &(reference_sequence_id, interval)) in intervals.iter().enumerate() {
+        if id == reference_sequence_id && interval.intersects(alignment_interval) {
+            region_indices.push(i);
+        }
+    }
+}
+
+fn index_record_intersects(
+    record: &crai::Record,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> bool {
+    if record.reference_sequence_id() != Some(reference_sequence_id) {
+        return false;
+    }
+
+    let Some(start) = record.alignment_start() else {
+        return false;
+    };
+
+    let end = usize::from(start) + record.alignment_span().max(1) - 1;
+
+    match noodles_core::Position::new(end) {
+        Some(end) => interval.intersects((start..=end).into()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::{Position, Region};
+    use noodles_fasta as fasta;
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+    use crate::io::writer;
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        const SEQUENCE: &[u8] = b"ACGTACGTACGT";
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(SEQUENCE.to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(SEQUENCE.len())?),
+            )
+            .build();
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        for (name, start) in [("r0", 1), ("r1", 5), ("r2", 9)] {
+            let record = RecordBuf::builder()
+                .set_name(name.as_bytes().into())
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(SEQUENCE[start - 1..start + 3].to_vec().into())
+                .set_quality_scores(vec![45; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.finish(&header)?;
+        let data = writer.get_ref().clone();
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(Cursor::new(data));
+
+        reader.read_header()?;
+        let offset = reader.position()?;
+
+        let index = vec![crai::Record::new(
+            Some(0),
+            Position::new(1),
+            SEQUENCE.len(),
+            offset,
+            0,
+            0,
+        )];
+
+        let regions: [Region; 3] = ["sq0:2-6".parse()?, "sq0:6-7".parse()?, "sq0:12".parse()?];
+        let mut query = reader.query_regions(&header, &index, &regions)?;
+
+        let mut actual = Vec::new();
+
+        while let Some(result) = query.next() {
+            let record = result?;
+            actual.push((record.alignment_start(), query.region_indices().to_vec()));
+        }
+
+        assert_eq!(
+            actual,
+            [
+                (Position::new(1), vec![0]),
+                (Position::new(5), vec![0, 1]),
+                (Position::new(9), vec![2]),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -2,7 +2,9 @@
 
 pub mod index;
 mod indexer;
+pub(crate) mod merge;
 mod reference_sequence;
+pub mod validation;
 
 use std::io;
 
@@ -10,7 +12,9 @@ use noodles_bgzf as bgzf;
 use noodles_core::region::Interval;
 
 use self::index::{reference_sequence::bin::Chunk, Header};
-pub use self::{index::Index, indexer::Indexer, reference_sequence::ReferenceSequence};
+pub use self::{
+    index::Index, indexer::Indexer, merge::merge, reference_sequence::ReferenceSequence,
+};
 
 /// A binning index.
 pub trait BinningIndex {
@@ -72,6 +76,51 @@ where
     }
 }
 
+/// Returns the merged chunks that overlap with any of the given intervals.
+///
+/// Each interval is given with the ID of its reference sequence. The chunks of all intervals are
+/// merged (see [`merge_chunks`]), so reading the returned chunks reads each record at most once,
+/// even when intervals overlap or are adjacent.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+/// use noodles_core::Position;
+/// use noodles_csi::{
+///     self as csi,
+///     binning_index::{index::reference_sequence::bin::Chunk, query_intervals, Indexer},
+/// };
+///
+/// let chunk = Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(144));
+///
+/// let mut indexer = Indexer::default();
+/// indexer.add_record(Some((0, Position::try_from(8)?, Position::try_from(13)?, true)), chunk)?;
+/// let index: csi::Index = indexer.build(1);
+///
+/// let intervals = [
+///     (0, (Position::try_from(5)?..=Position::try_from(10)?).into()),
+///     (0, (Position::try_from(8)?..=Position::try_from(21)?).into()),
+/// ];
+///
+/// let chunks = query_intervals(&index, &intervals)?;
+/// assert_eq!(chunks, [chunk]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn query_intervals<I>(index: &I, intervals: &[(usize, Interval)]) -> io::Result<Vec<Chunk>>
+where
+    I: BinningIndex + ?Sized,
+{
+    let mut chunks = Vec::new();
+
+    for &(reference_sequence_id, interval) in intervals {
+        let interval_chunks = index.query(reference_sequence_id, interval)?;
+        chunks.extend(interval_chunks);
+    }
+
+    Ok(merge_chunks(&chunks))
+}
+
 /// Merges a list of chunks into a list of non-overlapping chunks.
 ///
 /// This is the same as calling [`optimize_chunks`] with a `min_offset` of 0.
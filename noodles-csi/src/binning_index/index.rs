@@ -3,8 +3,14 @@
 mod builder;
 pub mod header;
 pub mod reference_sequence;
+pub mod statistics;
 
-pub use self::{builder::Builder, header::Header, reference_sequence::ReferenceSequence};
+pub use self::{
+    builder::Builder,
+    header::Header,
+    reference_sequence::ReferenceSequence,
+    statistics::{RegionEstimate, Statistics},
+};
 
 use std::io;
 
@@ -51,6 +57,150 @@ where
     pub fn reference_sequences(&self) -> &[ReferenceSequence<I>] {
         &self.reference_sequences
     }
+
+    /// Returns statistics of the index.
+    ///
+    /// This summarizes, for each reference sequence, the number of bins, chunks, and reference
+    /// sequence index entries, and its metadata, which includes mapped and unmapped record counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::default();
+    /// let statistics = index.statistics();
+    ///
+    /// for (i, reference_sequence_statistics) in statistics.reference_sequences().iter().enumerate() {
+    ///     println!(
+    ///         "{i}\t{}\t{}",
+    ///         reference_sequence_statistics.bin_count(),
+    ///         reference_sequence_statistics.chunk_count()
+    ///     );
+    /// }
+    /// ```
+    pub fn statistics(&self) -> Statistics {
+        use self::statistics::ReferenceSequenceStatistics;
+        use super::ReferenceSequence as _;
+
+        let reference_sequences = self
+            .reference_sequences
+            .iter()
+            .map(|reference_sequence| {
+                let bins = reference_sequence.bins();
+
+                ReferenceSequenceStatistics {
+                    bin_count: bins.len(),
+                    chunk_count: bins.values().map(|bin| bin.chunks().len()).sum(),
+                    index_entry_count: reference_sequence.index().entry_count(),
+                    metadata: reference_sequence.metadata().cloned(),
+                }
+            })
+            .collect();
+
+        Statistics {
+            reference_sequences,
+            unplaced_unmapped_record_count: self.unplaced_unmapped_record_count,
+        }
+    }
+
+    /// Estimates the size of the given region without reading the associated file.
+    ///
+    /// The size is computed from the chunks of the bins that intersect the region. The record
+    /// count is scaled from the reference sequence metadata, if available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{self as csi, binning_index::{index::reference_sequence::bin::Chunk, Indexer}};
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    ///
+    /// let mut indexer = Indexer::default();
+    /// indexer.add_record(
+    ///     Some((0, start, end, true)),
+    ///     Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(55 << 16)),
+    /// )?;
+    /// let index: csi::Index = indexer.build(1);
+    ///
+    /// let estimate = index.estimate(0, (start..=end).into())?;
+    /// assert_eq!(estimate.chunk_count(), 1);
+    /// assert_eq!(estimate.compressed_size(), 55);
+    /// assert_eq!(estimate.record_count(), Some(1));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn estimate(
+        &self,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<RegionEstimate> {
+        use super::ReferenceSequence as _;
+
+        let chunks = self.query(reference_sequence_id, interval)?;
+
+        let compressed_size = chunks
+            .iter()
+            .map(|chunk| chunk_compressed_size(*chunk))
+            .sum();
+
+        let record_count = self.reference_sequences[reference_sequence_id]
+            .metadata()
+            .map(|metadata| {
+                let total_record_count =
+                    metadata.mapped_record_count() + metadata.unmapped_record_count();
+
+                let reference_sequence_chunk =
+                    Chunk::new(metadata.start_position(), metadata.end_position());
+
+                if chunks.is_empty() {
+                    return 0;
+                }
+
+                let reference_sequence_compressed_size =
+                    chunk_compressed_size(reference_sequence_chunk);
+
+                // If the reference sequence is in a single block, so are the chunks, and the
+                // uncompressed positions are comparable.
+                let (size, total_size) = if reference_sequence_compressed_size == 0 {
+                    (
+                        chunks
+                            .iter()
+                            .map(|chunk| chunk_uncompressed_size(*chunk))
+                            .sum(),
+                        chunk_uncompressed_size(reference_sequence_chunk),
+                    )
+                } else {
+                    (compressed_size, reference_sequence_compressed_size)
+                };
+
+                let n = if total_size == 0 {
+                    total_record_count
+                } else {
+                    let n =
+                        u128::from(total_record_count) * u128::from(size) / u128::from(total_size);
+
+                    u64::try_from(n).unwrap_or(u64::MAX)
+                };
+
+                // Each chunk has at least one record.
+                let min_record_count = u64::try_from(chunks.len()).unwrap_or(u64::MAX);
+
+                n.max(min_record_count).min(total_record_count)
+            });
+
+        Ok(RegionEstimate {
+            chunk_count: chunks.len(),
+            compressed_size,
+            record_count,
+        })
+    }
+
+    pub(crate) fn into_reference_sequences(self) -> Vec<ReferenceSequence<I>> {
+        self.reference_sequences
+    }
 }
 
 impl<I> Default for Index<I>
@@ -156,7 +306,24 @@ where
     }
 }
 
-fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
+fn chunk_compressed_size(chunk: Chunk) -> u64 {
+    chunk
+        .end()
+        .compressed()
+        .saturating_sub(chunk.start().compressed())
+}
+
+// The chunk is expected to start and end in the same block.
+fn chunk_uncompressed_size(chunk: Chunk) -> u64 {
+    u64::from(
+        chunk
+            .end()
+            .uncompressed()
+            .saturating_sub(chunk.start().uncompressed()),
+    )
+}
+
+pub(super) fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
     assert!(min_shift > 0);
     let n = (1 << (usize::from(min_shift) + 3 * usize::from(depth))) - 1;
     Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
@@ -177,4 +344,159 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_statistics() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::binning_index::Indexer;
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            Some((0, start, end, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(5 << 16),
+            ),
+        )?;
+
+        indexer.add_record(
+            Some((0, start, end, false)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(8 << 16),
+                bgzf::VirtualPosition::from(13 << 16),
+            ),
+        )?;
+
+        indexer.add_record(
+            None,
+            Chunk::new(
+                bgzf::VirtualPosition::from(13 << 16),
+                bgzf::VirtualPosition::from(21 << 16),
+            ),
+        )?;
+
+        let index: crate::Index = indexer.build(2);
+        let statistics = index.statistics();
+
+        assert_eq!(statistics.unplaced_unmapped_record_count(), Some(1));
+        assert_eq!(statistics.bin_count(), 1);
+        assert_eq!(statistics.chunk_count(), 2);
+
+        let reference_sequences = statistics.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+
+        let metadata = reference_sequences[0]
+            .metadata()
+            .ok_or("missing metadata")?;
+        assert_eq!(metadata.mapped_record_count(), 1);
+        assert_eq!(metadata.unmapped_record_count(), 1);
+
+        assert_eq!(reference_sequences[1].bin_count(), 0);
+        assert!(reference_sequences[1].metadata().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::binning_index::Indexer;
+
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            Some((0, Position::try_from(8)?, Position::try_from(13)?, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(10 << 16),
+            ),
+        )?;
+
+        indexer.add_record(
+            Some((
+                0,
+                Position::try_from(100000)?,
+                Position::try_from(100010)?,
+                true,
+            )),
+            Chunk::new(
+                bgzf::VirtualPosition::from(10 << 16),
+                bgzf::VirtualPosition::from(20 << 16),
+            ),
+        )?;
+
+        let index: crate::Index = indexer.build(1);
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        let estimate = index.estimate(0, (start..=end).into())?;
+        assert_eq!(estimate.chunk_count(), 1);
+        assert_eq!(estimate.compressed_size(), 10);
+        assert_eq!(estimate.record_count(), Some(1));
+
+        let estimate = index.estimate(0, Interval::from(..))?;
+        assert_eq!(estimate.compressed_size(), 20);
+        assert_eq!(estimate.record_count(), Some(2));
+
+        let start = Position::try_from(50000)?;
+        let end = Position::try_from(60000)?;
+        let estimate = index.estimate(0, (start..=end).into())?;
+        assert_eq!(estimate.chunk_count(), 0);
+        assert_eq!(estimate.compressed_size(), 0);
+        assert_eq!(estimate.record_count(), Some(0));
+
+        assert!(index.estimate(1, Interval::from(..)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_with_chunks_in_a_single_block() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::binning_index::Indexer;
+
+        fn build_index(
+            chunks: [(u64, u64); 2],
+        ) -> Result<crate::Index, Box<dyn std::error::Error>> {
+            let mut indexer = Indexer::default();
+
+            for ((start, end), (record_start, record_end)) in
+                chunks.into_iter().zip([(8, 13), (100000, 100010)])
+            {
+                indexer.add_record(
+                    Some((
+                        0,
+                        Position::try_from(record_start)?,
+                        Position::try_from(record_end)?,
+                        true,
+                    )),
+                    Chunk::new(
+                        bgzf::VirtualPosition::from(start),
+                        bgzf::VirtualPosition::from(end),
+                    ),
+                )?;
+            }
+
+            Ok(indexer.build(1))
+        }
+
+        let start = Position::try_from(100000)?;
+        let end = Position::try_from(100010)?;
+        let interval = Interval::from(start..=end);
+
+        // The reference sequence is in a single block.
+        let index = build_index([(0, 100), (100, 200)])?;
+        let estimate = index.estimate(0, interval)?;
+        assert_eq!(estimate.compressed_size(), 0);
+        assert_eq!(estimate.record_count(), Some(1));
+
+        // The reference sequence spans blocks, but the region is in a single block.
+        let index = build_index([(0, 10 << 16), (10 << 16, (10 << 16) | 50)])?;
+        let estimate = index.estimate(0, interval)?;
+        assert_eq!(estimate.compressed_size(), 0);
+        assert_eq!(estimate.record_count(), Some(1));
+
+        Ok(())
+    }
 }
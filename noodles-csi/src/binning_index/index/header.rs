@@ -2,6 +2,7 @@
 
 mod builder;
 pub mod format;
+pub mod sequence_dictionary;
 
 pub use self::{builder::Builder, format::Format};
 
@@ -0,0 +1,86 @@
+//! Conversions between index header reference sequence names and a sequence dictionary.
+
+use std::io;
+
+use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+
+use super::ReferenceSequenceNames;
+
+/// Converts index header reference sequence names to a sequence dictionary.
+///
+/// Index headers only list names, so the resulting sequences have no lengths.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_csi::binning_index::index::header::{sequence_dictionary, ReferenceSequenceNames};
+///
+/// let reference_sequence_names: ReferenceSequenceNames =
+///     [String::from("sq0")].into_iter().collect();
+///
+/// let dictionary = sequence_dictionary::from_reference_sequence_names(&reference_sequence_names);
+/// assert_eq!(dictionary.get_index_of(b"sq0"), Some(0));
+/// ```
+pub fn from_reference_sequence_names(
+    reference_sequence_names: &ReferenceSequenceNames,
+) -> SequenceDictionary {
+    reference_sequence_names
+        .iter()
+        .map(|name| Sequence::new(name.as_str()))
+        .collect()
+}
+
+/// Converts a sequence dictionary to index header reference sequence names.
+///
+/// Every sequence name must be valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+/// use noodles_csi::binning_index::index::header::sequence_dictionary;
+///
+/// let dictionary: SequenceDictionary = [Sequence::new("sq0")].into_iter().collect();
+///
+/// let reference_sequence_names = sequence_dictionary::to_reference_sequence_names(&dictionary)?;
+/// assert_eq!(reference_sequence_names.get_index_of("sq0"), Some(0));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn to_reference_sequence_names(
+    dictionary: &SequenceDictionary,
+) -> io::Result<ReferenceSequenceNames> {
+    dictionary
+        .iter()
+        .map(|sequence| {
+            String::from_utf8(sequence.name().to_vec())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> io::Result<()> {
+        let reference_sequence_names: ReferenceSequenceNames =
+            [String::from("sq0"), String::from("sq1")]
+                .into_iter()
+                .collect();
+
+        let dictionary = from_reference_sequence_names(&reference_sequence_names);
+
+        let expected: SequenceDictionary = [Sequence::new("sq0"), Sequence::new("sq1")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(dictionary, expected);
+        assert_eq!(
+            to_reference_sequence_names(&dictionary)?,
+            reference_sequence_names
+        );
+
+        Ok(())
+    }
+}
@@ -227,6 +227,46 @@ where
 
         metadata.update(is_mapped, chunk);
     }
+
+    pub(crate) fn shift(&mut self, compressed_offset: u64) -> io::Result<()> {
+        for bin in self.bins.values_mut() {
+            bin.shift(compressed_offset)?;
+        }
+
+        self.index.shift(compressed_offset)?;
+
+        if let Some(metadata) = self.metadata.as_mut() {
+            metadata.shift(compressed_offset)?;
+        }
+
+        Ok(())
+    }
+
+    // `other` is expected to only contain records after the records in this reference sequence.
+    pub(crate) fn merge(&mut self, other: Self) -> io::Result<()> {
+        for (id, other_bin) in other.bins {
+            self.bins
+                .entry(id)
+                .and_modify(|bin| {
+                    for &chunk in other_bin.chunks() {
+                        bin.add_chunk(chunk);
+                    }
+                })
+                .or_insert(other_bin);
+        }
+
+        self.index.merge(other.index)?;
+
+        self.metadata = match (self.metadata.take(), other.metadata) {
+            (Some(mut metadata), Some(other_metadata)) => {
+                metadata.merge(&other_metadata);
+                Some(metadata)
+            }
+            (metadata, other_metadata) => metadata.or(other_metadata),
+        };
+
+        Ok(())
+    }
 }
 
 impl<I> binning_index::ReferenceSequence for ReferenceSequence<I>
@@ -253,7 +293,7 @@ pub(crate) fn parent_id(id: usize) -> Option<usize> {
 }
 
 // `CSIv1.pdf` (2020-07-21)
-fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
+pub(crate) fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
     // [beg, end), 0-based
     let beg = usize::from(start) - 1;
     let end = usize::from(end);
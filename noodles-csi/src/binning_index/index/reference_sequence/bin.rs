@@ -4,6 +4,10 @@ mod chunk;
 
 pub use self::chunk::Chunk;
 
+use std::io;
+
+use crate::binning_index::merge::shift_virtual_position;
+
 pub(crate) const METADATA_CHUNK_COUNT: u32 = 2;
 
 /// A binning index reference sequence bin.
@@ -74,6 +78,17 @@ impl Bin {
 
         self.chunks.push(chunk);
     }
+
+    pub(super) fn shift(&mut self, compressed_offset: u64) -> io::Result<()> {
+        for chunk in &mut self.chunks {
+            *chunk = Chunk::new(
+                shift_virtual_position(chunk.start(), compressed_offset)?,
+                shift_virtual_position(chunk.end(), compressed_offset)?,
+            );
+        }
+
+        Ok(())
+    }
 }
 
 // `CSIv1.pdf` (2020-07-21)
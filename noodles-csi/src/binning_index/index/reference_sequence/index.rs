@@ -3,6 +3,8 @@
 mod binned_index;
 mod linear_index;
 
+use std::io;
+
 use noodles_bgzf as bgzf;
 use noodles_core::Position;
 
@@ -20,4 +22,18 @@ pub trait Index {
 
     /// Adds a record to the index.
     fn update(&mut self, min_shift: u8, depth: u8, start: Position, end: Position, chunk: Chunk);
+
+    /// Returns the number of entries in the index.
+    ///
+    /// For a linear index, this is the number of windows. For a binned index, this is the number of
+    /// bins with a first record start position (`loffset`).
+    fn entry_count(&self) -> usize;
+
+    /// Shifts the compressed positions of all virtual positions by the given offset.
+    fn shift(&mut self, compressed_offset: u64) -> io::Result<()>;
+
+    /// Merges the index of records that are after the records in this index.
+    fn merge(&mut self, other: Self) -> io::Result<()>
+    where
+        Self: Sized;
 }
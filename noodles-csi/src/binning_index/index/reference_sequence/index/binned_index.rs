@@ -1,9 +1,14 @@
+use std::io;
+
 use indexmap::IndexMap;
 use noodles_bgzf as bgzf;
 use noodles_core::Position;
 
 use super::Index;
-use crate::binning_index::index::reference_sequence::{bin::Chunk, parent_id, reg2bin};
+use crate::binning_index::{
+    index::reference_sequence::{bin::Chunk, parent_id, reg2bin},
+    merge::shift_virtual_position,
+};
 
 /// A binned index.
 pub type BinnedIndex = IndexMap<usize, bgzf::VirtualPosition>;
@@ -42,6 +47,28 @@ impl Index for BinnedIndex {
             })
             .or_insert(chunk.start());
     }
+
+    fn entry_count(&self) -> usize {
+        self.len()
+    }
+
+    fn shift(&mut self, compressed_offset: u64) -> io::Result<()> {
+        for loffset in self.values_mut() {
+            *loffset = shift_virtual_position(*loffset, compressed_offset)?;
+        }
+
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) -> io::Result<()> {
+        for (bin_id, position) in other {
+            self.entry(bin_id)
+                .and_modify(|loffset| *loffset = (*loffset).min(position))
+                .or_insert(position);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -72,6 +99,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        let mut index: BinnedIndex = [
+            (0, bgzf::VirtualPosition::from(8)),
+            (9, bgzf::VirtualPosition::from(13)),
+        ]
+        .into_iter()
+        .collect();
+
+        let other: BinnedIndex = [
+            (0, bgzf::VirtualPosition::from(34)),
+            (2, bgzf::VirtualPosition::from(21)),
+        ]
+        .into_iter()
+        .collect();
+
+        index.merge(other)?;
+
+        let expected: BinnedIndex = [
+            (0, bgzf::VirtualPosition::from(8)),
+            (9, bgzf::VirtualPosition::from(13)),
+            (2, bgzf::VirtualPosition::from(21)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(index, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_last_first_start_position() {
         let index: BinnedIndex = [
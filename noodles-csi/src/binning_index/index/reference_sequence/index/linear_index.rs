@@ -1,8 +1,10 @@
+use std::io;
+
 use noodles_bgzf as bgzf;
 use noodles_core::Position;
 
 use super::Index;
-use crate::binning_index::index::reference_sequence::bin::Chunk;
+use crate::binning_index::{index::reference_sequence::bin::Chunk, merge::shift_virtual_position};
 
 /// A linear index.
 pub type LinearIndex = Vec<bgzf::VirtualPosition>;
@@ -29,12 +31,66 @@ impl Index for LinearIndex {
             self.resize(new_len, chunk.start());
         }
     }
+
+    fn entry_count(&self) -> usize {
+        self.len()
+    }
+
+    fn shift(&mut self, compressed_offset: u64) -> io::Result<()> {
+        for position in self.iter_mut() {
+            *position = shift_virtual_position(*position, compressed_offset)?;
+        }
+
+        Ok(())
+    }
+
+    fn merge(&mut self, mut other: Self) -> io::Result<()> {
+        if other.len() > self.len() {
+            other[..self.len()].copy_from_slice(self);
+            *self = other;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        let mut index = vec![
+            bgzf::VirtualPosition::from(8),
+            bgzf::VirtualPosition::from(13),
+        ];
+
+        index.merge(vec![bgzf::VirtualPosition::from(21)])?;
+        assert_eq!(
+            index,
+            [
+                bgzf::VirtualPosition::from(8),
+                bgzf::VirtualPosition::from(13),
+            ]
+        );
+
+        index.merge(vec![
+            bgzf::VirtualPosition::from(34),
+            bgzf::VirtualPosition::from(34),
+            bgzf::VirtualPosition::from(55),
+        ])?;
+        assert_eq!(
+            index,
+            [
+                bgzf::VirtualPosition::from(8),
+                bgzf::VirtualPosition::from(13),
+                bgzf::VirtualPosition::from(55),
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_update() -> Result<(), noodles_core::position::TryFromIntError> {
         const MIN_SHIFT: u8 = 14;
@@ -1,6 +1,9 @@
+use std::io;
+
 use noodles_bgzf as bgzf;
 
 use super::bin::Chunk;
+use crate::binning_index::merge::shift_virtual_position;
 
 /// Index reference sequence metadata.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -135,4 +138,17 @@ impl Metadata {
         self.start_position = self.start_position.min(chunk.start());
         self.end_position = self.end_position.max(chunk.end());
     }
+
+    pub(super) fn shift(&mut self, compressed_offset: u64) -> io::Result<()> {
+        self.start_position = shift_virtual_position(self.start_position, compressed_offset)?;
+        self.end_position = shift_virtual_position(self.end_position, compressed_offset)?;
+        Ok(())
+    }
+
+    pub(super) fn merge(&mut self, other: &Self) {
+        self.start_position = self.start_position.min(other.start_position);
+        self.end_position = self.end_position.max(other.end_position);
+        self.mapped_record_count += other.mapped_record_count;
+        self.unmapped_record_count += other.unmapped_record_count;
+    }
 }
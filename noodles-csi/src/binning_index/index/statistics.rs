@@ -0,0 +1,144 @@
+//! Binning index statistics.
+
+use super::reference_sequence::Metadata;
+
+/// Binning index statistics.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Statistics {
+    pub(super) reference_sequences: Vec<ReferenceSequenceStatistics>,
+    pub(super) unplaced_unmapped_record_count: Option<u64>,
+}
+
+impl Statistics {
+    /// Returns the statistics of each reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// let statistics = index.statistics();
+    /// assert!(statistics.reference_sequences().is_empty());
+    /// ```
+    pub fn reference_sequences(&self) -> &[ReferenceSequenceStatistics] {
+        &self.reference_sequences
+    }
+
+    /// Returns the number of unplaced, unmapped records in the associated file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// let statistics = index.statistics();
+    /// assert!(statistics.unplaced_unmapped_record_count().is_none());
+    /// ```
+    pub fn unplaced_unmapped_record_count(&self) -> Option<u64> {
+        self.unplaced_unmapped_record_count
+    }
+
+    /// Returns the total number of bins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// let statistics = index.statistics();
+    /// assert_eq!(statistics.bin_count(), 0);
+    /// ```
+    pub fn bin_count(&self) -> usize {
+        self.reference_sequences.iter().map(|s| s.bin_count()).sum()
+    }
+
+    /// Returns the total number of chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// let statistics = index.statistics();
+    /// assert_eq!(statistics.chunk_count(), 0);
+    /// ```
+    pub fn chunk_count(&self) -> usize {
+        self.reference_sequences
+            .iter()
+            .map(|s| s.chunk_count())
+            .sum()
+    }
+}
+
+/// Binning index reference sequence statistics.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceSequenceStatistics {
+    pub(super) bin_count: usize,
+    pub(super) chunk_count: usize,
+    pub(super) index_entry_count: usize,
+    pub(super) metadata: Option<Metadata>,
+}
+
+impl ReferenceSequenceStatistics {
+    /// Returns the number of bins.
+    pub fn bin_count(&self) -> usize {
+        self.bin_count
+    }
+
+    /// Returns the number of chunks in all bins.
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_count
+    }
+
+    /// Returns the number of entries in the reference sequence index.
+    ///
+    /// For a linear index, this is the number of windows. For a binned index, this is the number of
+    /// bins with a first record start position.
+    pub fn index_entry_count(&self) -> usize {
+        self.index_entry_count
+    }
+
+    /// Returns the reference sequence metadata.
+    ///
+    /// This includes the number of mapped and unmapped records, if available.
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+}
+
+/// An estimate of the size of a region, computed only from a binning index.
+///
+/// This is created by calling [`super::Index::estimate`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RegionEstimate {
+    pub(super) chunk_count: usize,
+    pub(super) compressed_size: u64,
+    pub(super) record_count: Option<u64>,
+}
+
+impl RegionEstimate {
+    /// Returns the number of chunks that would be read to query the region.
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_count
+    }
+
+    /// Returns the estimated number of compressed bytes that would be read to query the region.
+    ///
+    /// This is the sum of the distances between the compressed offsets of the start and end of
+    /// each chunk. Because the size of the last block of a chunk is unknown, this can undercount,
+    /// e.g., a chunk that starts and ends in the same block has a size of 0.
+    pub fn compressed_size(&self) -> u64 {
+        self.compressed_size
+    }
+
+    /// Returns the estimated number of records in the region.
+    ///
+    /// This is the number of records in the reference sequence, scaled by the proportion of the
+    /// compressed size of the reference sequence that the region covers. Records are assumed to be
+    /// uniformly distributed in the compressed stream. If the reference sequence is in a single
+    /// block, the uncompressed sizes are used instead. Each chunk is counted as at least one
+    /// record. This is only available when the reference sequence has metadata.
+    pub fn record_count(&self) -> Option<u64> {
+        self.record_count
+    }
+}
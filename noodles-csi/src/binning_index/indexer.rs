@@ -4,6 +4,7 @@ use indexmap::IndexMap;
 use noodles_core::Position;
 
 use super::index::{
+    max_position,
     reference_sequence::{self, bin::Chunk},
     Header, Index, ReferenceSequence,
 };
@@ -60,6 +61,10 @@ where
 
     /// Adds a record.
     ///
+    /// This returns an error if the record is on a reference sequence before the current one or
+    /// if its end position cannot be represented with the indexer's min shift and depth, i.e., it
+    /// is greater than 2^(min_shift + 3 * depth) - 1.
+    ///
     /// # Examples
     ///
     /// ```
@@ -96,6 +101,13 @@ where
             return Ok(());
         };
 
+        if end > max_position(self.min_shift, self.depth)? {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "position exceeds the maximum position of the binning index; increase the min shift or depth",
+            ));
+        }
+
         if self.reference_sequences.is_empty() {
             self.add_reference_sequences_until(0);
         }
@@ -130,8 +142,12 @@ where
     /// let index = indexer.build(0);
     /// ```
     pub fn build(mut self, reference_sequence_count: usize) -> Index<I> {
+        let mut builder = Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth);
+
         if reference_sequence_count == 0 {
-            return Index::builder()
+            return builder
                 .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
                 .build();
         }
@@ -139,7 +155,7 @@ where
         // SAFETY: `reference_sequence_count` is > 0.
         self.add_reference_sequences_until(reference_sequence_count - 1);
 
-        let mut builder = Index::builder()
+        builder = builder
             .set_reference_sequences(self.reference_sequences)
             .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count);
 
@@ -275,6 +291,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_build_with_min_shift_and_depth() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::binning_index::{index::reference_sequence::index::BinnedIndex, BinningIndex};
+
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 6;
+
+        // 2^29
+        let start = Position::try_from(536870912)?;
+        let end = start;
+
+        let mut indexer = Indexer::<BinnedIndex>::new(MIN_SHIFT, DEPTH);
+
+        indexer.add_record(
+            Some((0, start, end, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+
+        let index = indexer.build(1);
+
+        assert_eq!(index.min_shift(), MIN_SHIFT);
+        assert_eq!(index.depth(), DEPTH);
+
+        let chunks = index.query(0, (start..=end).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        assert!(matches!(
+            indexer.add_record(
+                Some((0, start, end, true)),
+                Chunk::new(
+                    bgzf::VirtualPosition::from(0),
+                    bgzf::VirtualPosition::from(9),
+                ),
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_build_with_reference_sequence_count() {
         let index = Indexer::<LinearIndex>::default().build(2);
@@ -0,0 +1,340 @@
+use std::io;
+
+use indexmap::IndexMap;
+use noodles_bgzf as bgzf;
+
+use super::{
+    index::{reference_sequence, Header, ReferenceSequence},
+    BinningIndex, Index,
+};
+
+/// Merges the binning indices of concatenated files.
+///
+/// Each input is an index and the compressed position of the start of its associated file in the
+/// concatenated file, e.g., from [`bgzf::concat::Offset::compressed`]. The inputs must be in the
+/// same order as the files in the concatenated file. The virtual positions of each index are
+/// shifted by its compressed offset, and bins, reference sequence indices (linear or binned), and
+/// metadata are combined.
+///
+/// All indices must have the same min shift and depth.
+///
+/// Indices with tabix headers must have the same header fields, except for the reference sequence
+/// names. Their reference sequences are matched by name, and the header of the merged index lists
+/// the reference sequence names in order of first appearance. Indices without headers (e.g., BAM
+/// indices (BAI) or CSIs of BAM and BCF files) are matched by reference sequence ID, as they refer
+/// to the reference sequences of a shared file header.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+/// use noodles_core::Position;
+/// use noodles_csi::{
+///     self as csi,
+///     binning_index::{self, index::reference_sequence::bin::Chunk, Indexer},
+/// };
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// let chunk = Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(144));
+///
+/// let mut indexer = Indexer::default();
+/// indexer.add_record(Some((0, start, end, true)), chunk)?;
+/// let a: csi::Index = indexer.build(2);
+///
+/// let mut indexer = Indexer::default();
+/// indexer.add_record(Some((1, start, end, true)), chunk)?;
+/// let b: csi::Index = indexer.build(2);
+///
+/// let index = binning_index::merge([(a, 0), (b, 55)])?;
+/// assert_eq!(index.reference_sequences().len(), 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn merge<I, J>(indices: J) -> io::Result<Index<I>>
+where
+    I: reference_sequence::Index + Default,
+    J: IntoIterator<Item = (Index<I>, u64)>,
+{
+    let mut indices = indices.into_iter();
+
+    let Some((first_index, first_compressed_offset)) = indices.next() else {
+        return Ok(Index::default());
+    };
+
+    let min_shift = first_index.min_shift();
+    let depth = first_index.depth();
+    let mut header = first_index.header().cloned();
+    let mut unplaced_unmapped_record_count = first_index.unplaced_unmapped_record_count();
+
+    let mut reference_sequences = first_index.into_reference_sequences();
+
+    for reference_sequence in &mut reference_sequences {
+        reference_sequence.shift(first_compressed_offset)?;
+    }
+
+    for (index, compressed_offset) in indices {
+        if index.min_shift() != min_shift || index.depth() != depth {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "min shift and depth mismatch",
+            ));
+        }
+
+        let reference_sequence_ids: Vec<_> = match (header.as_mut(), index.header()) {
+            (Some(header), Some(other_header)) => {
+                if !is_compatible(header, other_header) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "header mismatch",
+                    ));
+                }
+
+                other_header
+                    .reference_sequence_names()
+                    .iter()
+                    .map(|name| {
+                        let (i, _) = header
+                            .reference_sequence_names_mut()
+                            .insert_full(name.clone());
+
+                        i
+                    })
+                    .collect()
+            }
+            (None, None) => (0..index.reference_sequences().len()).collect(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "header mismatch",
+                ))
+            }
+        };
+
+        unplaced_unmapped_record_count = match (
+            unplaced_unmapped_record_count,
+            index.unplaced_unmapped_record_count(),
+        ) {
+            (Some(m), Some(n)) => Some(m + n),
+            (m, n) => m.or(n),
+        };
+
+        for (id, mut reference_sequence) in reference_sequence_ids
+            .into_iter()
+            .zip(index.into_reference_sequences())
+        {
+            reference_sequence.shift(compressed_offset)?;
+
+            if id >= reference_sequences.len() {
+                reference_sequences.resize_with(id + 1, || {
+                    ReferenceSequence::new(IndexMap::new(), I::default(), None)
+                });
+            }
+
+            reference_sequences[id].merge(reference_sequence)?;
+        }
+    }
+
+    let mut builder = Index::builder()
+        .set_min_shift(min_shift)
+        .set_depth(depth)
+        .set_reference_sequences(reference_sequences);
+
+    if let Some(header) = header {
+        builder = builder.set_header(header);
+    }
+
+    if let Some(n) = unplaced_unmapped_record_count {
+        builder = builder.set_unplaced_unmapped_record_count(n);
+    }
+
+    Ok(builder.build())
+}
+
+// Returns whether two headers are the same, excluding their reference sequence names.
+fn is_compatible(a: &Header, b: &Header) -> bool {
+    a.format() == b.format()
+        && a.reference_sequence_name_index() == b.reference_sequence_name_index()
+        && a.start_position_index() == b.start_position_index()
+        && a.end_position_index() == b.end_position_index()
+        && a.line_comment_prefix() == b.line_comment_prefix()
+        && a.line_skip_count() == b.line_skip_count()
+}
+
+pub(crate) fn shift_virtual_position(
+    position: bgzf::VirtualPosition,
+    compressed_offset: u64,
+) -> io::Result<bgzf::VirtualPosition> {
+    let (compressed_position, uncompressed_position) = position.into();
+
+    compressed_position
+        .checked_add(compressed_offset)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "compressed position overflow"))
+        .and_then(|compressed_position| {
+            bgzf::VirtualPosition::try_from((compressed_position, uncompressed_position))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::binning_index::{
+        index::{
+            header::ReferenceSequenceNames,
+            reference_sequence::{
+                bin::Chunk,
+                index::{BinnedIndex, LinearIndex},
+                Metadata,
+            },
+        },
+        Indexer, ReferenceSequence as _,
+    };
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start << 16),
+            bgzf::VirtualPosition::from(end << 16),
+        )
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+        indexer.add_record(Some((0, start, end, true)), build_chunk(0, 5))?;
+        indexer.add_record(None, build_chunk(5, 8))?;
+        let a = indexer.build(2);
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+        indexer.add_record(Some((0, start, end, false)), build_chunk(0, 3))?;
+        indexer.add_record(Some((1, start, end, true)), build_chunk(3, 13))?;
+        let b = indexer.build(2);
+
+        let index = merge([(a, 0), (b, 21)])?;
+
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+
+        let bin = reference_sequences[0]
+            .bins()
+            .values()
+            .next()
+            .ok_or("missing bin")?;
+        assert_eq!(bin.chunks(), [build_chunk(0, 5), build_chunk(21, 24)]);
+        assert_eq!(
+            reference_sequences[0].index(),
+            &vec![bgzf::VirtualPosition::from(0)]
+        );
+        assert_eq!(
+            reference_sequences[0].metadata(),
+            Some(&Metadata::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(24 << 16),
+                1,
+                1
+            ))
+        );
+
+        assert_eq!(
+            reference_sequences[1].index(),
+            &vec![bgzf::VirtualPosition::from(24 << 16)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_headers() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_header(names: &[&str]) -> Header {
+            let reference_sequence_names: ReferenceSequenceNames =
+                names.iter().map(|name| name.to_string()).collect();
+
+            Header::builder()
+                .set_reference_sequence_names(reference_sequence_names)
+                .build()
+        }
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        let mut indexer = Indexer::<BinnedIndex>::default().set_header(build_header(&["sq0"]));
+        indexer.add_record(Some((0, start, end, true)), build_chunk(0, 5))?;
+        let a = indexer.build(1);
+
+        let mut indexer =
+            Indexer::<BinnedIndex>::default().set_header(build_header(&["sq1", "sq0"]));
+        indexer.add_record(Some((0, start, end, true)), build_chunk(0, 3))?;
+        indexer.add_record(Some((1, start, end, true)), build_chunk(3, 8))?;
+        let b = indexer.build(2);
+
+        let index = merge([(a, 0), (b, 13)])?;
+
+        let header = index.header().ok_or("missing header")?;
+        let actual: Vec<_> = header.reference_sequence_names().iter().collect();
+        assert_eq!(actual, ["sq0", "sq1"]);
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+
+        let metadata = reference_sequences[0]
+            .metadata()
+            .ok_or("missing metadata")?;
+        assert_eq!(metadata.mapped_record_count(), 2);
+        assert_eq!(
+            metadata.end_position(),
+            bgzf::VirtualPosition::from(21 << 16)
+        );
+
+        let metadata = reference_sequences[1]
+            .metadata()
+            .ok_or("missing metadata")?;
+        assert_eq!(
+            metadata.start_position(),
+            bgzf::VirtualPosition::from(13 << 16)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_mismatched_indices() {
+        let a = Index::<LinearIndex>::builder().set_min_shift(14).build();
+        let b = Index::<LinearIndex>::builder().set_min_shift(12).build();
+
+        assert!(matches!(
+            merge([(a, 0), (b, 8)]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let a = Index::<LinearIndex>::builder()
+            .set_header(Header::default())
+            .build();
+        let b = Index::<LinearIndex>::default();
+
+        assert!(matches!(
+            merge([(a, 0), (b, 8)]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_shift_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            shift_virtual_position(bgzf::VirtualPosition::try_from((8, 13))?, 21)?,
+            bgzf::VirtualPosition::try_from((29, 13))?
+        );
+
+        assert!(matches!(
+            shift_virtual_position(bgzf::VirtualPosition::MAX, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,583 @@
+//! Binning index validation.
+//!
+//! An index is validated against its associated data file by checking that each chunk starts in
+//! a BGZF block of the data file and at a record that is placed in the chunk's bin or one of its
+//! descendants. (htslib moves the chunks of small bins into their parent bins.) This detects stale
+//! indices, e.g., an index that was not regenerated after its data file was.
+//!
+//! Only the record at the start of each chunk is checked. The other records in a chunk are not
+//! necessarily placed in its bin: htslib merges chunks of a bin that end and start in the same BGZF
+//! block, which can include the records of other bins between them.
+
+use std::{
+    error, fmt,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::index::{
+    reference_sequence::{self, bin::Chunk, parent_id, reg2bin},
+    Index,
+};
+
+/// A binning index validation diagnostic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    reference_sequence_id: usize,
+    bin_id: usize,
+    chunk: Chunk,
+    kind: Kind,
+}
+
+impl Diagnostic {
+    /// Returns the reference sequence ID of the bin with the invalid chunk.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the ID of the bin with the invalid chunk.
+    pub fn bin_id(&self) -> usize {
+        self.bin_id
+    }
+
+    /// Returns the invalid chunk.
+    pub fn chunk(&self) -> Chunk {
+        self.chunk
+    }
+
+    /// Returns the kind of diagnostic.
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+}
+
+impl error::Error for Diagnostic {}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.chunk.start();
+        let end = self.chunk.end();
+
+        write!(
+            f,
+            "reference sequence {}, bin {}, chunk ({}, {})..({}, {}): {}",
+            self.reference_sequence_id,
+            self.bin_id,
+            start.compressed(),
+            start.uncompressed(),
+            end.compressed(),
+            end.uncompressed(),
+            self.kind
+        )
+    }
+}
+
+/// A binning index validation diagnostic kind.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// The chunk start is after the chunk end.
+    InvalidChunk,
+    /// The compressed position of the chunk start is not the start of a BGZF block.
+    InvalidBlock,
+    /// The uncompressed position of the chunk start is not in the block.
+    InvalidUncompressedPosition,
+    /// A record could not be read at the chunk start.
+    InvalidRecord,
+    /// There is no record at the chunk start.
+    MissingRecord,
+    /// The reference sequence ID of the record at the chunk start does not match the reference
+    /// sequence of the bin.
+    ReferenceSequenceIdMismatch {
+        /// The reference sequence ID of the bin.
+        expected: usize,
+        /// The reference sequence ID of the record.
+        actual: usize,
+    },
+    /// The record at the chunk start is not placed in the bin or any of its descendants.
+    BinMismatch {
+        /// The ID of the bin.
+        expected: usize,
+        /// The bin ID calculated from the interval of the record.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChunk => write!(f, "invalid chunk"),
+            Self::InvalidBlock => write!(f, "invalid block"),
+            Self::InvalidUncompressedPosition => write!(f, "invalid uncompressed position"),
+            Self::InvalidRecord => write!(f, "invalid record"),
+            Self::MissingRecord => write!(f, "missing record"),
+            Self::ReferenceSequenceIdMismatch { expected, actual } => write!(
+                f,
+                "reference sequence ID mismatch: expected {expected}, got {actual}"
+            ),
+            Self::BinMismatch { expected, actual } => {
+                write!(f, "bin mismatch: expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+/// Validates a binning index against its associated BGZF-compressed data file.
+///
+/// For each chunk in each bin, this checks that
+///
+///   1. the chunk start is not after the chunk end;
+///   2. the chunk start is in a BGZF block of the data file; and
+///   3. the record at the chunk start is on the bin's reference sequence and is placed in the bin.
+///
+/// The records after the chunk start are not read.
+///
+/// Records are read using `read_record`, which is given the data reader positioned at the chunk
+/// start. It returns the reference sequence ID, start position, and end position of the record,
+/// or `None` if there is no record (EOF).
+///
+/// This returns a list of diagnostics, which is empty if the index is valid. An error is only
+/// returned for I/O errors that are unrelated to the contents of the index.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufRead}};
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{
+///     self as csi,
+///     binning_index::{validation, BinningIndex},
+///     io::{IndexedRecord, IndexedRecords},
+/// };
+///
+/// let index = csi::read("sample.vcf.gz.csi")?;
+/// let header = index.header().cloned().expect("missing tabix header");
+///
+/// let mut reader = File::open("sample.vcf.gz").map(bgzf::Reader::new)?;
+///
+/// let diagnostics = validation::validate(&index, &mut reader, |reader| {
+///     let Some(record) = IndexedRecords::new(reader, &header).next().transpose()? else {
+///         return Ok(None);
+///     };
+///
+///     let reference_sequence_id = header
+///         .reference_sequence_names()
+///         .get_index_of(record.indexed_reference_sequence_name())
+///         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence name"))?;
+///
+///     Ok(Some((
+///         reference_sequence_id,
+///         record.indexed_start_position(),
+///         record.indexed_end_position(),
+///     )))
+/// })?;
+///
+/// for diagnostic in diagnostics {
+///     eprintln!("{diagnostic}");
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn validate<I, R, F>(
+    index: &Index<I>,
+    reader: &mut bgzf::Reader<R>,
+    mut read_record: F,
+) -> io::Result<Vec<Diagnostic>>
+where
+    I: reference_sequence::Index,
+    R: Read + Seek,
+    F: FnMut(&mut bgzf::Reader<R>) -> io::Result<Option<(usize, Position, Position)>>,
+{
+    use super::BinningIndex;
+
+    let min_shift = index.min_shift();
+    let depth = index.depth();
+
+    let mut diagnostics = Vec::new();
+
+    for (reference_sequence_id, reference_sequence) in
+        index.reference_sequences().iter().enumerate()
+    {
+        for (&bin_id, bin) in reference_sequence.bins() {
+            for &chunk in bin.chunks() {
+                let result = validate_chunk_start(
+                    reader,
+                    &mut read_record,
+                    min_shift,
+                    depth,
+                    reference_sequence_id,
+                    bin_id,
+                    chunk,
+                )?;
+
+                if let Err(kind) = result {
+                    diagnostics.push(Diagnostic {
+                        reference_sequence_id,
+                        bin_id,
+                        chunk,
+                        kind,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(diagnostics)
+}
+
+// Validates the chunk bounds, the block at the chunk start, and the record at the chunk start.
+fn validate_chunk_start<R, F>(
+    reader: &mut bgzf::Reader<R>,
+    read_record: &mut F,
+    min_shift: u8,
+    depth: u8,
+    reference_sequence_id: usize,
+    bin_id: usize,
+    chunk: Chunk,
+) -> io::Result<Result<(), Kind>>
+where
+    R: Read + Seek,
+    F: FnMut(&mut bgzf::Reader<R>) -> io::Result<Option<(usize, Position, Position)>>,
+{
+    if chunk.start() > chunk.end() {
+        return Ok(Err(Kind::InvalidChunk));
+    }
+
+    if let Err(kind) = validate_block(reader.get_mut(), chunk.start())? {
+        return Ok(Err(kind));
+    }
+
+    reader.seek(chunk.start())?;
+
+    let (id, start, end) = match read_record(reader) {
+        Ok(Some(record)) => record,
+        Ok(None) => return Ok(Err(Kind::MissingRecord)),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => return Ok(Err(Kind::InvalidRecord)),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(Err(Kind::MissingRecord)),
+        Err(e) => return Err(e),
+    };
+
+    if id != reference_sequence_id {
+        return Ok(Err(Kind::ReferenceSequenceIdMismatch {
+            expected: reference_sequence_id,
+            actual: id,
+        }));
+    }
+
+    if end < start {
+        return Ok(Err(Kind::InvalidRecord));
+    }
+
+    let actual_bin_id = reg2bin(start, end, min_shift, depth);
+
+    if !is_ancestor_or_self(bin_id, actual_bin_id) {
+        return Ok(Err(Kind::BinMismatch {
+            expected: bin_id,
+            actual: actual_bin_id,
+        }));
+    }
+
+    Ok(Ok(()))
+}
+
+fn is_ancestor_or_self(ancestor_id: usize, id: usize) -> bool {
+    let mut id = Some(id);
+
+    while let Some(i) = id {
+        if i == ancestor_id {
+            return true;
+        }
+
+        id = parent_id(i);
+    }
+
+    false
+}
+
+// Checks that the compressed position is the start of a nonempty BGZF block and that the
+// uncompressed position is in that block.
+fn validate_block<R>(
+    reader: &mut R,
+    position: bgzf::VirtualPosition,
+) -> io::Result<Result<(), Kind>>
+where
+    R: Read + Seek,
+{
+    let (compressed_position, uncompressed_position) = position.into();
+
+    reader.seek(SeekFrom::Start(compressed_position))?;
+
+    let mut block_reader = bgzf::Reader::new(reader);
+
+    let block_len = match block_reader.fill_buf() {
+        Ok(buf) => buf.len(),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => return Ok(Err(Kind::InvalidBlock)),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(Err(Kind::InvalidBlock)),
+        Err(e) => return Err(e),
+    };
+
+    // An empty block is skipped, so the first nonempty block must be at the compressed position.
+    if block_len == 0 || block_reader.virtual_position().compressed() != 0 {
+        return Ok(Err(Kind::InvalidBlock));
+    }
+
+    if usize::from(uncompressed_position) >= block_len {
+        return Ok(Err(Kind::InvalidUncompressedPosition));
+    }
+
+    Ok(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, Cursor, Write};
+
+    use super::*;
+    use crate::binning_index::Indexer;
+
+    const MIN_SHIFT: u8 = 14;
+    const DEPTH: u8 = 5;
+
+    // Each line is `<reference sequence ID>\t<start>\t<end>`.
+    fn read_record<R>(
+        reader: &mut bgzf::Reader<R>,
+    ) -> io::Result<Option<(usize, Position, Position)>>
+    where
+        R: Read,
+    {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let fields: Vec<_> = line.trim_end().split('\t').collect();
+
+        let parse = |s: &str| {
+            s.parse::<usize>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        };
+
+        let id = parse(fields[0])?;
+        let start = Position::new(parse(fields[1])?).ok_or(io::ErrorKind::InvalidData)?;
+        let end = Position::new(parse(fields[2])?).ok_or(io::ErrorKind::InvalidData)?;
+
+        Ok(Some((id, start, end)))
+    }
+
+    fn build_data_and_index(
+        records: &[(usize, usize, usize)],
+    ) -> Result<(Vec<u8>, crate::Index), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        let mut indexer = Indexer::default();
+
+        for &(id, start, end) in records {
+            let chunk_start = writer.virtual_position();
+            writeln!(writer, "{id}\t{start}\t{end}")?;
+            let chunk_end = writer.virtual_position();
+
+            indexer.add_record(
+                Some((
+                    id,
+                    Position::try_from(start)?,
+                    Position::try_from(end)?,
+                    true,
+                )),
+                Chunk::new(chunk_start, chunk_end),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build(2);
+
+        Ok((data, index))
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [(0, 8, 13), (0, 100000, 100005), (1, 21, 34)];
+        let (data, index) = build_data_and_index(&records)?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(data));
+        let diagnostics = validate(&index, &mut reader, read_record)?;
+        assert!(diagnostics.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_stale_index() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [(0, 20008, 20013), (0, 90008, 90013)];
+        let (_, index) = build_data_and_index(&records)?;
+
+        let records = [(0, 90008, 90013), (0, 20008, 20013)];
+        let (data, _) = build_data_and_index(&records)?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(data));
+        let diagnostics = validate(&index, &mut reader, read_record)?;
+
+        let bin_id_0 = reg2bin(
+            Position::try_from(20008)?,
+            Position::try_from(20013)?,
+            MIN_SHIFT,
+            DEPTH,
+        );
+        let bin_id_1 = reg2bin(
+            Position::try_from(90008)?,
+            Position::try_from(90013)?,
+            MIN_SHIFT,
+            DEPTH,
+        );
+
+        let actual: Vec<_> = diagnostics.iter().map(|d| d.kind().clone()).collect();
+        let expected = [
+            Kind::BinMismatch {
+                expected: bin_id_0,
+                actual: bin_id_1,
+            },
+            Kind::BinMismatch {
+                expected: bin_id_1,
+                actual: bin_id_0,
+            },
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [(0, 8, 13)];
+        let (data, _) = build_data_and_index(&records)?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(data));
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        let bin_id = reg2bin(start, end, MIN_SHIFT, DEPTH);
+
+        for (chunk, expected) in [
+            (
+                Chunk::new(
+                    bgzf::VirtualPosition::from(8),
+                    bgzf::VirtualPosition::from(0),
+                ),
+                Kind::InvalidChunk,
+            ),
+            (
+                Chunk::new(
+                    bgzf::VirtualPosition::try_from((1, 0))?,
+                    bgzf::VirtualPosition::try_from((2, 0))?,
+                ),
+                Kind::InvalidBlock,
+            ),
+            (
+                Chunk::new(
+                    bgzf::VirtualPosition::try_from((0, 8))?,
+                    bgzf::VirtualPosition::try_from((0, 13))?,
+                ),
+                Kind::InvalidUncompressedPosition,
+            ),
+        ] {
+            let mut read_record = read_record;
+
+            let actual = validate_chunk_start(
+                &mut reader,
+                &mut read_record,
+                MIN_SHIFT,
+                DEPTH,
+                0,
+                bin_id,
+                chunk,
+            )?;
+
+            assert_eq!(actual, Err(expected));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_chunk_start_with_chunk_in_parent_bin() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let records = [(0, 8, 13)];
+        let (data, _) = build_data_and_index(&records)?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(data));
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        let bin_id = reg2bin(start, end, MIN_SHIFT, DEPTH);
+        let parent_bin_id = parent_id(bin_id).unwrap();
+
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::try_from((0, 8))?,
+        );
+
+        let mut read_record = read_record;
+
+        for id in [bin_id, parent_bin_id, 0] {
+            let actual = validate_chunk_start(
+                &mut reader,
+                &mut read_record,
+                MIN_SHIFT,
+                DEPTH,
+                0,
+                id,
+                chunk,
+            )?;
+
+            assert_eq!(actual, Ok(()));
+        }
+
+        let sibling_bin_id = bin_id + 1;
+
+        let actual = validate_chunk_start(
+            &mut reader,
+            &mut read_record,
+            MIN_SHIFT,
+            DEPTH,
+            0,
+            sibling_bin_id,
+            chunk,
+        )?;
+
+        assert_eq!(
+            actual,
+            Err(Kind::BinMismatch {
+                expected: sibling_bin_id,
+                actual: bin_id,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_ancestor_or_self() {
+        assert!(is_ancestor_or_self(4681, 4681));
+        assert!(is_ancestor_or_self(585, 4681));
+        assert!(is_ancestor_or_self(0, 4681));
+        assert!(!is_ancestor_or_self(4682, 4681));
+        assert!(!is_ancestor_or_self(586, 4681));
+    }
+
+    #[test]
+    fn test_fmt() {
+        let diagnostic = Diagnostic {
+            reference_sequence_id: 0,
+            bin_id: 4681,
+            chunk: Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(8),
+            ),
+            kind: Kind::MissingRecord,
+        };
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "reference sequence 0, bin 4681, chunk (0, 0)..(0, 8): missing record"
+        );
+    }
+}
@@ -1,14 +1,16 @@
 //! CSI I/O.
 
 mod filter_by_region;
+mod filter_by_regions;
 mod indexed_reader;
 mod indexed_record;
-mod indexed_records;
+pub mod indexed_records;
 mod query;
 
 pub use self::{
-    filter_by_region::FilterByRegion, indexed_reader::IndexedReader, indexed_record::IndexedRecord,
-    indexed_records::IndexedRecords, query::Query,
+    filter_by_region::FilterByRegion, filter_by_regions::FilterByRegions,
+    indexed_reader::IndexedReader, indexed_record::IndexedRecord, indexed_records::IndexedRecords,
+    query::Query,
 };
 
 pub(crate) static MAGIC_NUMBER: &[u8] = b"CSI\x01";
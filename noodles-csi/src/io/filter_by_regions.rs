@@ -0,0 +1,155 @@
+use std::io;
+
+use noodles_core::Region;
+
+use super::IndexedRecord;
+
+/// An iterator that filters indexed records that intersect any of the given regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+pub struct FilterByRegions<'r, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: IndexedRecord,
+{
+    records: I,
+    regions: &'r [Region],
+    region_indices: Vec<usize>,
+}
+
+impl<'r, I, R> FilterByRegions<'r, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: IndexedRecord,
+{
+    /// Creates a filtered indexed records iterator.
+    pub fn new(records: I, regions: &'r [Region]) -> Self {
+        Self {
+            records,
+            regions,
+            region_indices: Vec::new(),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        &self.region_indices
+    }
+}
+
+impl<'r, I, R> Iterator for FilterByRegions<'r, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: IndexedRecord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.records.next()? {
+                Ok(r) => r,
+                Err(e) => return Some(Err(e)),
+            };
+
+            self.region_indices.clear();
+
+            for (i, region) in self.regions.iter().enumerate() {
+                if intersects(&record, region) {
+                    self.region_indices.push(i);
+                }
+            }
+
+            if !self.region_indices.is_empty() {
+                return Some(Ok(record));
+            }
+        }
+    }
+}
+
+fn intersects<R>(record: &R, region: &Region) -> bool
+where
+    R: IndexedRecord,
+{
+    record.indexed_reference_sequence_name().as_bytes() == region.name()
+        && record.indexed_interval().intersects(region.interval())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    struct Record(&'static str, Position, Position);
+
+    impl IndexedRecord for Record {
+        fn indexed_reference_sequence_name(&self) -> &str {
+            self.0
+        }
+
+        fn indexed_start_position(&self) -> Position {
+            self.1
+        }
+
+        fn indexed_end_position(&self) -> Position {
+            self.2
+        }
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            Ok(Record(
+                "sq0",
+                Position::try_from(5)?,
+                Position::try_from(13)?,
+            )),
+            Ok(Record(
+                "sq1",
+                Position::try_from(5)?,
+                Position::try_from(13)?,
+            )),
+            Ok(Record(
+                "sq1",
+                Position::try_from(21)?,
+                Position::try_from(55)?,
+            )),
+            Ok(Record(
+                "sq1",
+                Position::try_from(89)?,
+                Position::try_from(144)?,
+            )),
+            Ok(Record(
+                "sq2",
+                Position::try_from(5)?,
+                Position::try_from(13)?,
+            )),
+        ];
+
+        let regions = [
+            "sq1:21-34".parse()?,
+            "sq1:34-89".parse()?,
+            "sq2:8-13".parse()?,
+        ];
+
+        let mut iter = FilterByRegions::new(records.into_iter(), &regions);
+        let mut actual = Vec::new();
+
+        while let Some(record) = iter.next().transpose()? {
+            actual.push((record.0, record.1, iter.region_indices().to_vec()));
+        }
+
+        let expected = [
+            ("sq1", Position::try_from(21)?, vec![0, 1]),
+            ("sq1", Position::try_from(89)?, vec![1]),
+            ("sq2", Position::try_from(5)?, vec![2]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
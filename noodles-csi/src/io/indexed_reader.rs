@@ -6,8 +6,11 @@ use std::{
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 
-use super::{indexed_records::Record, Query};
-use crate::BinningIndex;
+use super::{indexed_records::Record, FilterByRegions, IndexedRecords, Query};
+use crate::{
+    binning_index::{index::Header, query_intervals},
+    BinningIndex,
+};
 
 /// An indexed reader.
 pub struct IndexedReader<R, I> {
@@ -28,6 +31,28 @@ where
         }
     }
 
+    /// Creates an indexed reader from a BGZF reader.
+    ///
+    /// This allows using a BGZF reader with a block cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{self as csi, io::IndexedReader};
+    ///
+    /// let inner = bgzf::reader::Builder::default()
+    ///     .set_block_cache_capacity(NonZeroUsize::try_from(64)?)
+    ///     .build_from_reader(&[][..]);
+    ///
+    /// let reader = IndexedReader::from_reader(inner, csi::Index::default());
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn from_reader(inner: bgzf::Reader<R>, index: I) -> Self {
+        Self { inner, index }
+    }
+
     /// Returns a reference to the underlying reader.
     pub fn get_ref(&self) -> &bgzf::Reader<R> {
         &self.inner
@@ -64,23 +89,71 @@ where
             .header()
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
 
-        let region_name = str::from_utf8(region.name())
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-        let reference_sequence_id = header
-            .reference_sequence_names()
-            .get_index_of(region_name)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "missing reference sequence name",
-                )
-            })?;
-
+        let reference_sequence_id = resolve_region(header, region)?;
         let chunks = self.index.query(reference_sequence_id, region.interval())?;
 
         Ok(Query::new(&mut self.inner, chunks)
             .indexed_records(header)
             .filter_by_region(region))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. The regions that a record
+    /// intersects are available from [`FilterByRegions::region_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_csi::{self as csi, io::IndexedReader};
+    ///
+    /// let index = csi::read("sample.bed.gz.csi")?;
+    /// let mut reader = File::open("sample.bed.gz").map(|f| IndexedReader::new(f, index))?;
+    ///
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&regions)?;
+    ///
+    /// while let Some(record) = query.next().transpose()? {
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r>(
+        &'r mut self,
+        regions: &'r [Region],
+    ) -> io::Result<FilterByRegions<'r, IndexedRecords<Query<'r, bgzf::Reader<R>>>, Record>> {
+        let header = self
+            .index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        let intervals = regions
+            .iter()
+            .map(|region| resolve_region(header, region).map(|id| (id, region.interval())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(&self.index, &intervals)?;
+
+        Ok(Query::new(&mut self.inner, chunks)
+            .indexed_records(header)
+            .filter_by_regions(regions))
+    }
+}
+
+fn resolve_region(header: &Header, region: &Region) -> io::Result<usize> {
+    let region_name = str::from_utf8(region.name())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    header
+        .reference_sequence_names()
+        .get_index_of(region_name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })
 }
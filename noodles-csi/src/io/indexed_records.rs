@@ -1,3 +1,5 @@
+//! Indexed records.
+
 mod record;
 
 use noodles_core::Region;
@@ -9,7 +11,7 @@ use std::io::{self, BufRead, Lines};
 use self::record::parse_record;
 use crate::binning_index::index::{header::format::CoordinateSystem, Header};
 
-use super::FilterByRegion;
+use super::{FilterByRegion, FilterByRegions};
 
 /// An iterator over indexed records.
 pub struct IndexedRecords<R> {
@@ -41,6 +43,11 @@ where
     pub fn filter_by_region(self, region: &Region) -> FilterByRegion<Self, Record> {
         FilterByRegion::new(self, region)
     }
+
+    /// Creates an iterator that filters indexed records that intersect any of the given regions.
+    pub fn filter_by_regions(self, regions: &[Region]) -> FilterByRegions<'_, Self, Record> {
+        FilterByRegions::new(self, regions)
+    }
 }
 
 impl<R> Iterator for IndexedRecords<R>
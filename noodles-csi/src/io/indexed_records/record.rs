@@ -1,14 +1,19 @@
 mod position;
 
-use std::{error, fmt, ops::Range};
+use std::{error, fmt, io, ops::Range};
 
 use noodles_core::Position;
 
 use self::position::parse_start_position;
 use crate::{
-    binning_index::index::header::format::coordinate_system::CoordinateSystem, io::IndexedRecord,
+    binning_index::index::{header::format::coordinate_system::CoordinateSystem, Header},
+    io::IndexedRecord,
 };
 
+/// An indexed record.
+///
+/// This is a line of a tab-delimited file with its reference sequence name, start position, and
+/// end position parsed using the fields described by a tabix index header.
 pub struct Record {
     buf: String,
     reference_sequence_name_bounds: Range<usize>,
@@ -16,6 +21,40 @@ pub struct Record {
     end_position: Position,
 }
 
+impl Record {
+    /// Parses a line using the fields and coordinate system described by the given header.
+    ///
+    /// The line must not include the line terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::{
+    ///     binning_index::index::header,
+    ///     io::{indexed_records::Record, IndexedRecord},
+    /// };
+    ///
+    /// let header = header::Builder::bed().build();
+    /// let record = Record::parse(String::from("sq0\t7\t13"), &header)?;
+    ///
+    /// assert_eq!(record.indexed_reference_sequence_name(), "sq0");
+    /// assert_eq!(record.indexed_start_position(), Position::try_from(8)?);
+    /// assert_eq!(record.indexed_end_position(), Position::try_from(13)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse(s: String, header: &Header) -> io::Result<Self> {
+        parse_record(
+            s,
+            header.reference_sequence_name_index(),
+            header.start_position_index(),
+            header.end_position_index(),
+            header.format().coordinate_system(),
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
 impl IndexedRecord for Record {
     fn indexed_reference_sequence_name(&self) -> &str {
         &self.buf[self.reference_sequence_name_bounds.clone()]
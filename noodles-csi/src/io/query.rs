@@ -1,5 +1,5 @@
 use std::{
-    io::{self, BufRead, Read, Seek},
+    io::{self, BufRead, Read},
     vec,
 };
 
@@ -17,18 +17,21 @@ enum State {
 /// A query reader.
 ///
 /// This reader returns the uncompressed data between all the given chunks.
+///
+/// The underlying reader is a BGZF reader, e.g., a [`bgzf::Reader`] or a
+/// [`bgzf::MultithreadedReader`].
 pub struct Query<'r, R> {
-    reader: &'r mut bgzf::Reader<R>,
+    reader: &'r mut R,
     chunks: vec::IntoIter<Chunk>,
     state: State,
 }
 
 impl<'r, R> Query<'r, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     /// Creates a query reader.
-    pub fn new(reader: &'r mut bgzf::Reader<R>, chunks: Vec<Chunk>) -> Self {
+    pub fn new(reader: &'r mut R, chunks: Vec<Chunk>) -> Self {
         Self {
             reader,
             chunks: chunks.into_iter(),
@@ -44,7 +47,7 @@ where
 
 impl<'r, R> Read for Query<'r, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let mut src = self.fill_buf()?;
@@ -56,7 +59,7 @@ where
 
 impl<'r, R> BufRead for Query<'r, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         loop {
@@ -64,7 +67,7 @@ where
                 State::Seek => {
                     self.state = match self.chunks.next() {
                         Some(chunk) => {
-                            self.reader.seek(chunk.start())?;
+                            self.reader.seek_to_virtual_position(chunk.start())?;
                             State::Read(chunk.end())
                         }
                         None => State::Done,
@@ -0,0 +1,35 @@
+//! Creates a sequence dictionary from a FASTA file.
+//!
+//! This writes the output to stdout rather than `<src>.dict`.
+//!
+//! The result is similar to the output of `samtools dict --uri file:<src> <src>` or Picard
+//! `CreateSequenceDictionary`, differing only in the header (`@HD`) record.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_fasta::{self as fasta, sequence_dictionary};
+use noodles_sam as sam;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(&src)
+        .map(BufReader::new)
+        .map(fasta::Reader::new)?;
+
+    let dictionary = sequence_dictionary::from_reader(&mut reader)?;
+
+    let uri = format!("file:{src}");
+    let header = sam::dict::from_sequence_dictionary(&dictionary, Some(&uri))?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = sam::io::Writer::new(stdout);
+
+    writer.write_header(&header)?;
+
+    Ok(())
+}
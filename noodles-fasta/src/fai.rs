@@ -5,6 +5,7 @@ mod r#async;
 
 mod reader;
 mod record;
+pub mod sequence_dictionary;
 mod writer;
 
 pub use self::{reader::Reader, record::Record, writer::Writer};
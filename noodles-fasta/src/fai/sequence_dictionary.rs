@@ -0,0 +1,52 @@
+//! Conversions between a FASTA index and a sequence dictionary.
+
+use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+
+use super::Index;
+
+/// Converts a FASTA index to a sequence dictionary.
+///
+/// Each record contributes its name and length. There is no conversion in the other direction,
+/// as a sequence dictionary does not describe where sequences are located in a FASTA file.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::fai::{self, sequence_dictionary};
+///
+/// let index = vec![fai::Record::new("sq0", 8, 5, 4, 5)];
+/// let dictionary = sequence_dictionary::from_index(&index);
+///
+/// assert_eq!(dictionary.get(b"sq0").and_then(|sequence| sequence.length()), Some(8));
+/// ```
+pub fn from_index(index: &Index) -> SequenceDictionary {
+    index
+        .iter()
+        .map(|record| Sequence::new(record.name()).set_length(record.length() as usize))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fai::Record;
+
+    #[test]
+    fn test_from_index() {
+        let index = vec![
+            Record::new("sq0", 8, 5, 4, 5),
+            Record::new("sq1", 13, 19, 13, 14),
+        ];
+
+        let actual = from_index(&index);
+
+        let expected: SequenceDictionary = [
+            Sequence::new("sq0").set_length(8),
+            Sequence::new("sq1").set_length(13),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected);
+    }
+}
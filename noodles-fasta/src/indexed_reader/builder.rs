@@ -2,6 +2,7 @@ use std::{
     ffi::{OsStr, OsString},
     fs::File,
     io::{self, BufRead, BufReader},
+    num::NonZeroUsize,
     path::{Path, PathBuf},
 };
 
@@ -14,6 +15,7 @@ use crate::{fai, io::BufReadSeek};
 #[derive(Default)]
 pub struct Builder {
     index: Option<fai::Index>,
+    block_cache_capacity: Option<NonZeroUsize>,
 }
 
 impl Builder {
@@ -31,6 +33,26 @@ impl Builder {
         self
     }
 
+    /// Sets the capacity of the block cache.
+    ///
+    /// This is only used when building from a path of a bgzipped FASTA file. See
+    /// [`bgzf::reader::Builder::set_block_cache_capacity`].
+    ///
+    /// By default, the block cache is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_fasta::indexed_reader::Builder;
+    /// let builder = Builder::default().set_block_cache_capacity(NonZeroUsize::try_from(64)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_block_cache_capacity(mut self, capacity: NonZeroUsize) -> Self {
+        self.block_cache_capacity = Some(capacity);
+        self
+    }
+
     /// Builds an indexed FASTA reader from a path.
     ///
     /// # Examples
@@ -55,9 +77,15 @@ impl Builder {
         };
 
         let reader: Box<dyn BufReadSeek> = match src.extension().and_then(|ext| ext.to_str()) {
-            Some("gz" | "bgz") => bgzf::indexed_reader::Builder::default()
-                .build_from_path(src)
-                .map(Box::new)?,
+            Some("gz" | "bgz") => {
+                let mut builder = bgzf::indexed_reader::Builder::default();
+
+                if let Some(capacity) = self.block_cache_capacity {
+                    builder = builder.set_block_cache_capacity(capacity);
+                }
+
+                builder.build_from_path(src).map(Box::new)?
+            }
             _ => File::open(src).map(BufReader::new).map(Box::new)?,
         };
 
@@ -57,6 +57,7 @@ pub mod io;
 pub mod reader;
 pub mod record;
 pub mod repository;
+pub mod sequence_dictionary;
 pub mod writer;
 
 pub use self::{
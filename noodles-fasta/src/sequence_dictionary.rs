@@ -0,0 +1,113 @@
+//! FASTA sequence dictionary.
+
+use std::io::{self, BufRead};
+
+use md5::{Digest, Md5};
+use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+
+use super::Reader;
+
+/// Builds a sequence dictionary from the records of a FASTA reader.
+///
+/// Each sequence has its name, length, and the MD5 checksum of its normalized sequence, i.e.,
+/// with all characters outside of the inclusive range 33 (`!`) to 126 (`~`) removed and all
+/// lowercase characters converted to uppercase. This is the same checksum used by the SAM header
+/// reference sequence `M5` field.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::{self as fasta, sequence_dictionary};
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+/// let mut reader = fasta::Reader::new(&data[..]);
+///
+/// let dictionary = sequence_dictionary::from_reader(&mut reader)?;
+///
+/// assert_eq!(dictionary.len(), 2);
+/// assert_eq!(dictionary.get(b"sq1").and_then(|sequence| sequence.length()), Some(10));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn from_reader<R>(reader: &mut Reader<R>) -> io::Result<SequenceDictionary>
+where
+    R: BufRead,
+{
+    let mut dictionary = SequenceDictionary::new();
+
+    for result in reader.records() {
+        let record = result?;
+        let sequence = record.sequence().as_ref();
+
+        dictionary.insert(
+            Sequence::new(record.name())
+                .set_length(sequence.len())
+                .set_md5_checksum(calculate_normalized_sequence_digest(sequence)),
+        );
+    }
+
+    Ok(dictionary)
+}
+
+// _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5 calculation"
+fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
+    let mut hasher = Md5::new();
+
+    for &b in sequence {
+        // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
+        if b.is_ascii_graphic() {
+            // "All lowercase characters are converted to uppercase."
+            hasher.update([b.to_ascii_uppercase()]);
+        }
+    }
+
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACGT_MD5_CHECKSUM: [u8; 16] = [
+        0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91, 0x04,
+        0x3e,
+    ];
+
+    #[test]
+    fn test_from_reader() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1 LN:4\nac\ngt\n";
+        let mut reader = Reader::new(&data[..]);
+
+        let actual = from_reader(&mut reader)?;
+
+        let expected: SequenceDictionary = [
+            Sequence::new("sq0")
+                .set_length(4)
+                .set_md5_checksum(ACGT_MD5_CHECKSUM),
+            Sequence::new("sq1")
+                .set_length(4)
+                .set_md5_checksum(ACGT_MD5_CHECKSUM),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_normalized_sequence_digest() {
+        assert_eq!(
+            calculate_normalized_sequence_digest(b"ACGT"),
+            ACGT_MD5_CHECKSUM
+        );
+        assert_eq!(
+            calculate_normalized_sequence_digest(b"ACgt"),
+            ACGT_MD5_CHECKSUM
+        );
+        assert_eq!(
+            calculate_normalized_sequence_digest(b"AC GT\t"),
+            ACGT_MD5_CHECKSUM
+        );
+    }
+}
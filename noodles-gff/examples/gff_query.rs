@@ -0,0 +1,40 @@
+//! Prints records in a GFF file that intersect the given regions.
+//!
+//! The records are loaded into an in-memory interval tree, which is then queried with each region.
+//!
+//! The results match the output of `awk -F '\t' '$1 == <name> && $4 <= <end> && $5 >= <start>'
+//! <src>` for each region.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_core::{region::IntervalTree, Region};
+use noodles_gff as gff;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+    let src = args.next().expect("missing src");
+
+    let mut reader = File::open(src).map(BufReader::new).map(gff::Reader::new)?;
+
+    let tree: IntervalTree<gff::Record> = reader
+        .records()
+        .map(|result| result.map(<(Region, gff::Record)>::from))
+        .collect::<io::Result<_>>()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = gff::Writer::new(stdout);
+
+    for raw_region in args {
+        let region: Region = raw_region.parse()?;
+
+        for (_, record) in tree.query(region.name(), region.interval()) {
+            writer.write_record(record)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
 //! GFF reader and iterators.
 
 mod lines;
+mod query_regions;
 mod records;
 
-pub use self::{lines::Lines, records::Records};
+pub use self::{lines::Lines, query_regions::QueryRegions, records::Records};
 
 use std::{
     io::{self, BufRead, Read, Seek},
@@ -12,7 +13,7 @@ use std::{
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::{self as csi, BinningIndex};
+use noodles_csi::{self as csi, binning_index::query_intervals, BinningIndex};
 
 use super::{lazy, Record};
 
@@ -214,19 +215,7 @@ where
             .header()
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
 
-        let region_name = str::from_utf8(region.name())
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-        let reference_sequence_id = header
-            .reference_sequence_names()
-            .get_index_of(region_name)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "missing reference sequence name",
-                )
-            })?;
-
+        let reference_sequence_id = resolve_region(header, region)?;
         let chunks = index.query(reference_sequence_id, region.interval())?;
 
         let records = csi::io::Query::new(&mut self.inner, chunks)
@@ -242,6 +231,78 @@ where
 
         Ok(records)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. The regions that a record
+    /// intersects are available from [`QueryRegions::region_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut reader = File::open("annotations.gff3.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gff::Reader::new)?;
+    ///
+    /// let index = csi::read("annotations.gff3.gz.csi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&index, &regions)?;
+    ///
+    /// while let Some(record) = query.next().transpose()? {
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, I>(
+        &'r mut self,
+        index: &I,
+        regions: &'r [Region],
+    ) -> io::Result<QueryRegions<'r, R>>
+    where
+        I: BinningIndex,
+    {
+        let header = index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        let intervals = regions
+            .iter()
+            .map(|region| resolve_region(header, region).map(|id| (id, region.interval())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        let records = csi::io::Query::new(&mut self.inner, chunks)
+            .indexed_records(header)
+            .filter_by_regions(regions);
+
+        Ok(QueryRegions::new(records))
+    }
+}
+
+fn resolve_region(
+    header: &csi::binning_index::index::Header,
+    region: &Region,
+) -> io::Result<usize> {
+    let region_name = str::from_utf8(region.name())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    header
+        .reference_sequence_names()
+        .get_index_of(region_name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
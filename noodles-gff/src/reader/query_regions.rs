@@ -0,0 +1,59 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::io::{indexed_records, FilterByRegions, IndexedRecords, Query};
+
+use crate::Record;
+
+/// An iterator over records of a GFF reader that intersect any of the given regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`crate::Reader::query_regions`].
+pub struct QueryRegions<'r, R>
+where
+    R: Read + Seek,
+{
+    records:
+        FilterByRegions<'r, IndexedRecords<Query<'r, bgzf::Reader<R>>>, indexed_records::Record>,
+}
+
+impl<'r, R> QueryRegions<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(crate) fn new(
+        records: FilterByRegions<
+            'r,
+            IndexedRecords<Query<'r, bgzf::Reader<R>>>,
+            indexed_records::Record,
+        >,
+    ) -> Self {
+        Self { records }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        self.records.region_indices()
+    }
+}
+
+impl<'r, R> Iterator for QueryRegions<'r, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.records.next().map(|result| {
+            result.and_then(|r| {
+                r.as_ref()
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+        })
+    }
+}
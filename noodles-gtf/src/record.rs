@@ -9,7 +9,7 @@ pub use self::{attributes::Attributes, builder::Builder, frame::Frame, strand::S
 
 use std::{error, fmt, num, str::FromStr};
 
-use noodles_core::Position;
+use noodles_core::{Position, Region};
 
 pub(crate) const MISSING_FIELD: &str = ".";
 
@@ -174,6 +174,16 @@ impl Default for Record {
     }
 }
 
+impl From<Record> for (Region, Record) {
+    fn from(record: Record) -> Self {
+        let region = Region::new(
+            record.reference_sequence_name(),
+            record.start()..=record.end(),
+        );
+        (region, record)
+    }
+}
+
 impl fmt::Display for Record {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -434,4 +444,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_record_for_region_record_tuple() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0")
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .build();
+
+        let (region, _) = <(Region, Record)>::from(record);
+        assert_eq!(region, "sq0:8-13".parse()?);
+
+        Ok(())
+    }
 }
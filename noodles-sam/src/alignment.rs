@@ -1,5 +1,7 @@
 //! Alignment record and fields.
 
+pub mod calmd;
+pub mod fixmate;
 pub mod io;
 pub mod record;
 pub mod record_buf;
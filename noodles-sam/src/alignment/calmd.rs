@@ -0,0 +1,521 @@
+//! Alignment record mismatched positions (`MD`) and edit distance (`NM`) calculation.
+//!
+//! This is similar to `samtools calmd`. The reference sequence given to these functions is the
+//! entire sequence of the record's reference sequence, e.g., as returned by
+//! `noodles_fasta::Repository::get`.
+
+use std::io;
+
+use bstr::BString;
+
+use super::{
+    record::{
+        cigar::op::Kind,
+        data::field::{Tag, Value},
+    },
+    record_buf, Record, RecordBuf,
+};
+
+const MATCH: u8 = b'=';
+const MISSING: u8 = b'N';
+
+/// Calculated alignment record mismatched positions (`MD`) and edit distance (`NM`) values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tags {
+    mismatched_positions: BString,
+    edit_distance: u32,
+}
+
+impl Tags {
+    /// Returns the mismatched positions (`MD`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{
+    ///         calmd,
+    ///         record::{cigar::{op::Kind, Op}, Flags},
+    ///     },
+    /// };
+    ///
+    /// let record = sam::alignment::RecordBuf::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_alignment_start(Position::MIN)
+    ///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+    ///     .set_sequence(b"ACTT".into())
+    ///     .build();
+    ///
+    /// let tags = calmd::calculate(&record, b"ACGT")?.expect("missing tags");
+    /// assert_eq!(tags.mismatched_positions(), b"2G1");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn mismatched_positions(&self) -> &[u8] {
+        &self.mismatched_positions
+    }
+
+    /// Returns the edit distance (`NM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{
+    ///         calmd,
+    ///         record::{cigar::{op::Kind, Op}, Flags},
+    ///     },
+    /// };
+    ///
+    /// let record = sam::alignment::RecordBuf::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_alignment_start(Position::MIN)
+    ///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+    ///     .set_sequence(b"ACTT".into())
+    ///     .build();
+    ///
+    /// let tags = calmd::calculate(&record, b"ACGT")?.expect("missing tags");
+    /// assert_eq!(tags.edit_distance(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn edit_distance(&self) -> u32 {
+        self.edit_distance
+    }
+}
+
+/// Calculates the mismatched positions (`MD`) and edit distance (`NM`) of an alignment record.
+///
+/// This returns `None` if the record is unmapped or is missing an alignment start, CIGAR, or
+/// sequence.
+///
+/// As in `samtools calmd`, an `N` in either the read or reference sequence is always counted as a
+/// mismatch, and `=` in the read sequence is always counted as a match. Bases are compared
+/// case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         calmd,
+///         record::{cigar::{op::Kind, Op}, Flags},
+///     },
+/// };
+///
+/// let record = sam::alignment::RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar(
+///         [
+///             Op::new(Kind::Match, 2),
+///             Op::new(Kind::Deletion, 1),
+///             Op::new(Kind::Match, 2),
+///         ]
+///         .into_iter()
+///         .collect(),
+///     )
+///     .set_sequence(b"CGAT".into())
+///     .build();
+///
+/// let tags = calmd::calculate(&record, b"ACGTTT")?.expect("missing tags");
+/// assert_eq!(tags.mismatched_positions(), b"2^T0T1");
+/// assert_eq!(tags.edit_distance(), 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate(record: &dyn Record, reference_sequence: &[u8]) -> io::Result<Option<Tags>> {
+    if record.flags()?.is_unmapped() {
+        return Ok(None);
+    }
+
+    let Some(alignment_start) = record.alignment_start().transpose()? else {
+        return Ok(None);
+    };
+
+    let cigar = record.cigar();
+    let sequence = record.sequence();
+
+    if cigar.is_empty() || sequence.is_empty() {
+        return Ok(None);
+    }
+
+    let mut bases = sequence.iter();
+    let mut reference_position = usize::from(alignment_start) - 1;
+
+    let mut mismatched_positions = BString::default();
+    let mut edit_distance = 0;
+    let mut match_count = 0;
+
+    for result in cigar.iter() {
+        let op = result?;
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let reference_bases =
+                    get_reference_bases(reference_sequence, reference_position, len)?;
+
+                for &reference_base in reference_bases {
+                    let read_base = bases.next().ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "CIGAR read length-sequence length mismatch",
+                        )
+                    })?;
+
+                    if is_match(read_base, reference_base) {
+                        match_count += 1;
+                    } else {
+                        push_match_count(&mut mismatched_positions, &mut match_count);
+                        mismatched_positions.push(reference_base.to_ascii_uppercase());
+                        edit_distance += 1;
+                    }
+                }
+
+                reference_position += len;
+            }
+            Kind::Insertion => {
+                advance(&mut bases, len)?;
+                edit_distance += len;
+            }
+            Kind::Deletion => {
+                let reference_bases =
+                    get_reference_bases(reference_sequence, reference_position, len)?;
+
+                push_match_count(&mut mismatched_positions, &mut match_count);
+                mismatched_positions.push(b'^');
+
+                mismatched_positions.extend(reference_bases.iter().map(|b| b.to_ascii_uppercase()));
+
+                edit_distance += len;
+                reference_position += len;
+            }
+            Kind::Skip => reference_position += len,
+            Kind::SoftClip => advance(&mut bases, len)?,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    push_match_count(&mut mismatched_positions, &mut match_count);
+
+    let edit_distance =
+        u32::try_from(edit_distance).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(Some(Tags {
+        mismatched_positions,
+        edit_distance,
+    }))
+}
+
+/// Checks whether the mismatched positions (`MD`) and edit distance (`NM`) of an alignment record
+/// match the calculated values.
+///
+/// Missing tags are considered invalid. Records that cannot be calculated (see [`calculate`]) are
+/// always valid.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         calmd,
+///         record::{cigar::{op::Kind, Op}, data::field::Tag, Flags},
+///         record_buf::data::field::Value,
+///     },
+/// };
+///
+/// let record = sam::alignment::RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACTT".into())
+///     .set_data(
+///         [
+///             (Tag::MISMATCHED_POSITIONS, Value::from("2G1")),
+///             (Tag::EDIT_DISTANCE, Value::from(1)),
+///         ]
+///         .into_iter()
+///         .collect(),
+///     )
+///     .build();
+///
+/// assert!(calmd::check(&record, b"ACGT")?);
+/// assert!(!calmd::check(&record, b"ACTT")?);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn check(record: &dyn Record, reference_sequence: &[u8]) -> io::Result<bool> {
+    let Some(tags) = calculate(record, reference_sequence)? else {
+        return Ok(true);
+    };
+
+    let data = record.data();
+
+    let is_mismatched_positions_valid = match data.get(&Tag::MISMATCHED_POSITIONS).transpose()? {
+        Some(Value::String(s)) => s == tags.mismatched_positions(),
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid MD type",
+            ))
+        }
+        None => false,
+    };
+
+    let is_edit_distance_valid = match data.get(&Tag::EDIT_DISTANCE).transpose()? {
+        Some(value) => {
+            let n = value
+                .as_int()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid NM type"))?;
+
+            n == i64::from(tags.edit_distance())
+        }
+        None => false,
+    };
+
+    Ok(is_mismatched_positions_valid && is_edit_distance_valid)
+}
+
+/// Calculates and sets the mismatched positions (`MD`) and edit distance (`NM`) of an alignment
+/// record.
+///
+/// Existing `MD` and `NM` fields are replaced. The record is left unchanged if the values cannot
+/// be calculated (see [`calculate`]).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         calmd,
+///         record::{cigar::{op::Kind, Op}, data::field::Tag, Flags},
+///         record_buf::data::field::Value,
+///     },
+/// };
+///
+/// let mut record = sam::alignment::RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACTT".into())
+///     .build();
+///
+/// calmd::update(&mut record, b"ACGT")?;
+///
+/// let data = record.data();
+/// assert_eq!(data.get(&Tag::MISMATCHED_POSITIONS), Some(&Value::from("2G1")));
+/// assert_eq!(data.get(&Tag::EDIT_DISTANCE), Some(&Value::from(1)));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn update(record: &mut RecordBuf, reference_sequence: &[u8]) -> io::Result<()> {
+    if let Some(tags) = calculate(record, reference_sequence)? {
+        let data = record.data_mut();
+
+        data.insert(
+            Tag::MISMATCHED_POSITIONS,
+            record_buf::data::field::Value::String(tags.mismatched_positions),
+        );
+
+        data.insert(
+            Tag::EDIT_DISTANCE,
+            record_buf::data::field::Value::from(tags.edit_distance),
+        );
+    }
+
+    Ok(())
+}
+
+fn get_reference_bases(reference_sequence: &[u8], start: usize, len: usize) -> io::Result<&[u8]> {
+    start
+        .checked_add(len)
+        .and_then(|end| reference_sequence.get(start..end))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "alignment extends past the end of the reference sequence",
+            )
+        })
+}
+
+fn advance<I>(bases: &mut I, len: usize) -> io::Result<()>
+where
+    I: Iterator<Item = u8>,
+{
+    for _ in 0..len {
+        bases.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "CIGAR read length-sequence length mismatch",
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+fn is_match(read_base: u8, reference_base: u8) -> bool {
+    let read_base = read_base.to_ascii_uppercase();
+    let reference_base = reference_base.to_ascii_uppercase();
+
+    read_base == MATCH
+        || (read_base == reference_base && read_base != MISSING && reference_base != MISSING)
+}
+
+fn push_match_count(dst: &mut BString, match_count: &mut usize) {
+    dst.extend_from_slice(match_count.to_string().as_bytes());
+    *match_count = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::alignment::record::{
+        cigar::{op::Kind, Op},
+        Flags,
+    };
+
+    fn build_record(alignment_start: usize, ops: &[(Kind, usize)], sequence: &[u8]) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(alignment_start).unwrap())
+            .set_cigar(ops.iter().map(|&(kind, len)| Op::new(kind, len)).collect())
+            .set_sequence(sequence.into())
+            .build()
+    }
+
+    #[test]
+    fn test_calculate() -> io::Result<()> {
+        fn t(record: &RecordBuf, reference_sequence: &[u8], md: &[u8], nm: u32) -> io::Result<()> {
+            let tags = calculate(record, reference_sequence)?.expect("missing tags");
+            assert_eq!(tags.mismatched_positions(), md);
+            assert_eq!(tags.edit_distance(), nm);
+            Ok(())
+        }
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTAC";
+
+        let record = build_record(1, &[(Kind::Match, 4)], b"ACGT");
+        t(&record, REFERENCE_SEQUENCE, b"4", 0)?;
+
+        let record = build_record(1, &[(Kind::Match, 4)], b"acgt");
+        t(&record, REFERENCE_SEQUENCE, b"4", 0)?;
+
+        let record = build_record(1, &[(Kind::Match, 4)], b"TCGA");
+        t(&record, REFERENCE_SEQUENCE, b"0A2T0", 2)?;
+
+        let record = build_record(1, &[(Kind::Match, 4)], b"A=GN");
+        t(&record, REFERENCE_SEQUENCE, b"3T0", 1)?;
+
+        let record = build_record(
+            3,
+            &[
+                (Kind::SoftClip, 1),
+                (Kind::Match, 2),
+                (Kind::Insertion, 2),
+                (Kind::Match, 1),
+                (Kind::Deletion, 2),
+                (Kind::Skip, 1),
+                (Kind::Match, 1),
+                (Kind::HardClip, 3),
+            ],
+            b"NGTTTAT",
+        );
+        t(&record, REFERENCE_SEQUENCE, b"3^CG0A0", 5)?;
+
+        let record = build_record(
+            1,
+            &[
+                (Kind::SequenceMatch, 2),
+                (Kind::SequenceMismatch, 1),
+                (Kind::SequenceMatch, 1),
+            ],
+            b"ACTT",
+        );
+        t(&record, REFERENCE_SEQUENCE, b"2G1", 1)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_with_uncalculable_record() -> io::Result<()> {
+        let record = RecordBuf::default();
+        assert!(calculate(&record, b"ACGT")?.is_none());
+
+        let record = build_record(1, &[], b"ACGT");
+        assert!(calculate(&record, b"ACGT")?.is_none());
+
+        let record = build_record(1, &[(Kind::Match, 4)], b"");
+        assert!(calculate(&record, b"ACGT")?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_with_invalid_record() {
+        let record = build_record(2, &[(Kind::Match, 4)], b"CGTA");
+        assert!(matches!(
+            calculate(&record, b"ACGT"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = build_record(1, &[(Kind::Match, 4)], b"AC");
+        assert!(matches!(
+            calculate(&record, b"ACGT"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_check() -> io::Result<()> {
+        let mut record = build_record(1, &[(Kind::Match, 4)], b"ACTT");
+        assert!(!check(&record, b"ACGT")?);
+
+        update(&mut record, b"ACGT")?;
+        assert!(check(&record, b"ACGT")?);
+
+        record
+            .data_mut()
+            .insert(Tag::EDIT_DISTANCE, record_buf::data::field::Value::from(2));
+        assert!(!check(&record, b"ACGT")?);
+
+        let record = RecordBuf::default();
+        assert!(check(&record, b"ACGT")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update() -> io::Result<()> {
+        let mut record = build_record(1, &[(Kind::Match, 4)], b"ACTT");
+
+        record.data_mut().insert(
+            Tag::MISMATCHED_POSITIONS,
+            record_buf::data::field::Value::from("4"),
+        );
+
+        update(&mut record, b"ACGT")?;
+
+        let data = record.data();
+
+        assert_eq!(
+            data.get(&Tag::MISMATCHED_POSITIONS),
+            Some(&record_buf::data::field::Value::from("2G1"))
+        );
+
+        assert_eq!(
+            data.get(&Tag::EDIT_DISTANCE),
+            Some(&record_buf::data::field::Value::from(1))
+        );
+
+        Ok(())
+    }
+}
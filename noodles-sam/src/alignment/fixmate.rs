@@ -0,0 +1,445 @@
+//! Alignment record mate information fixing.
+//!
+//! This is similar to `samtools fixmate`. Records of the same template are expected to be
+//! adjacent, e.g., after sorting or collating by name.
+
+use std::{collections::VecDeque, io};
+
+use super::{
+    record::{data::field::Tag, Flags},
+    record_buf::data::field::Value,
+    RecordBuf,
+};
+use crate::io::writer::record::write_cigar;
+
+const MISSING_MAPPING_QUALITY: u8 = 255;
+
+/// An iterator that fixes the mate information of name-collated alignment records.
+///
+/// Consecutive records with the same name are grouped into a template, which is fixed using
+/// [`fix_template`]. Records are returned in the same order they are read.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{fixmate::FixMates, record::Flags},
+/// };
+///
+/// let records = [
+///     sam::alignment::RecordBuf::builder()
+///         .set_name(b"r0".into())
+///         .set_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::FIRST_SEGMENT)
+///         .build(),
+///     sam::alignment::RecordBuf::builder()
+///         .set_name(b"r0".into())
+///         .set_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::LAST_SEGMENT)
+///         .build(),
+/// ];
+///
+/// let records: Vec<_> = FixMates::new(records.into_iter().map(Ok)).collect::<io::Result<_>>()?;
+///
+/// assert!(records.iter().all(|record| record.flags().is_mate_unmapped()));
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct FixMates<I> {
+    records: I,
+    next_record: Option<RecordBuf>,
+    template: VecDeque<RecordBuf>,
+}
+
+impl<I> FixMates<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    /// Creates a mate information fixing iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::fixmate::FixMates;
+    /// let mut records = FixMates::new(std::iter::empty());
+    /// assert!(records.next().is_none());
+    /// ```
+    pub fn new(records: I) -> Self {
+        Self {
+            records,
+            next_record: None,
+            template: VecDeque::new(),
+        }
+    }
+
+    fn read_template(&mut self) -> Option<io::Result<Vec<RecordBuf>>> {
+        let first_record = match self.next_record.take() {
+            Some(record) => record,
+            None => match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            },
+        };
+
+        let mut template = vec![first_record];
+
+        for result in self.records.by_ref() {
+            let record = match result {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if record.name().is_some() && record.name() == template[0].name() {
+                template.push(record);
+            } else {
+                self.next_record = Some(record);
+                break;
+            }
+        }
+
+        Some(Ok(template))
+    }
+}
+
+impl<I> Iterator for FixMates<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.template.pop_front() {
+                return Some(Ok(record));
+            }
+
+            let mut template = match self.read_template()? {
+                Ok(template) => template,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if let Err(e) = fix_template(&mut template) {
+                return Some(Err(e));
+            }
+
+            self.template.extend(template);
+        }
+    }
+}
+
+/// Fixes the mate information of the records of a template.
+///
+/// If the template has exactly two primary segments, they are fixed using [`fix_mates`]. Other
+/// templates, and secondary and supplementary records, are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{fixmate, record::Flags},
+/// };
+///
+/// let mut records = [
+///     sam::alignment::RecordBuf::builder()
+///         .set_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::FIRST_SEGMENT)
+///         .build(),
+///     sam::alignment::RecordBuf::builder()
+///         .set_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::LAST_SEGMENT)
+///         .build(),
+/// ];
+///
+/// fixmate::fix_template(&mut records)?;
+///
+/// assert!(records.iter().all(|record| record.flags().is_mate_unmapped()));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn fix_template(records: &mut [RecordBuf]) -> io::Result<()> {
+    let mut segments = records.iter_mut().filter(|record| {
+        let flags = record.flags();
+        flags.is_segmented() && !flags.is_secondary() && !flags.is_supplementary()
+    });
+
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some(a), Some(b), None) => fix_mates(a, b),
+        _ => Ok(()),
+    }
+}
+
+/// Fixes the mate information of two mates.
+///
+/// An unmapped mate is placed at the position of a mapped mate. Then, for each record, this sets
+///
+///   * the mate reference sequence ID and mate alignment start;
+///   * the mate reverse complemented (`0x20`) and mate unmapped (`0x08`) flags;
+///   * the template length, as the distance between the 5' ends of the mates when both are
+///     mapped to the same reference sequence, otherwise 0; and
+///   * the mate CIGAR (`MC`) and mate mapping quality (`MQ`) data fields when the mate is mapped.
+///     They are removed otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         fixmate,
+///         record::{cigar::{op::Kind, Op}, data::field::Tag, Flags},
+///         record_buf::data::field::Value,
+///     },
+/// };
+///
+/// let mut a = sam::alignment::RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// let mut b = sam::alignment::RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED | Flags::LAST_SEGMENT)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(21)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// fixmate::fix_mates(&mut a, &mut b)?;
+///
+/// assert_eq!(a.mate_reference_sequence_id(), Some(0));
+/// assert_eq!(a.mate_alignment_start(), Some(Position::try_from(21)?));
+/// assert!(a.flags().is_mate_reverse_complemented());
+/// assert_eq!(a.template_length(), 17);
+/// assert_eq!(a.data().get(&Tag::MATE_CIGAR), Some(&Value::from("4M")));
+///
+/// assert_eq!(b.mate_alignment_start(), Some(Position::try_from(8)?));
+/// assert_eq!(b.template_length(), -17);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn fix_mates(a: &mut RecordBuf, b: &mut RecordBuf) -> io::Result<()> {
+    place_unmapped(a, b);
+    place_unmapped(b, a);
+
+    set_mate(a, b)?;
+    set_mate(b, a)?;
+
+    let (a_template_length, b_template_length) = calculate_template_lengths(a, b)?;
+    *a.template_length_mut() = a_template_length;
+    *b.template_length_mut() = b_template_length;
+
+    Ok(())
+}
+
+fn place_unmapped(record: &mut RecordBuf, mate: &RecordBuf) {
+    if record.flags().is_unmapped() && !mate.flags().is_unmapped() {
+        *record.reference_sequence_id_mut() = mate.reference_sequence_id();
+        *record.alignment_start_mut() = mate.alignment_start();
+    }
+}
+
+fn set_mate(record: &mut RecordBuf, mate: &RecordBuf) -> io::Result<()> {
+    *record.mate_reference_sequence_id_mut() = mate.reference_sequence_id();
+    *record.mate_alignment_start_mut() = mate.alignment_start();
+
+    let mate_flags = mate.flags();
+
+    let flags = record.flags_mut();
+    flags.set(
+        Flags::MATE_REVERSE_COMPLEMENTED,
+        mate_flags.is_reverse_complemented(),
+    );
+    flags.set(Flags::MATE_UNMAPPED, mate_flags.is_unmapped());
+
+    let data = record.data_mut();
+
+    if mate_flags.is_unmapped() {
+        data.remove(&Tag::MATE_CIGAR);
+        data.remove(&Tag::MATE_MAPPING_QUALITY);
+        return Ok(());
+    }
+
+    if mate.cigar().as_ref().is_empty() {
+        data.remove(&Tag::MATE_CIGAR);
+    } else {
+        let mut buf = Vec::new();
+        write_cigar(&mut buf, &mate.cigar())?;
+        data.insert(Tag::MATE_CIGAR, Value::String(buf.into()));
+    }
+
+    let mapping_quality = mate
+        .mapping_quality()
+        .map(u8::from)
+        .unwrap_or(MISSING_MAPPING_QUALITY);
+
+    data.insert(Tag::MATE_MAPPING_QUALITY, Value::from(mapping_quality));
+
+    Ok(())
+}
+
+fn calculate_template_lengths(a: &RecordBuf, b: &RecordBuf) -> io::Result<(i32, i32)> {
+    if a.flags().is_unmapped()
+        || b.flags().is_unmapped()
+        || a.reference_sequence_id() != b.reference_sequence_id()
+    {
+        return Ok((0, 0));
+    }
+
+    let (Some(a_position), Some(b_position)) = (five_prime_position(a), five_prime_position(b))
+    else {
+        return Ok((0, 0));
+    };
+
+    let len = i32::try_from(b_position - a_position)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok((len, -len))
+}
+
+// Returns the 0-based position of the 5' end, or, for the reverse strand, the exclusive end.
+fn five_prime_position(record: &RecordBuf) -> Option<i64> {
+    if record.flags().is_reverse_complemented() {
+        record.alignment_end().map(|end| usize::from(end) as i64)
+    } else {
+        record
+            .alignment_start()
+            .map(|start| usize::from(start) as i64 - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::alignment::record::{
+        cigar::{op::Kind, Op},
+        MappingQuality,
+    };
+
+    fn build_record(
+        name: &str,
+        flags: Flags,
+        alignment_start: Option<usize>,
+    ) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        let mut builder = RecordBuf::builder()
+            .set_name(name.as_bytes().into())
+            .set_flags(Flags::SEGMENTED | flags);
+
+        if let Some(start) = alignment_start {
+            builder = builder
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_mapping_quality(MappingQuality::try_from(30)?)
+                .set_cigar(
+                    [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 4)]
+                        .into_iter()
+                        .collect(),
+                );
+        }
+
+        Ok(builder.build())
+    }
+
+    #[test]
+    fn test_fix_mates() -> Result<(), Box<dyn std::error::Error>> {
+        let mut a = build_record("r0", Flags::FIRST_SEGMENT, Some(8))?;
+        let mut b = build_record(
+            "r0",
+            Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED | Flags::MATE_UNMAPPED,
+            Some(21),
+        )?;
+
+        fix_mates(&mut a, &mut b)?;
+
+        assert_eq!(a.mate_reference_sequence_id(), Some(0));
+        assert_eq!(a.mate_alignment_start(), Some(Position::try_from(21)?));
+        assert_eq!(
+            a.flags(),
+            Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_REVERSE_COMPLEMENTED
+        );
+        assert_eq!(a.template_length(), 17);
+        assert_eq!(a.data().get(&Tag::MATE_CIGAR), Some(&Value::from("1S4M")));
+        assert_eq!(
+            a.data().get(&Tag::MATE_MAPPING_QUALITY),
+            Some(&Value::from(30))
+        );
+
+        assert_eq!(b.mate_reference_sequence_id(), Some(0));
+        assert_eq!(b.mate_alignment_start(), Some(Position::try_from(8)?));
+        assert_eq!(
+            b.flags(),
+            Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED
+        );
+        assert_eq!(b.template_length(), -17);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fix_mates_with_unmapped_mate() -> Result<(), Box<dyn std::error::Error>> {
+        let mut a = build_record("r0", Flags::FIRST_SEGMENT, Some(8))?;
+        a.data_mut().insert(Tag::MATE_CIGAR, Value::from("4M"));
+
+        let mut b = build_record("r0", Flags::LAST_SEGMENT | Flags::UNMAPPED, None)?;
+
+        fix_mates(&mut a, &mut b)?;
+
+        assert!(a.flags().is_mate_unmapped());
+        assert_eq!(a.mate_alignment_start(), Some(Position::try_from(8)?));
+        assert_eq!(a.template_length(), 0);
+        assert!(a.data().get(&Tag::MATE_CIGAR).is_none());
+        assert!(a.data().get(&Tag::MATE_MAPPING_QUALITY).is_none());
+
+        assert_eq!(b.reference_sequence_id(), Some(0));
+        assert_eq!(b.alignment_start(), Some(Position::try_from(8)?));
+        assert_eq!(b.mate_alignment_start(), Some(Position::try_from(8)?));
+        assert!(!b.flags().is_mate_unmapped());
+        assert_eq!(b.template_length(), 0);
+        assert_eq!(b.data().get(&Tag::MATE_CIGAR), Some(&Value::from("1S4M")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fix_mates_iter() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record("r0", Flags::FIRST_SEGMENT, Some(8))?,
+            build_record("r0", Flags::SECONDARY, Some(55))?,
+            build_record("r0", Flags::LAST_SEGMENT, Some(34))?,
+            build_record("r1", Flags::FIRST_SEGMENT, Some(13))?,
+            build_record("r2", Flags::FIRST_SEGMENT, Some(21))?,
+            build_record("r2", Flags::LAST_SEGMENT, Some(5))?,
+        ];
+
+        let actual: Vec<_> =
+            FixMates::new(records.clone().into_iter().map(Ok)).collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), records.len());
+
+        let names: Vec<_> = actual.iter().map(|record| record.name()).collect();
+        let expected: Vec<_> = records.iter().map(|record| record.name()).collect();
+        assert_eq!(names, expected);
+
+        assert_eq!(
+            actual[0].mate_alignment_start(),
+            Some(Position::try_from(34)?)
+        );
+        assert_eq!(actual[0].template_length(), 26);
+        assert_eq!(actual[1], records[1]);
+        assert_eq!(
+            actual[2].mate_alignment_start(),
+            Some(Position::try_from(8)?)
+        );
+        assert_eq!(actual[3], records[3]);
+        assert_eq!(
+            actual[4].mate_alignment_start(),
+            Some(Position::try_from(5)?)
+        );
+        assert_eq!(
+            actual[5].mate_alignment_start(),
+            Some(Position::try_from(21)?)
+        );
+        assert_eq!(actual[5].template_length(), 16);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//! Sequence dictionary (`.dict`) files.
+//!
+//! A sequence dictionary file, as created by Picard `CreateSequenceDictionary`, is a SAM header
+//! with a header (`@HD`) record and one reference sequence (`@SQ`) record per sequence in the
+//! reference. Each reference sequence has a length (`LN`), MD5 checksum (`M5`), and, optionally, a
+//! URI (`UR`) of the reference.
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use noodles_core::SequenceDictionary;
+
+use crate::{
+    header::{record::value::map::reference_sequence::tag, sequence_dictionary},
+    io::{Reader, Writer},
+    Header,
+};
+
+/// Reads a sequence dictionary file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_sam::dict;
+/// let header = dict::read("reference.dict")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read<P>(src: P) -> io::Result<Header>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(BufReader::new).map(Reader::new)?;
+    reader.read_header()
+}
+
+/// Writes a sequence dictionary file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_sam::{self as sam, dict};
+/// let header = sam::Header::default();
+/// dict::write("reference.dict", &header)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn write<P>(dst: P, header: &Header) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let mut writer = File::create(dst).map(BufWriter::new).map(Writer::new)?;
+    writer.write_header(header)?;
+    writer.get_mut().flush()
+}
+
+/// Creates a sequence dictionary file header from a sequence dictionary.
+///
+/// If a URI is given, it is set as the `UR` field of every reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+/// use noodles_sam::dict;
+///
+/// let dictionary: SequenceDictionary = [Sequence::new("sq0").set_length(8)]
+///     .into_iter()
+///     .collect();
+///
+/// let header = dict::from_sequence_dictionary(&dictionary, Some("file:/tmp/reference.fa"))?;
+///
+/// assert!(header.header().is_some());
+/// assert_eq!(header.reference_sequences().len(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn from_sequence_dictionary(
+    dictionary: &SequenceDictionary,
+    uri: Option<&str>,
+) -> io::Result<Header> {
+    let mut reference_sequences = sequence_dictionary::to_reference_sequences(dictionary)?;
+
+    if let Some(uri) = uri {
+        for reference_sequence in reference_sequences.values_mut() {
+            reference_sequence
+                .other_fields_mut()
+                .insert(tag::URI, uri.into());
+        }
+    }
+
+    Ok(Header::builder()
+        .set_header(Default::default())
+        .set_reference_sequences(reference_sequences)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::sequence_dictionary::Sequence;
+
+    use super::*;
+
+    #[test]
+    fn test_from_sequence_dictionary() -> io::Result<()> {
+        let dictionary: SequenceDictionary = [
+            Sequence::new("sq0").set_length(4).set_md5_checksum([
+                0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91,
+                0x04, 0x3e,
+            ]),
+            Sequence::new("sq1").set_length(13),
+        ]
+        .into_iter()
+        .collect();
+
+        let header = from_sequence_dictionary(&dictionary, Some("file:/tmp/reference.fa"))?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let expected = b"@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:4\tM5:f1f8f4bf413b16ad135722aa4591043e\tUR:file:/tmp/reference.fa
+@SQ\tSN:sq1\tLN:13\tUR:file:/tmp/reference.fa
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut reader = Reader::new(&expected[..]);
+        assert_eq!(reader.read_header()?, header);
+
+        Ok(())
+    }
+}
@@ -74,6 +74,7 @@
 mod builder;
 mod parser;
 pub mod record;
+pub mod sequence_dictionary;
 
 pub use self::{
     builder::Builder,
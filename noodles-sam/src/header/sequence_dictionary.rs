@@ -0,0 +1,182 @@
+//! Conversions between SAM reference sequences and a sequence dictionary.
+
+use std::{io, num::NonZeroUsize};
+
+use bstr::{BString, ByteSlice};
+use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+
+use super::{
+    record::value::{
+        map::{
+            reference_sequence::{tag, Md5Checksum},
+            ReferenceSequence,
+        },
+        Map,
+    },
+    ReferenceSequences,
+};
+
+/// Converts SAM reference sequences to a sequence dictionary.
+///
+/// The MD5 checksum (`M5`) and alternative names (`AN`) fields are carried over, if present.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_sam::header::{
+///     record::value::{map::ReferenceSequence, Map},
+///     sequence_dictionary, ReferenceSequences,
+/// };
+///
+/// let reference_sequences: ReferenceSequences = [(
+///     "sq0".into(),
+///     Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+/// )]
+/// .into_iter()
+/// .collect();
+///
+/// let dictionary = sequence_dictionary::from_reference_sequences(&reference_sequences)?;
+/// assert_eq!(dictionary.get(b"sq0").and_then(|sequence| sequence.length()), Some(8));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn from_reference_sequences(
+    reference_sequences: &ReferenceSequences,
+) -> io::Result<SequenceDictionary> {
+    let mut dictionary = SequenceDictionary::new();
+
+    for (name, reference_sequence) in reference_sequences {
+        let mut sequence =
+            Sequence::new(name.to_vec()).set_length(usize::from(reference_sequence.length()));
+
+        let other_fields = reference_sequence.other_fields();
+
+        if let Some(raw_md5_checksum) = other_fields.get(&tag::MD5_CHECKSUM) {
+            let md5_checksum: Md5Checksum = raw_md5_checksum
+                .to_str()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            sequence = sequence.set_md5_checksum(md5_checksum.into());
+        }
+
+        if let Some(raw_alternative_names) = other_fields.get(&tag::ALTERNATIVE_NAMES) {
+            let aliases = raw_alternative_names
+                .split_str(",")
+                .map(|alias| alias.to_vec());
+
+            sequence = sequence.set_aliases(aliases);
+        }
+
+        dictionary.insert(sequence);
+    }
+
+    Ok(dictionary)
+}
+
+/// Converts a sequence dictionary to SAM reference sequences.
+///
+/// Every sequence must have a nonzero length. MD5 checksums and aliases are written to the `M5`
+/// and `AN` fields, respectively.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+/// use noodles_sam::header::sequence_dictionary;
+///
+/// let dictionary: SequenceDictionary = [Sequence::new("sq0").set_length(8)]
+///     .into_iter()
+///     .collect();
+///
+/// let reference_sequences = sequence_dictionary::to_reference_sequences(&dictionary)?;
+/// assert_eq!(usize::from(reference_sequences[0].length()), 8);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn to_reference_sequences(dictionary: &SequenceDictionary) -> io::Result<ReferenceSequences> {
+    let mut reference_sequences = ReferenceSequences::with_capacity(dictionary.len());
+
+    for sequence in dictionary.iter() {
+        let length = sequence
+            .length()
+            .and_then(NonZeroUsize::new)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "invalid reference sequence length: {}",
+                        sequence.name().as_bstr()
+                    ),
+                )
+            })?;
+
+        let mut reference_sequence = Map::<ReferenceSequence>::new(length);
+        let other_fields = reference_sequence.other_fields_mut();
+
+        if let Some(md5_checksum) = sequence.md5_checksum() {
+            let value = Md5Checksum::from(md5_checksum).to_string();
+            other_fields.insert(tag::MD5_CHECKSUM, value.into());
+        }
+
+        if !sequence.aliases().is_empty() {
+            let value = bstr::join(",", sequence.aliases());
+            other_fields.insert(tag::ALTERNATIVE_NAMES, value.into());
+        }
+
+        reference_sequences.insert(BString::from(sequence.name()), reference_sequence);
+    }
+
+    Ok(reference_sequences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let mut sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?);
+        sq0.other_fields_mut()
+            .insert(tag::MD5_CHECKSUM, "d7eba311421bbc9d3ada44709dd61534".into());
+        sq0.other_fields_mut()
+            .insert(tag::ALTERNATIVE_NAMES, "0,chr0".into());
+
+        let sq1 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?);
+
+        let reference_sequences: ReferenceSequences = [("sq0".into(), sq0), ("sq1".into(), sq1)]
+            .into_iter()
+            .collect();
+
+        let dictionary = from_reference_sequences(&reference_sequences)?;
+
+        let expected: SequenceDictionary = [
+            Sequence::new("sq0")
+                .set_length(8)
+                .set_md5_checksum([
+                    0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d,
+                    0xd6, 0x15, 0x34,
+                ])
+                .set_aliases([b"0".to_vec(), b"chr0".to_vec()]),
+            Sequence::new("sq1").set_length(13),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(dictionary, expected);
+        assert_eq!(to_reference_sequences(&dictionary)?, reference_sequences);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_reference_sequences_with_missing_length() {
+        let dictionary: SequenceDictionary = [Sequence::new("sq0")].into_iter().collect();
+
+        assert!(matches!(
+            to_reference_sequences(&dictionary),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
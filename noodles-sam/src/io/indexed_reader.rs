@@ -9,7 +9,10 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 pub use self::builder::Builder;
-use super::{reader::RecordBufs, Reader};
+use super::{
+    reader::{QueryRegions, RecordBufs},
+    Reader,
+};
 use crate::{alignment::RecordBuf, Header, Record};
 
 /// An indexed SAM reader.
@@ -93,6 +96,17 @@ where
         self.inner.query(header, &self.index, region)
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions<'a>(
+        &'a mut self,
+        header: &'a Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'a, R>> {
+        self.inner.query_regions(header, &self.index, regions)
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     pub fn query_unmapped(&mut self) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
         self.inner.query_unmapped(&self.index)
@@ -3,6 +3,7 @@
 mod builder;
 mod header;
 mod query;
+mod query_regions;
 mod record;
 pub(crate) mod record_buf;
 mod record_bufs;
@@ -10,13 +11,14 @@ mod record_bufs;
 use std::{
     io::{self, BufRead, Read, Seek},
     iter,
+    ops::Range,
 };
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 
-pub use self::{builder::Builder, record_bufs::RecordBufs};
+pub use self::{builder::Builder, query_regions::QueryRegions, record_bufs::RecordBufs};
 use self::{record::read_record, record_buf::read_record_buf};
 use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
 
@@ -341,6 +343,58 @@ where
         ))
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. The regions that a record
+    /// intersects are available from [`QueryRegions::region_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.sam.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(sam::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = csi::read("sample.sam.gz.csi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some(record) = query.next().transpose()? {
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'a, I>(
+        &'a mut self,
+        header: &'a Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'a, R>>
+    where
+        I: BinningIndex,
+    {
+        let intervals = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.reference_sequences(), region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(QueryRegions::new(self.get_mut(), header, chunks, intervals))
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// ```no_run
@@ -395,6 +449,73 @@ where
             }
         }))
     }
+
+    /// Returns an iterator over records that start in the given partition.
+    ///
+    /// Partitions typically come from [`bgzf::partition::partition`]. Unless the partition starts
+    /// at the beginning of the stream, the partial line at the start of the partition is skipped.
+    /// Any header lines are also skipped. Records are then read until one starts after the end of
+    /// the partition.
+    ///
+    /// Given contiguous partitions of a stream, each record is read by exactly one partition.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.sam.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(sam::io::Reader::new)?;
+    ///
+    /// reader.read_header()?;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let partitions = bgzf::partition::partition(reader.get_mut().get_mut(), worker_count)?;
+    ///
+    /// for partition in &partitions {
+    ///     for result in reader.query_partition(partition)? {
+    ///         let record = result?;
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_partition(
+        &mut self,
+        partition: &Range<bgzf::VirtualPosition>,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
+        const HEADER_PREFIX: u8 = b'@';
+
+        self.seek(partition.start)?;
+
+        if partition.start != bgzf::VirtualPosition::default() {
+            self.buf.clear();
+            read_line(&mut self.inner, &mut self.buf)?;
+        }
+
+        while self.inner.fill_buf()?.first() == Some(&HEADER_PREFIX) {
+            self.buf.clear();
+            read_line(&mut self.inner, &mut self.buf)?;
+        }
+
+        let end = partition.end;
+        let mut record = Record::default();
+
+        Ok(iter::from_fn(move || {
+            if self.get_ref().virtual_position() > end {
+                return None;
+            }
+
+            match self.read_record(&mut record) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok(record.clone())),
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
 }
 
 impl<R> From<R> for Reader<R>
@@ -467,6 +588,128 @@ fn resolve_region(reference_sequences: &ReferenceSequences, region: &Region) ->
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_core::Position;
+        use noodles_csi::{
+            self as csi,
+            binning_index::{index::reference_sequence::bin::Chunk, Indexer},
+        };
+
+        let records = [
+            ("r0", 0, 1),
+            ("r1", 0, 5),
+            ("r2", 0, 9),
+            ("r3", 0, 13),
+            ("r4", 1, 1),
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"@SQ\tSN:sq0\tLN:144\n@SQ\tSN:sq1\tLN:144\n")?;
+
+        let mut indexer = Indexer::default();
+
+        for (name, reference_sequence_id, alignment_start) in records {
+            let start_position = writer.virtual_position();
+            writeln!(
+                writer,
+                "{name}\t0\tsq{reference_sequence_id}\t{alignment_start}\t255\t4M\t*\t0\t0\t*\t*"
+            )?;
+            let end_position = writer.virtual_position();
+
+            let alignment_start = Position::try_from(alignment_start)?;
+            let alignment_end = alignment_start
+                .checked_add(3)
+                .ok_or("invalid alignment end")?;
+
+            indexer.add_record(
+                Some((reference_sequence_id, alignment_start, alignment_end, true)),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index: csi::Index = indexer.build(2);
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        let header = reader.read_header()?;
+
+        let regions = ["sq0:6-10".parse()?, "sq0:9-9".parse()?, "sq1".parse()?];
+
+        let mut query = reader.query_regions(&header, &index, &regions)?;
+        let mut actual = Vec::new();
+
+        while let Some(record) = query.next().transpose()? {
+            let name = record.name().map(|name| name.as_ref().to_vec());
+            actual.push((name, query.region_indices().to_vec()));
+        }
+
+        let expected = [
+            (Some(b"r1".to_vec()), vec![0]),
+            (Some(b"r2".to_vec()), vec![0, 1]),
+            (Some(b"r4".to_vec()), vec![2]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_partition() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::{Cursor, Write},
+            num::NonZeroUsize,
+        };
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"@HD\tVN:1.6\n")?;
+        writer.flush()?;
+        writer.write_all(b"@SQ\tSN:sq0\tLN:8\n")?;
+        writer.flush()?;
+        writer.write_all(b"r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n")?;
+        writer.flush()?;
+        writer.write_all(b"r1\t4\t*\t0\t255\t*")?;
+        writer.flush()?;
+        writer.write_all(b"\t*\t0\t0\t*\t*\nr2\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\nr3")?;
+        writer.flush()?;
+        writer.write_all(b"\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n")?;
+        writer.flush()?;
+        writer.write_all(b"r4\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        reader.read_header()?;
+
+        for count in 1..=8 {
+            let partitions = bgzf::partition::partition(
+                reader.get_mut().get_mut(),
+                NonZeroUsize::try_from(count)?,
+            )?;
+
+            let mut names = Vec::new();
+
+            for partition in &partitions {
+                for result in reader.query_partition(partition)? {
+                    let record = result?;
+                    let name = record.name().map(|name| name.as_ref().to_vec());
+                    names.push(name);
+                }
+            }
+
+            let expected: Vec<_> = ["r0", "r1", "r2", "r3", "r4"]
+                .into_iter()
+                .map(|name| Some(name.as_bytes().to_vec()))
+                .collect();
+
+            assert_eq!(names, expected);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         fn t(buf: &mut Vec<u8>, mut reader: &[u8], expected: &[u8]) -> io::Result<()> {
@@ -11,7 +11,7 @@ pub struct Query<'a, R>
 where
     R: Read + Seek,
 {
-    reader: Reader<csi::io::Query<'a, R>>,
+    reader: Reader<csi::io::Query<'a, bgzf::Reader<R>>>,
     record: Record,
 }
 
@@ -102,7 +102,7 @@ where
     }
 }
 
-fn intersects(
+pub(super) fn intersects(
     header: &Header,
     record: &Record,
     reference_sequence_id: usize,
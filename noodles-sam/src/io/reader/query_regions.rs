@@ -0,0 +1,84 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::binning_index::index::reference_sequence::bin::Chunk;
+
+use super::query::{intersects, Query};
+use crate::{Header, Record};
+
+/// An iterator over records of a SAM reader that intersect any of a list of regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`super::Reader::query_regions`].
+pub struct QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    records: Query<'a, R>,
+    header: &'a Header,
+    intervals: Vec<(usize, Interval)>,
+    region_indices: Vec<usize>,
+}
+
+impl<'a, R> QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'a mut bgzf::Reader<R>,
+        header: &'a Header,
+        chunks: Vec<Chunk>,
+        intervals: Vec<(usize, Interval)>,
+    ) -> Self {
+        Self {
+            records: Query::new(reader, chunks),
+            header,
+            intervals,
+            region_indices: Vec::new(),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        &self.region_indices
+    }
+
+    fn update_region_indices(&mut self, record: &Record) -> io::Result<()> {
+        self.region_indices.clear();
+
+        for (i, &(reference_sequence_id, interval)) in self.intervals.iter().enumerate() {
+            if intersects(self.header, record, reference_sequence_id, interval)? {
+                self.region_indices.push(i);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, R> Iterator for QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            match self.update_region_indices(&record) {
+                Ok(()) if !self.region_indices.is_empty() => return Some(Ok(record)),
+                Ok(()) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
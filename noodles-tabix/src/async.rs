@@ -1,9 +1,10 @@
 //! Async tabix index and fields.
 
+mod indexed_reader;
 mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
 
 use std::path::Path;
 
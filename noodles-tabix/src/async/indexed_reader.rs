@@ -0,0 +1,359 @@
+use std::{slice, str, vec};
+
+use futures::{stream, Stream, TryStreamExt};
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{
+    binning_index::index::{reference_sequence::bin::Chunk, Header},
+    binning_index::query_intervals,
+    io::{indexed_records::Record, IndexedRecord},
+    BinningIndex,
+};
+use tokio::io::{self, AsyncBufReadExt, AsyncRead, AsyncSeek};
+
+use crate::Index;
+
+/// An async indexed reader of bgzipped tab-delimited files.
+///
+/// This is the async counterpart to [`noodles_csi::io::IndexedReader`] for tabix-indexed files,
+/// e.g., BED or GFF.
+pub struct IndexedReader<R>
+where
+    R: AsyncRead,
+{
+    inner: bgzf::AsyncReader<R>,
+    index: Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: AsyncRead,
+{
+    /// Creates an async indexed reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// let reader = tabix::r#async::IndexedReader::new(&[][..], tabix::Index::default());
+    /// ```
+    pub fn new(inner: R, index: Index) -> Self {
+        Self {
+            inner: bgzf::AsyncReader::new(inner),
+            index,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &bgzf::AsyncReader<R> {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut bgzf::AsyncReader<R> {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> bgzf::AsyncReader<R> {
+        self.inner
+    }
+
+    /// Returns the associated index.
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    /// Returns a stream over records that intersect the given region.
+    ///
+    /// The index must have a header, which describes the columns of the records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_tabix as tabix;
+    /// use tokio::fs::File;
+    ///
+    /// let index = tabix::r#async::read("sample.bed.gz.tbi").await?;
+    /// let mut reader = File::open("sample.bed.gz")
+    ///     .await
+    ///     .map(|f| tabix::r#async::IndexedReader::new(f, index))?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// let mut query = reader.query(&region)?;
+    ///
+    /// while let Some(record) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query<'r>(
+        &'r mut self,
+        region: &'r Region,
+    ) -> io::Result<impl Stream<Item = io::Result<Record>> + 'r> {
+        let header = self
+            .index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        let reference_sequence_id = resolve_region(header, region)?;
+        let chunks = self.index.query(reference_sequence_id, region.interval())?;
+
+        Ok(
+            query(&mut self.inner, chunks, header, slice::from_ref(region))
+                .map_ok(|(record, _)| record),
+        )
+    }
+
+    /// Returns a stream over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. Each record is paired with the
+    /// indices of the regions it intersects.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_tabix as tabix;
+    /// use tokio::fs::File;
+    ///
+    /// let index = tabix::r#async::read("sample.bed.gz.tbi").await?;
+    /// let mut reader = File::open("sample.bed.gz")
+    ///     .await
+    ///     .map(|f| tabix::r#async::IndexedReader::new(f, index))?;
+    ///
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&regions)?;
+    ///
+    /// while let Some((record, region_indices)) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_regions<'r>(
+        &'r mut self,
+        regions: &'r [Region],
+    ) -> io::Result<impl Stream<Item = io::Result<(Record, Vec<usize>)>> + 'r> {
+        let header = self
+            .index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        let intervals = regions
+            .iter()
+            .map(|region| resolve_region(header, region).map(|id| (id, region.interval())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let chunks = query_intervals(&self.index, &intervals)?;
+
+        Ok(query(&mut self.inner, chunks, header, regions))
+    }
+}
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+struct Context<'r, R>
+where
+    R: AsyncRead,
+{
+    reader: &'r mut bgzf::AsyncReader<R>,
+    chunks: vec::IntoIter<Chunk>,
+    header: &'r Header,
+    regions: &'r [Region],
+    state: State,
+}
+
+fn query<'r, R>(
+    reader: &'r mut bgzf::AsyncReader<R>,
+    chunks: Vec<Chunk>,
+    header: &'r Header,
+    regions: &'r [Region],
+) -> impl Stream<Item = io::Result<(Record, Vec<usize>)>> + 'r
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let ctx = Context {
+        reader,
+        chunks: chunks.into_iter(),
+        header,
+        regions,
+        state: State::Seek,
+    };
+
+    Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            match ctx.state {
+                State::Seek => {
+                    ctx.state = match ctx.chunks.next() {
+                        Some(chunk) => {
+                            ctx.reader.seek(chunk.start()).await?;
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    };
+                }
+                State::Read(chunk_end) => match next_record(ctx.reader, ctx.header).await? {
+                    Some(record) => {
+                        if ctx.reader.virtual_position() >= chunk_end {
+                            ctx.state = State::Seek;
+                        }
+
+                        if let Some(record) = record {
+                            let region_indices: Vec<_> = ctx
+                                .regions
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, region)| intersects(&record, region))
+                                .map(|(i, _)| i)
+                                .collect();
+
+                            if !region_indices.is_empty() {
+                                return Ok(Some(((record, region_indices), ctx)));
+                            }
+                        }
+                    }
+                    None => ctx.state = State::Seek,
+                },
+                State::Done => return Ok(None),
+            }
+        }
+    }))
+}
+
+// Returns `Ok(Some(None))` for a comment line.
+async fn next_record<R>(
+    reader: &mut bgzf::AsyncReader<R>,
+    header: &Header,
+) -> io::Result<Option<Option<Record>>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = String::new();
+
+    if reader.read_line(&mut buf).await? == 0 {
+        return Ok(None);
+    }
+
+    if buf.ends_with('\n') {
+        buf.pop();
+
+        if buf.ends_with('\r') {
+            buf.pop();
+        }
+    }
+
+    if buf.starts_with(char::from(header.line_comment_prefix())) {
+        return Ok(Some(None));
+    }
+
+    Record::parse(buf, header).map(|record| Some(Some(record)))
+}
+
+fn intersects(record: &Record, region: &Region) -> bool {
+    record.indexed_reference_sequence_name().as_bytes() == region.name()
+        && record.indexed_interval().intersects(region.interval())
+}
+
+fn resolve_region(header: &Header, region: &Region) -> io::Result<usize> {
+    let region_name = str::from_utf8(region.name())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    header
+        .reference_sequence_names()
+        .get_index_of(region_name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use futures::TryStreamExt;
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let lines = [
+            ("sq0", 5, 13, "sq0\t4\t13\n"),
+            ("sq0", 21, 34, "sq0\t20\t34\n"),
+            ("sq1", 8, 13, "sq1\t7\t13\n"),
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"#chrom\tstart\tend\n")?;
+
+        let mut indexer = crate::index::Indexer::default();
+        indexer.set_header(noodles_csi::binning_index::index::header::Builder::bed().build());
+
+        for (name, start, end, line) in lines {
+            let start_position = writer.virtual_position();
+            writer.write_all(line.as_bytes())?;
+            let end_position = writer.virtual_position();
+
+            indexer.add_record(
+                name,
+                Position::try_from(start)?,
+                Position::try_from(end)?,
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build();
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index);
+
+        let region = "sq0:14-21".parse()?;
+        let records: Vec<_> = reader.query(&region)?.try_collect().await?;
+        let actual: Vec<_> = records.iter().map(|record| record.as_ref()).collect();
+        assert_eq!(actual, ["sq0\t20\t34"]);
+
+        let region = "sq1".parse()?;
+        let records: Vec<_> = reader.query(&region)?.try_collect().await?;
+        let actual: Vec<_> = records.iter().map(|record| record.as_ref()).collect();
+        assert_eq!(actual, ["sq1\t7\t13"]);
+
+        let region = "sq2".parse()?;
+        assert!(matches!(
+            reader.query(&region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let regions = ["sq0:14-21".parse()?, "sq0:21-34".parse()?, "sq1".parse()?];
+        let records: Vec<_> = reader.query_regions(&regions)?.try_collect().await?;
+        let actual: Vec<_> = records
+            .iter()
+            .map(|(record, region_indices)| (record.as_ref(), region_indices.as_slice()))
+            .collect();
+        assert_eq!(
+            actual,
+            [("sq0\t20\t34", &[0, 1][..]), ("sq1\t7\t13", &[2][..])]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    binning_index::index::{header::Format, reference_sequence::bin::Chunk, Header},
+    io::{indexed_records::Record, IndexedRecord},
+};
+
+use super::Index;
+
+const SAM_UNMAPPED_REFERENCE_SEQUENCE_NAME: &str = "*";
+
+/// Indexes a bgzipped-compressed tab-delimited file.
+///
+/// The given header describes the fields and coordinate system of the records (see
+/// [`noodles_csi::binning_index::index::header::Builder`] for presets), i.e., this is equivalent
+/// to `tabix --preset` or `tabix --sequence --begin --end`. The first `line_skip_count` lines and
+/// lines that start with the line comment prefix are not indexed.
+///
+/// For VCF, the end position is calculated from the length of the reference bases or the `END`
+/// info field. For SAM, it is calculated from the CIGAR operations, and unmapped records without a
+/// reference sequence name are not indexed.
+///
+/// The reference sequence names of the resulting index header are the names of the records in
+/// order of first appearance.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_csi::binning_index::index::header;
+/// use noodles_tabix as tabix;
+///
+/// let header = header::Builder::bed().build();
+/// let index = tabix::index("sample.bed.gz", header)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index<P>(src: P, header: Header) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(bgzf::Reader::new)?;
+    index_inner(&mut reader, header)
+}
+
+fn index_inner<R>(reader: &mut bgzf::Reader<R>, header: Header) -> io::Result<Index>
+where
+    R: Read,
+{
+    let line_comment_prefix = char::from(header.line_comment_prefix());
+    let line_skip_count = header.line_skip_count();
+
+    let mut indexer = super::index::Indexer::default();
+
+    let mut line = String::new();
+    let mut line_count = 0;
+    let mut start_position = reader.virtual_position();
+
+    loop {
+        line.clear();
+
+        if read_line(reader, &mut line)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+
+        line_count += 1;
+
+        if line_count <= line_skip_count || line.is_empty() || line.starts_with(line_comment_prefix)
+        {
+            start_position = end_position;
+            continue;
+        }
+
+        let record = Record::parse(line.clone(), &header)?;
+        let reference_sequence_name = record.indexed_reference_sequence_name();
+
+        if header.format() == Format::Sam
+            && reference_sequence_name == SAM_UNMAPPED_REFERENCE_SEQUENCE_NAME
+        {
+            start_position = end_position;
+            continue;
+        }
+
+        let start = record.indexed_start_position();
+        let end = match header.format() {
+            Format::Sam => calculate_sam_end_position(record.as_ref(), start)?,
+            Format::Vcf => calculate_vcf_end_position(record.as_ref(), start)?,
+            Format::Generic(_) => record.indexed_end_position(),
+        };
+
+        let chunk = Chunk::new(start_position, end_position);
+        indexer.add_record(reference_sequence_name, start, end, chunk)?;
+
+        start_position = end_position;
+    }
+
+    indexer.set_header(header);
+
+    Ok(indexer.build())
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    const LINE_FEED: char = '\n';
+    const CARRIAGE_RETURN: char = '\r';
+
+    match reader.read_line(buf) {
+        Ok(0) => Ok(0),
+        Ok(n) => {
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+
+                if buf.ends_with(CARRIAGE_RETURN) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn calculate_sam_end_position(s: &str, start: Position) -> io::Result<Position> {
+    const CIGAR_INDEX: usize = 5;
+    const MISSING: &str = "*";
+
+    let cigar = s
+        .split('\t')
+        .nth(CIGAR_INDEX)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing CIGAR"))?;
+
+    if cigar == MISSING {
+        return calculate_end_position(start, 1);
+    }
+
+    let mut reference_len = 0;
+    let mut len = 0;
+
+    for c in cigar.chars() {
+        if let Some(d) = c.to_digit(10) {
+            len = len * 10 + d as usize;
+            continue;
+        }
+
+        match c {
+            'M' | 'D' | 'N' | '=' | 'X' => reference_len += len,
+            'I' | 'S' | 'H' | 'P' => {}
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid CIGAR operation kind",
+                ))
+            }
+        }
+
+        len = 0;
+    }
+
+    calculate_end_position(start, reference_len.max(1))
+}
+
+fn calculate_vcf_end_position(s: &str, start: Position) -> io::Result<Position> {
+    const REFERENCE_BASES_INDEX: usize = 3;
+    const INFO_INDEX: usize = 7;
+    const END_KEY: &str = "END";
+
+    let fields: Vec<_> = s.split('\t').collect();
+
+    if let Some(info) = fields.get(INFO_INDEX) {
+        let end = info.split(';').find_map(|field| {
+            field
+                .split_once('=')
+                .filter(|(key, _)| *key == END_KEY)
+                .map(|(_, value)| value)
+        });
+
+        if let Some(raw_end) = end {
+            return raw_end
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+        }
+    }
+
+    let reference_bases = fields
+        .get(REFERENCE_BASES_INDEX)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing reference bases"))?;
+
+    calculate_end_position(start, reference_bases.len().max(1))
+}
+
+fn calculate_end_position(start: Position, len: usize) -> io::Result<Position> {
+    start
+        .checked_add(len - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid end position"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_csi::binning_index::{index::header, BinningIndex, ReferenceSequence as _};
+
+    use super::*;
+
+    fn build_reader(data: &[u8]) -> io::Result<bgzf::Reader<io::Cursor<Vec<u8>>>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish().map(io::Cursor::new).map(bgzf::Reader::new)
+    }
+
+    #[test]
+    fn test_index_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"#noodles
+sq0\t7\t13
+sq0\t20\t34
+sq1\t4\t8
+";
+
+        let mut reader = build_reader(data)?;
+        let index = index_inner(&mut reader, header::Builder::bed().build())?;
+
+        let header = index.header().ok_or("missing header")?;
+        let names: Vec<_> = header.reference_sequence_names().iter().collect();
+        assert_eq!(names, ["sq0", "sq1"]);
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(8)?;
+        let chunks = index.query(0, (start..=end).into())?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), bgzf::VirtualPosition::from(9));
+
+        let start = Position::try_from(5)?;
+        let end = Position::try_from(5)?;
+        let chunks = index.query(1, (start..=end).into())?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), bgzf::VirtualPosition::from(28));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_inner_with_line_skip_count() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"chrom\tstart\tend
+sq0\t7\t13
+";
+
+        let mut reader = build_reader(data)?;
+
+        let header = header::Builder::bed().set_line_skip_count(1).build();
+        let index = index_inner(&mut reader, header)?;
+
+        let header = index.header().ok_or("missing header")?;
+        assert_eq!(header.reference_sequence_names().len(), 1);
+
+        let mut reader = build_reader(data)?;
+        assert!(matches!(
+            index_inner(&mut reader, header::Builder::bed().build()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_inner_with_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.4
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tACGT\tA\t.\tPASS\t.
+sq0\t100000\t.\tN\t<DEL>\t.\tPASS\tEND=100100
+";
+
+        let mut reader = build_reader(data)?;
+        let index = index_inner(&mut reader, header::Builder::vcf().build())?;
+
+        // The deletion at 1-4 overlaps position 3.
+        let start = Position::try_from(3)?;
+        let chunks = index.query(0, (start..=start).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        let reference_sequence = &index.reference_sequences()[0];
+        let metadata = reference_sequence.metadata().ok_or("missing metadata")?;
+        assert_eq!(metadata.mapped_record_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_sam_end_position() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(8)?;
+
+        assert_eq!(
+            calculate_sam_end_position("r0\t0\tsq0\t8\t255\t4M2I3D1S\t*\t0\t0\tACGTACG\t*", start)?,
+            Position::try_from(14)?
+        );
+
+        assert_eq!(
+            calculate_sam_end_position("r0\t4\tsq0\t8\t255\t*\t*\t0\t0\tA\t*", start)?,
+            start
+        );
+
+        assert!(matches!(
+            calculate_sam_end_position("r0\t0\tsq0\t8\t255\t4Z\t*\t0\t0\tA\t*", start),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_vcf_end_position() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(8)?;
+
+        assert_eq!(
+            calculate_vcf_end_position("sq0\t8\t.\tACGT\tA\t.\tPASS\t.", start)?,
+            Position::try_from(11)?
+        );
+
+        assert_eq!(
+            calculate_vcf_end_position("sq0\t8\t.\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;END=21", start)?,
+            Position::try_from(21)?
+        );
+
+        Ok(())
+    }
+}
@@ -29,11 +29,12 @@
 pub mod r#async;
 
 pub mod index;
+mod indexer;
 pub mod io;
 mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{indexer::index, reader::Reader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,50 @@
+//! Calculates and sets the mismatched positions (`MD`) and edit distance (`NM`) of records in a
+//! SAM file.
+//!
+//! The result is similar to the output of `samtools calmd <src> <fasta-src>`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
+use noodles_sam::{self as sam, alignment::io::Write};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next().expect("missing fasta_src");
+
+    let repository = fasta::indexed_reader::Builder::default()
+        .build_from_path(fasta_src)
+        .map(IndexedReader::new)
+        .map(fasta::Repository::new)?;
+
+    let mut reader = sam::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = sam::io::Writer::new(BufWriter::new(stdout));
+
+    writer.write_alignment_header(&header)?;
+
+    for result in reader.record_bufs(&header) {
+        let mut record = result?;
+
+        if let Some((name, _)) = record.reference_sequence(&header).transpose()? {
+            let sequence = repository.get(name).transpose()?.ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "missing reference sequence")
+            })?;
+
+            sam::alignment::calmd::update(&mut record, sequence.as_ref())?;
+        }
+
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    Ok(())
+}
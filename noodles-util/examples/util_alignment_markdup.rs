@@ -0,0 +1,89 @@
+//! Marks duplicates in a coordinate-sorted BAM file and prints the duplication metrics.
+//!
+//! Paired reads must have mate CIGARs (`MC`), e.g., as set by `samtools fixmate -m`.
+//!
+//! If `--optical` is given, optical duplicates within 100 pixels are tagged with `DT:Z:SQ`.
+//!
+//! The result is similar to the output of `picard MarkDuplicates I=<src> O=<dst> M=/dev/stdout`.
+
+use std::{
+    env,
+    io::{self, BufWriter, Write},
+};
+
+use noodles_bam as bam;
+use noodles_sam::alignment::io::Write as _;
+use noodles_util::alignment::markdup;
+
+const OPTICAL_DUPLICATE_PIXEL_DISTANCE: u32 = 100;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let dst = args.next().expect("missing dst");
+    let optical = args.next().as_deref() == Some("--optical");
+
+    let mut reader = bam::io::reader::Builder.build_from_path(&src)?;
+    let header = reader.read_header()?;
+
+    let mut builder = markdup::Builder::default();
+
+    if optical {
+        builder = builder.set_optical_duplicate_pixel_distance(OPTICAL_DUPLICATE_PIXEL_DISTANCE);
+    }
+
+    let mut marker = builder.build(&header);
+
+    let mut writer = bam::io::writer::Builder.build_from_path(dst)?;
+    writer.write_header(&header)?;
+
+    for result in reader.record_bufs(&header) {
+        let record = result?;
+        marker.add_record(&header, record)?;
+
+        while let Some(record) = marker.next_record() {
+            writer.write_alignment_record(&header, &record)?;
+        }
+    }
+
+    marker.finish();
+
+    while let Some(record) = marker.next_record() {
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    let stdout = io::stdout().lock();
+    let mut metrics_writer = BufWriter::new(stdout);
+
+    writeln!(
+        metrics_writer,
+        "LIBRARY\tUNPAIRED_READS_EXAMINED\tREAD_PAIRS_EXAMINED\tSECONDARY_OR_SUPPLEMENTARY_RDS\tUNMAPPED_READS\tUNPAIRED_READ_DUPLICATES\tREAD_PAIR_DUPLICATES\tREAD_PAIR_OPTICAL_DUPLICATES\tPERCENT_DUPLICATION\tESTIMATED_LIBRARY_SIZE"
+    )?;
+
+    for metrics in marker.metrics() {
+        let estimated_library_size = metrics
+            .estimated_library_size()
+            .map(|n| n.to_string())
+            .unwrap_or_default();
+
+        writeln!(
+            metrics_writer,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{:.6}\t{}",
+            metrics.library(),
+            metrics.unpaired_reads_examined(),
+            metrics.read_pairs_examined(),
+            metrics.secondary_or_supplementary_reads(),
+            metrics.unmapped_reads(),
+            metrics.unpaired_read_duplicates(),
+            metrics.read_pair_duplicates(),
+            metrics.read_pair_optical_duplicates(),
+            metrics.percent_duplication(),
+            estimated_library_size,
+        )?;
+    }
+
+    Ok(())
+}
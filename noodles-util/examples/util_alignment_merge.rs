@@ -0,0 +1,26 @@
+//! Merges coordinate-sorted alignment files.
+//!
+//! The inputs can be in any alignment format. The output format is determined from the extension
+//! of the destination.
+//!
+//! The result is similar to the output of `samtools merge --no-PG -o <dst> <srcs...>`.
+
+use std::{env, io};
+
+use noodles_util::alignment::{self, merge, sort::SortOrder};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let dst = args.next().expect("missing dst");
+
+    let mut readers = args
+        .map(|src| alignment::io::reader::Builder::default().build_from_path(src))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut writer = alignment::io::writer::Builder::default().build_from_path(dst)?;
+
+    merge::merge(&mut readers, &mut writer, SortOrder::Coordinate)?;
+
+    Ok(())
+}
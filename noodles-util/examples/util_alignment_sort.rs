@@ -0,0 +1,42 @@
+//! Sorts an alignment file.
+//!
+//! Records are sorted by coordinate or, if `--queryname` is given, by name. The output format is
+//! determined from the extension of the destination.
+//!
+//! The result is similar to the output of `samtools sort [-n] -o <dst> <src>`.
+
+use std::{env, io};
+
+use noodles_util::alignment::{
+    self,
+    sort::{self, SortOrder},
+};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let dst = args.next().expect("missing dst");
+
+    let sort_order = match args.next().as_deref() {
+        Some("--queryname") => SortOrder::QueryName,
+        _ => SortOrder::Coordinate,
+    };
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let mut sorter = sort::Builder::default()
+        .set_sort_order(sort_order)
+        .build(header.clone());
+
+    for result in reader.records(&header) {
+        let record = result?;
+        sorter.add_record(&record)?;
+    }
+
+    let mut writer = alignment::io::writer::Builder::default().build_from_path(dst)?;
+    sorter.finish(&mut writer)?;
+
+    Ok(())
+}
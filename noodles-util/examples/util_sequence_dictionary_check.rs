@@ -0,0 +1,57 @@
+//! Checks that the reference sequences of an alignment file, a variant file, and a FASTA index
+//! are compatible.
+//!
+//! Each pair of inputs is compared by reference sequence names, lengths, and MD5 checksums. Any
+//! differences are printed, and the process exits with a nonzero status if there are any.
+
+use std::{env, io, process};
+
+use noodles_core::SequenceDictionary;
+use noodles_fasta::fai;
+use noodles_sam as sam;
+use noodles_util::{alignment, variant};
+use noodles_vcf as vcf;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let alignment_src = args.next().expect("missing alignment src");
+    let variant_src = args.next().expect("missing variant src");
+    let fai_src = args.next().expect("missing fai src");
+
+    let alignment_header = alignment::io::reader::Builder::default()
+        .build_from_path(alignment_src)?
+        .read_header()?;
+    let alignments = sam::header::sequence_dictionary::from_reference_sequences(
+        alignment_header.reference_sequences(),
+    )?;
+
+    let variant_header = variant::reader::Builder::default()
+        .build_from_path(variant_src)?
+        .read_header()?;
+    let variants = vcf::header::sequence_dictionary::from_contigs(variant_header.contigs())?;
+
+    let index = fai::read(fai_src)?;
+    let reference = fai::sequence_dictionary::from_index(&index);
+
+    let pairs: [(&str, &SequenceDictionary, &str, &SequenceDictionary); 3] = [
+        ("reference", &reference, "alignment", &alignments),
+        ("reference", &reference, "variant", &variants),
+        ("alignment", &alignments, "variant", &variants),
+    ];
+
+    let mut is_compatible = true;
+
+    for (first_name, first, second_name, second) in pairs {
+        for difference in first.compare(second) {
+            println!("{first_name} vs. {second_name}: {difference}");
+            is_compatible = false;
+        }
+    }
+
+    if !is_compatible {
+        process::exit(1);
+    }
+
+    Ok(())
+}
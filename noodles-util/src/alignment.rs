@@ -2,3 +2,6 @@
 
 pub mod io;
 pub mod iter;
+pub mod markdup;
+pub mod merge;
+pub mod sort;
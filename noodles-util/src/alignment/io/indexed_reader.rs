@@ -1,8 +1,9 @@
 //! Indexed alignment reader.
 
 mod builder;
+mod query_regions;
 
-pub use self::builder::Builder;
+pub use self::{builder::Builder, query_regions::QueryRegions};
 
 use std::io::{self, Read, Seek};
 
@@ -108,4 +109,22 @@ where
 
         Ok(records)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Each record is returned once, even when regions overlap. The regions that a record
+    /// intersects are available from [`QueryRegions::region_indices`].
+    pub fn query_regions<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, R>> {
+        match self {
+            Self::Sam(reader) => reader.query_regions(header, regions).map(QueryRegions::sam),
+            Self::Bam(reader) => reader.query_regions(header, regions).map(QueryRegions::bam),
+            Self::Cram(reader) => reader
+                .query_regions(header, regions)
+                .map(|query| QueryRegions::cram(query, header)),
+        }
+    }
 }
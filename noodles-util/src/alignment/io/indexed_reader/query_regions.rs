@@ -0,0 +1,91 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_cram as cram;
+use noodles_sam::{self as sam, alignment::Record};
+
+/// An iterator over records of an indexed alignment reader that intersect any of the given
+/// regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`super::IndexedReader::query_regions`].
+pub struct QueryRegions<'r, R>
+where
+    R: Read + Seek,
+{
+    inner: Inner<'r, R>,
+}
+
+enum Inner<'r, R>
+where
+    R: Read + Seek,
+{
+    Sam(sam::io::reader::QueryRegions<'r, R>),
+    Bam(bam::io::reader::QueryRegions<'r, bgzf::Reader<R>>),
+    Cram(cram::io::reader::QueryRegions<'r, R>, &'r sam::Header),
+}
+
+impl<'r, R> QueryRegions<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn sam(query: sam::io::reader::QueryRegions<'r, R>) -> Self {
+        Self {
+            inner: Inner::Sam(query),
+        }
+    }
+
+    pub(super) fn bam(query: bam::io::reader::QueryRegions<'r, bgzf::Reader<R>>) -> Self {
+        Self {
+            inner: Inner::Bam(query),
+        }
+    }
+
+    pub(super) fn cram(
+        query: cram::io::reader::QueryRegions<'r, R>,
+        header: &'r sam::Header,
+    ) -> Self {
+        Self {
+            inner: Inner::Cram(query, header),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        match &self.inner {
+            Inner::Sam(query) => query.region_indices(),
+            Inner::Bam(query) => query.region_indices(),
+            Inner::Cram(query, _) => query.region_indices(),
+        }
+    }
+}
+
+impl<'r, R> Iterator for QueryRegions<'r, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Box<dyn Record>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Sam(query) => query
+                .next()
+                .map(|result| result.map(|record| Box::new(record) as Box<dyn Record>)),
+            Inner::Bam(query) => query
+                .next()
+                .map(|result| result.map(|record| Box::new(record) as Box<dyn Record>)),
+            Inner::Cram(query, header) => query.next().map(|result| {
+                result.and_then(|record| {
+                    record
+                        .try_into_alignment_record(header)
+                        .map(|alignment_record| Box::new(alignment_record) as Box<dyn Record>)
+                })
+            }),
+        }
+    }
+}
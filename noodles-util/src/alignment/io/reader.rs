@@ -76,3 +76,19 @@ where
         self.inner.alignment_records(header)
     }
 }
+
+impl<R> sam::alignment::io::Read<R> for Reader<R>
+where
+    R: Read,
+{
+    fn read_alignment_header(&mut self) -> io::Result<sam::Header> {
+        self.inner.read_alignment_header()
+    }
+
+    fn alignment_records<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+    ) -> Box<dyn Iterator<Item = io::Result<Box<dyn sam::alignment::Record>>> + 'a> {
+        self.inner.alignment_records(header)
+    }
+}
@@ -83,3 +83,21 @@ impl Writer {
         self.inner.finish(header)
     }
 }
+
+impl sam::alignment::io::Write for Writer {
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.write_alignment_header(header)
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn Record,
+    ) -> io::Result<()> {
+        self.inner.write_alignment_record(header, record)
+    }
+
+    fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.finish(header)
+    }
+}
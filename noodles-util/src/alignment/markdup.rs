@@ -0,0 +1,1227 @@
+//! Alignment record duplicate marking.
+
+mod builder;
+mod metrics;
+mod physical_location;
+
+pub use self::{builder::Builder, metrics::DuplicationMetrics};
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    io, iter,
+};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            data::field::{Tag, Value},
+            Flags,
+        },
+        record_buf, Record, RecordBuf,
+    },
+};
+
+use self::physical_location::PhysicalLocation;
+
+const MIN_QUALITY_SCORE: u8 = 15;
+
+const MATE_SCORE: Tag = Tag::new(b'm', b's');
+const DUPLICATE_TYPE: Tag = Tag::new(b'D', b'T');
+const SEQUENCING_DUPLICATE: &str = "SQ";
+
+// A reference sequence ID and position.
+type Coordinate = (usize, i64);
+
+/// An alignment record duplicate marker.
+///
+/// This marks duplicates similar to Picard `MarkDuplicates` and `samtools markdup`. Mapped primary
+/// reads are grouped by library (`@RG LB`) and their unclipped 5' positions and orientations. For
+/// pairs, this is the position and orientation of both mates. Within each group, the read or pair
+/// with the highest sum of base quality scores (>= 15) is kept, and the rest are duplicates.
+/// Unpaired reads that share a position with a pair are always duplicates.
+///
+/// Marking is done in a single pass over coordinate-sorted records. As in `samtools markdup`, a
+/// pair is grouped when its first read is added, using the position and CIGAR (`MC`) of its mate.
+/// Reads with a mapped mate must therefore have a mate CIGAR, e.g., as set by
+/// [`sam::alignment::fixmate`]. The score of a pair is the score of its first read plus the mate
+/// score (`ms`), if present. Mates of duplicate pairs that are added later are marked by name.
+///
+/// Records are added to the marker and returned, marked and in the same order, once no later
+/// record can change whether they are duplicates. A group is resolved when the added records are
+/// past it by more than the longest 5' clip seen so far. Only the records in between and the
+/// names of duplicate pairs whose mates were not yet added are kept.
+///
+/// A read that is clipped by more than all reads before it is not compared with groups that were
+/// already resolved. Mates must be in the same read group. Secondary, supplementary, and unmapped
+/// records are never marked as duplicates.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{self as sam, alignment::RecordBuf};
+/// use noodles_util::alignment::markdup;
+///
+/// let header = sam::Header::default();
+/// let records = [RecordBuf::default(), RecordBuf::default()];
+///
+/// let mut marker = markdup::Builder::default().build(&header);
+/// let mut marked_records = Vec::new();
+///
+/// for record in records {
+///     marker.add_record(&header, record)?;
+///
+///     while let Some(record) = marker.next_record() {
+///         marked_records.push(record);
+///     }
+/// }
+///
+/// marker.finish();
+///
+/// while let Some(record) = marker.next_record() {
+///     marked_records.push(record);
+/// }
+///
+/// assert_eq!(marked_records.len(), 2);
+/// assert!(marked_records.iter().all(|record| !record.flags().is_duplicate()));
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Marker {
+    optical_duplicate_pixel_distance: Option<u32>,
+    read_group_libraries: HashMap<Vec<u8>, usize>,
+    unknown_library_index: usize,
+    metrics: Vec<DuplicationMetrics>,
+    last_coordinate: Option<Coordinate>,
+    has_unplaced_records: bool,
+    max_clip_len: usize,
+    record_offset: usize,
+    records: VecDeque<Entry>,
+    fragment_groups: BTreeMap<(End, usize), FragmentGroup>,
+    pair_groups: BTreeMap<(End, End, usize), Vec<Pair>>,
+    pending_pairs: HashMap<Vec<u8>, PendingPair>,
+    duplicate_pairs: HashMap<Vec<u8>, Status>,
+}
+
+impl Marker {
+    /// Adds an alignment record.
+    ///
+    /// Records must be added in coordinate order. Groups of earlier records that can no longer
+    /// change are resolved, and their records can be taken using [`Self::next_record`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut marker = markdup::Builder::default().build(&header);
+    ///
+    /// let record = sam::alignment::RecordBuf::default();
+    /// marker.add_record(&header, record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn add_record(&mut self, header: &sam::Header, record: RecordBuf) -> io::Result<()> {
+        let coordinate = record
+            .reference_sequence_id()
+            .zip(record.alignment_start())
+            .map(|(id, start)| (id, usize::from(start) as i64));
+
+        self.check_order(coordinate)?;
+
+        let record_index = self.record_offset + self.records.len();
+        let status = self.add_read(header, &record, record_index)?;
+        self.records.push_back(Entry { record, status });
+
+        // The window is updated with the clip of the added read before resolving, so its group is
+        // never resolved before later reads in the group are added.
+        if let Some((id, position)) = coordinate {
+            self.resolve_groups_before(Some((id, position - self.max_clip_len as i64)));
+        } else {
+            // Unplaced records are last, so no later record can be a duplicate.
+            self.resolve_groups_before(None);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the next marked record, if it is resolved.
+    ///
+    /// Records are returned in the order they are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut marker = markdup::Builder::default().build(&header);
+    /// assert!(marker.next_record().is_none());
+    ///
+    /// marker.add_record(&header, sam::alignment::RecordBuf::default())?;
+    /// assert!(marker.next_record().is_some());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn next_record(&mut self) -> Option<RecordBuf> {
+        let status = self.records.front()?.status?;
+        let mut record = self.records.pop_front().map(|entry| entry.record)?;
+        self.record_offset += 1;
+
+        mark_record(
+            &mut record,
+            status,
+            self.optical_duplicate_pixel_distance.is_some(),
+        );
+
+        Some(record)
+    }
+
+    /// Resolves all remaining records.
+    ///
+    /// The remaining records can then be taken using [`Self::next_record`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut marker = markdup::Builder::default().build(&header);
+    /// marker.finish();
+    /// ```
+    pub fn finish(&mut self) {
+        self.resolve_groups_before(None);
+
+        // Mates that were not added can no longer be marked.
+        self.duplicate_pairs.clear();
+    }
+
+    /// Returns the duplication metrics of each library.
+    ///
+    /// Libraries without records are not included. The metrics are complete after the marker is
+    /// finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup;
+    ///
+    /// let header = sam::Header::default();
+    /// let marker = markdup::Builder::default().build(&header);
+    /// assert!(marker.metrics().next().is_none());
+    /// ```
+    pub fn metrics(&self) -> impl Iterator<Item = &DuplicationMetrics> {
+        self.metrics.iter().filter(|m| {
+            m.unpaired_reads_examined
+                + m.read_pairs_examined
+                + m.secondary_or_supplementary_reads
+                + m.unmapped_reads
+                > 0
+        })
+    }
+
+    fn check_order(&mut self, coordinate: Option<Coordinate>) -> io::Result<()> {
+        match coordinate {
+            Some(coordinate) => {
+                if self.has_unplaced_records
+                    || self
+                        .last_coordinate
+                        .map(|c| coordinate < c)
+                        .unwrap_or(false)
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "records are not coordinate-sorted",
+                    ));
+                }
+
+                self.last_coordinate = Some(coordinate);
+            }
+            None => self.has_unplaced_records = true,
+        }
+
+        Ok(())
+    }
+
+    fn add_read(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn Record,
+        record_index: usize,
+    ) -> io::Result<Option<Status>> {
+        let flags = record.flags()?;
+
+        let data = record.data();
+
+        let read_group = match data.get(&Tag::READ_GROUP).transpose()? {
+            Some(Value::String(id)) => Some(id.as_ref()),
+            Some(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid read group type",
+                ))
+            }
+            None => None,
+        };
+
+        let library_index = read_group
+            .and_then(|id| self.read_group_libraries.get(id))
+            .copied()
+            .unwrap_or(self.unknown_library_index);
+
+        if flags.is_secondary() || flags.is_supplementary() {
+            self.metrics[library_index].secondary_or_supplementary_reads += 1;
+            return Ok(Some(Status::Unique));
+        } else if flags.is_unmapped() {
+            self.metrics[library_index].unmapped_reads += 1;
+            return Ok(Some(Status::Unique));
+        }
+
+        let (reference_sequence_id, alignment_start) = record
+            .reference_sequence_id(header)
+            .transpose()?
+            .zip(record.alignment_start().transpose()?)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+
+        let ops: Vec<_> = record.cigar().iter().collect::<io::Result<_>>()?;
+        let is_reverse_complemented = flags.is_reverse_complemented();
+
+        let end = End::new(
+            reference_sequence_id,
+            usize::from(alignment_start),
+            &ops,
+            is_reverse_complemented,
+        );
+
+        self.max_clip_len = self
+            .max_clip_len
+            .max(five_prime_clip_len(&ops, is_reverse_complemented));
+
+        let score = sum_quality_scores(record);
+        let has_mapped_mate = flags.is_segmented() && !flags.is_mate_unmapped();
+
+        let group = self
+            .fragment_groups
+            .entry((end, library_index))
+            .or_default();
+
+        if !has_mapped_mate {
+            group.fragments.push(Fragment {
+                score,
+                record_index,
+            });
+
+            self.metrics[library_index].unpaired_reads_examined += 1;
+
+            return Ok(None);
+        }
+
+        group.has_paired_reads = true;
+
+        let name = record
+            .name()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing name"))?;
+
+        let name = name.as_bytes();
+
+        // The mate of an unresolved pair is resolved with it.
+        if let Some(pair) = self.pending_pairs.get_mut(name) {
+            if pair.read_group.as_deref() != read_group {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "mates have different read groups",
+                ));
+            }
+
+            pair.mate_record_index = Some(record_index);
+
+            return Ok(None);
+        }
+
+        if let Some(status) = self.duplicate_pairs.remove(name) {
+            return Ok(Some(status));
+        }
+
+        let (mate_reference_sequence_id, mate_alignment_start) = record
+            .mate_reference_sequence_id(header)
+            .transpose()?
+            .zip(record.mate_alignment_start().transpose()?)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing mate position"))?;
+
+        let coordinate = (reference_sequence_id, usize::from(alignment_start));
+        let mate_coordinate = (
+            mate_reference_sequence_id,
+            usize::from(mate_alignment_start),
+        );
+
+        // The first read of this pair was already resolved, and its pair is not a duplicate.
+        if mate_coordinate < coordinate {
+            return Ok(Some(Status::Unique));
+        }
+
+        let mate_ops = match data.get(&Tag::MATE_CIGAR).transpose()? {
+            Some(Value::String(s)) => parse_cigar(s)?,
+            Some(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid mate CIGAR type",
+                ))
+            }
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "missing mate CIGAR (MC)",
+                ))
+            }
+        };
+
+        let is_mate_reverse_complemented = flags.is_mate_reverse_complemented();
+
+        let mate_end = End::new(
+            mate_reference_sequence_id,
+            usize::from(mate_alignment_start),
+            &mate_ops,
+            is_mate_reverse_complemented,
+        );
+
+        self.max_clip_len = self
+            .max_clip_len
+            .max(five_prime_clip_len(&mate_ops, is_mate_reverse_complemented));
+
+        let mate_score = match data.get(&MATE_SCORE).transpose()? {
+            Some(value) => value
+                .as_int()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid mate score"))?,
+            None => 0,
+        };
+
+        let ends = if end <= mate_end {
+            (end, mate_end)
+        } else {
+            (mate_end, end)
+        };
+
+        let physical_location = self
+            .optical_duplicate_pixel_distance
+            .and_then(|_| PhysicalLocation::parse(name, read_group));
+
+        self.pair_groups
+            .entry((ends.0, ends.1, library_index))
+            .or_default()
+            .push(Pair {
+                name: name.to_vec(),
+                score: score.saturating_add(mate_score),
+                record_index,
+                physical_location,
+            });
+
+        self.pending_pairs.insert(
+            name.to_vec(),
+            PendingPair {
+                read_group: read_group.map(|id| id.to_vec()),
+                mate_record_index: None,
+            },
+        );
+
+        self.metrics[library_index].read_pairs_examined += 1;
+
+        Ok(None)
+    }
+
+    // Resolves the groups whose (leftmost) end is before the given coordinate. If no coordinate
+    // is given, all groups are resolved.
+    fn resolve_groups_before(&mut self, coordinate: Option<Coordinate>) {
+        let is_resolvable = |end: &End| coordinate.map(|c| end.coordinate() < c).unwrap_or(true);
+
+        while let Some(entry) = self.pair_groups.first_entry() {
+            if !is_resolvable(&entry.key().0) {
+                break;
+            }
+
+            let ((_, _, library_index), pairs) = entry.remove_entry();
+            self.resolve_pair_group(library_index, pairs);
+        }
+
+        while let Some(entry) = self.fragment_groups.first_entry() {
+            if !is_resolvable(&entry.key().0) {
+                break;
+            }
+
+            let ((_, library_index), group) = entry.remove_entry();
+            self.resolve_fragment_group(library_index, group);
+        }
+    }
+
+    fn resolve_pair_group(&mut self, library_index: usize, mut pairs: Vec<Pair>) {
+        let mut statuses = vec![Status::Unique; pairs.len()];
+
+        if pairs.len() > 1 {
+            let metrics = &mut self.metrics[library_index];
+
+            pairs.sort_unstable_by_key(|pair| pair.record_index);
+            let best_index = find_best(pairs.iter().map(|pair| pair.score));
+
+            for (i, status) in statuses.iter_mut().enumerate() {
+                if i != best_index {
+                    *status = Status::Duplicate;
+                    metrics.read_pair_duplicates += 1;
+                }
+            }
+
+            if let Some(pixel_distance) = self.optical_duplicate_pixel_distance {
+                let ordered_indices: Vec<_> = iter::once(best_index)
+                    .chain((0..pairs.len()).filter(|&i| i != best_index))
+                    .collect();
+
+                for (j, &i) in ordered_indices.iter().enumerate().skip(1) {
+                    let Some(location) = &pairs[i].physical_location else {
+                        continue;
+                    };
+
+                    let is_optical_duplicate = ordered_indices[..j]
+                        .iter()
+                        .filter_map(|&k| pairs[k].physical_location.as_ref())
+                        .any(|l| l.is_near(location, pixel_distance));
+
+                    if is_optical_duplicate {
+                        statuses[i] = Status::OpticalDuplicate;
+                        metrics.read_pair_optical_duplicates += 1;
+                    }
+                }
+            }
+        }
+
+        for (pair, status) in pairs.into_iter().zip(statuses) {
+            self.set_status(pair.record_index, status);
+
+            let mate_record_index = self
+                .pending_pairs
+                .remove(&pair.name)
+                .and_then(|pending_pair| pending_pair.mate_record_index);
+
+            if let Some(mate_record_index) = mate_record_index {
+                self.set_status(mate_record_index, status);
+            } else if status != Status::Unique {
+                self.duplicate_pairs.insert(pair.name, status);
+            }
+        }
+    }
+
+    fn resolve_fragment_group(&mut self, library_index: usize, group: FragmentGroup) {
+        // Unpaired reads that share a position with a pair are always duplicates.
+        let best_index = if group.has_paired_reads {
+            None
+        } else {
+            Some(find_best(group.fragments.iter().map(|f| f.score)))
+        };
+
+        for (i, fragment) in group.fragments.iter().enumerate() {
+            if Some(i) == best_index {
+                self.set_status(fragment.record_index, Status::Unique);
+            } else {
+                self.metrics[library_index].unpaired_read_duplicates += 1;
+                self.set_status(fragment.record_index, Status::Duplicate);
+            }
+        }
+    }
+
+    fn set_status(&mut self, record_index: usize, status: Status) {
+        self.records[record_index - self.record_offset].status = Some(status);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Status {
+    Unique,
+    Duplicate,
+    OpticalDuplicate,
+}
+
+struct Entry {
+    record: RecordBuf,
+    status: Option<Status>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct End {
+    reference_sequence_id: usize,
+    position: i64,
+    is_reverse_complemented: bool,
+}
+
+impl End {
+    fn new(
+        reference_sequence_id: usize,
+        alignment_start: usize,
+        ops: &[Op],
+        is_reverse_complemented: bool,
+    ) -> Self {
+        let start = alignment_start as i64;
+        let clip_len = five_prime_clip_len(ops, is_reverse_complemented) as i64;
+
+        // The unclipped 5' position
+        let position = if is_reverse_complemented {
+            let span: usize = ops
+                .iter()
+                .filter(|op| op.kind().consumes_reference())
+                .map(|op| op.len())
+                .sum();
+
+            start + span as i64 + clip_len - 1
+        } else {
+            start - clip_len
+        };
+
+        Self {
+            reference_sequence_id,
+            position,
+            is_reverse_complemented,
+        }
+    }
+
+    fn coordinate(&self) -> Coordinate {
+        (self.reference_sequence_id, self.position)
+    }
+}
+
+struct Fragment {
+    score: u32,
+    record_index: usize,
+}
+
+#[derive(Default)]
+struct FragmentGroup {
+    has_paired_reads: bool,
+    fragments: Vec<Fragment>,
+}
+
+// A pair, as given by its first read.
+struct Pair {
+    name: Vec<u8>,
+    score: u32,
+    record_index: usize,
+    physical_location: Option<PhysicalLocation>,
+}
+
+// A pair whose group is not yet resolved.
+struct PendingPair {
+    read_group: Option<Vec<u8>>,
+    mate_record_index: Option<usize>,
+}
+
+fn five_prime_clip_len(ops: &[Op], is_reverse_complemented: bool) -> usize {
+    fn clip_len<'a, I>(ops: I) -> usize
+    where
+        I: Iterator<Item = &'a Op>,
+    {
+        ops.take_while(|op| matches!(op.kind(), Kind::SoftClip | Kind::HardClip))
+            .map(|op| op.len())
+            .sum()
+    }
+
+    if is_reverse_complemented {
+        clip_len(ops.iter().rev())
+    } else {
+        clip_len(ops.iter())
+    }
+}
+
+fn parse_cigar(src: &[u8]) -> io::Result<Vec<Op>> {
+    fn invalid_cigar() -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, "invalid mate CIGAR")
+    }
+
+    let mut ops = Vec::new();
+    let mut len: Option<usize> = None;
+
+    for &b in src {
+        if b.is_ascii_digit() {
+            let n = len
+                .unwrap_or_default()
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(usize::from(b - b'0')))
+                .ok_or_else(invalid_cigar)?;
+
+            len = Some(n);
+        } else {
+            let kind = match b {
+                b'M' => Kind::Match,
+                b'I' => Kind::Insertion,
+                b'D' => Kind::Deletion,
+                b'N' => Kind::Skip,
+                b'S' => Kind::SoftClip,
+                b'H' => Kind::HardClip,
+                b'P' => Kind::Pad,
+                b'=' => Kind::SequenceMatch,
+                b'X' => Kind::SequenceMismatch,
+                _ => return Err(invalid_cigar()),
+            };
+
+            let len = len.take().ok_or_else(invalid_cigar)?;
+            ops.push(Op::new(kind, len));
+        }
+    }
+
+    if len.is_some() {
+        return Err(invalid_cigar());
+    }
+
+    Ok(ops)
+}
+
+fn sum_quality_scores(record: &dyn Record) -> u32 {
+    record
+        .quality_scores()
+        .iter()
+        .filter(|&score| score >= MIN_QUALITY_SCORE)
+        .map(u32::from)
+        .sum()
+}
+
+// Sets or clears the duplicate flag (`0x400`). If optical duplicate detection is enabled, optical
+// duplicates are tagged with `DT:Z:SQ`, and the `DT` field is removed from all other records.
+fn mark_record(
+    record: &mut RecordBuf,
+    status: Status,
+    is_optical_duplicate_detection_enabled: bool,
+) {
+    record
+        .flags_mut()
+        .set(Flags::DUPLICATE, status != Status::Unique);
+
+    if is_optical_duplicate_detection_enabled {
+        let data = record.data_mut();
+
+        if status == Status::OpticalDuplicate {
+            data.insert(
+                DUPLICATE_TYPE,
+                record_buf::data::field::Value::from(SEQUENCING_DUPLICATE),
+            );
+        } else {
+            data.remove(&DUPLICATE_TYPE);
+        }
+    }
+}
+
+// Returns the index of the first highest score.
+fn find_best<I>(scores: I) -> usize
+where
+    I: Iterator<Item = u32>,
+{
+    let mut best = (0, None);
+
+    for (i, score) in scores.enumerate() {
+        if best.1.map(|s| score > s).unwrap_or(true) {
+            best = (i, Some(score));
+        }
+    }
+
+    best.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            fixmate::fix_mates,
+            record_buf::{data::field::Value as ValueBuf, QualityScores},
+        },
+        header::record::value::{
+            map::{read_group::tag as read_group_tag, ReadGroup, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder()
+                    .insert(read_group_tag::LIBRARY, "lib0")
+                    .build()?,
+            )
+            .add_read_group(
+                "rg1",
+                Map::<ReadGroup>::builder()
+                    .insert(read_group_tag::LIBRARY, "lib0")
+                    .build()?,
+            )
+            .build())
+    }
+
+    fn build_record(
+        name: &str,
+        flags: Flags,
+        alignment_start: usize,
+        cigar: &[(Kind, usize)],
+        quality_score: u8,
+    ) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        let read_length: usize = cigar
+            .iter()
+            .filter(|(kind, _)| kind.consumes_read())
+            .map(|(_, len)| len)
+            .sum();
+
+        Ok(RecordBuf::builder()
+            .set_name(name.as_bytes().into())
+            .set_flags(flags)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar(
+                cigar
+                    .iter()
+                    .map(|&(kind, len)| Op::new(kind, len))
+                    .collect(),
+            )
+            .set_sequence(vec![b'A'; read_length].into())
+            .set_quality_scores(QualityScores::from(vec![quality_score; read_length]))
+            .set_data(
+                [(Tag::READ_GROUP, ValueBuf::from("rg0"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build())
+    }
+
+    // Builds a pair from a forward read and a reverse read.
+    fn build_pair(
+        name: &str,
+        (start, cigar, quality_score): (usize, &[(Kind, usize)], u8),
+        (mate_start, mate_cigar, mate_quality_score): (usize, &[(Kind, usize)], u8),
+    ) -> Result<(RecordBuf, RecordBuf), Box<dyn std::error::Error>> {
+        let mut a = build_record(
+            name,
+            Flags::SEGMENTED | Flags::FIRST_SEGMENT,
+            start,
+            cigar,
+            quality_score,
+        )?;
+
+        let mut b = build_record(
+            name,
+            Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED,
+            mate_start,
+            mate_cigar,
+            mate_quality_score,
+        )?;
+
+        fix_mates(&mut a, &mut b)?;
+
+        Ok((a, b))
+    }
+
+    fn take_records(marker: &mut Marker) -> Vec<RecordBuf> {
+        iter::from_fn(|| marker.next_record()).collect()
+    }
+
+    fn mark(
+        mut marker: Marker,
+        header: &sam::Header,
+        records: Vec<RecordBuf>,
+    ) -> io::Result<(Vec<RecordBuf>, Vec<DuplicationMetrics>)> {
+        let mut marked_records = Vec::new();
+
+        for record in records {
+            marker.add_record(header, record)?;
+            marked_records.extend(take_records(&mut marker));
+        }
+
+        marker.finish();
+        marked_records.extend(take_records(&mut marker));
+
+        let metrics = marker.metrics().cloned().collect();
+
+        Ok((marked_records, metrics))
+    }
+
+    fn names(records: &[RecordBuf]) -> Vec<&[u8]> {
+        records
+            .iter()
+            .filter_map(|record| record.name().map(|name| name.as_ref()))
+            .collect()
+    }
+
+    fn duplicate_names(records: &[RecordBuf]) -> Vec<&[u8]> {
+        records
+            .iter()
+            .filter(|record| record.flags().is_duplicate())
+            .filter_map(|record| record.name().map(|name| name.as_ref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_mark() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        // r0: pair at (1F, 20R)
+        let r0 = build_pair(
+            "r0",
+            (1, &[(Kind::Match, 4)], 30),
+            (15, &[(Kind::Match, 4), (Kind::SoftClip, 2)], 30),
+        )?;
+
+        // r1: duplicate pair (lower score)
+        let r1 = build_pair(
+            "r1",
+            (1, &[(Kind::Match, 4)], 20),
+            (17, &[(Kind::Match, 4)], 20),
+        )?;
+
+        // r2: duplicate pair (clipped)
+        let r2 = build_pair(
+            "r2",
+            (3, &[(Kind::SoftClip, 2), (Kind::Match, 2)], 10),
+            (17, &[(Kind::Match, 4)], 10),
+        )?;
+
+        let records = vec![
+            r0.0,
+            r1.0,
+            // r3: unpaired read at a pair position
+            build_record("r3", Flags::empty(), 1, &[(Kind::Match, 4)], 40)?,
+            // r8: secondary
+            build_record("r8", Flags::SECONDARY, 1, &[(Kind::Match, 4)], 10)?,
+            r2.0,
+            r0.1,
+            r1.1,
+            r2.1,
+            // r6: unpaired read in the reverse orientation
+            build_record(
+                "r6",
+                Flags::REVERSE_COMPLEMENTED,
+                47,
+                &[(Kind::Match, 4)],
+                20,
+            )?,
+            // r4, r5: unpaired reads at the same position
+            build_record("r4", Flags::empty(), 50, &[(Kind::Match, 4)], 20)?,
+            build_record("r5", Flags::empty(), 50, &[(Kind::Match, 4)], 30)?,
+            // r7: mate is unmapped
+            build_record(
+                "r7",
+                Flags::SEGMENTED | Flags::MATE_UNMAPPED,
+                50,
+                &[(Kind::Match, 4)],
+                10,
+            )?,
+            build_record(
+                "r7",
+                Flags::SEGMENTED | Flags::UNMAPPED,
+                50,
+                &[(Kind::Match, 4)],
+                10,
+            )?,
+        ];
+
+        let expected_names: Vec<_> = names(&records).into_iter().map(|n| n.to_vec()).collect();
+
+        let marker = Builder::default().build(&header);
+        let (marked_records, metrics) = mark(marker, &header, records)?;
+
+        assert_eq!(names(&marked_records), expected_names);
+
+        assert_eq!(
+            duplicate_names(&marked_records),
+            [&b"r1"[..], b"r3", b"r2", b"r1", b"r2", b"r4", b"r7"]
+        );
+
+        assert_eq!(
+            metrics,
+            [DuplicationMetrics {
+                library: String::from("lib0"),
+                unpaired_reads_examined: 5,
+                read_pairs_examined: 3,
+                secondary_or_supplementary_reads: 1,
+                unmapped_reads: 1,
+                unpaired_read_duplicates: 3,
+                read_pair_duplicates: 2,
+                read_pair_optical_duplicates: 0,
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mark_with_mate_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let (mut r0_a, r0_b) = build_pair(
+            "r0",
+            (1, &[(Kind::Match, 4)], 30),
+            (17, &[(Kind::Match, 4)], 20),
+        )?;
+
+        let (mut r1_a, r1_b) = build_pair(
+            "r1",
+            (1, &[(Kind::Match, 4)], 20),
+            (17, &[(Kind::Match, 4)], 40),
+        )?;
+
+        r0_a.data_mut().insert(MATE_SCORE, ValueBuf::from(80));
+        r1_a.data_mut().insert(MATE_SCORE, ValueBuf::from(160));
+
+        let marker = Builder::default().build(&header);
+        let (marked_records, _) = mark(marker, &header, vec![r0_a, r1_a, r0_b, r1_b])?;
+
+        assert_eq!(duplicate_names(&marked_records), [&b"r0"[..], b"r0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mark_with_optical_duplicates() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let pairs = [
+            build_pair(
+                "m0:1:1101:1000:1000",
+                (1, &[(Kind::Match, 4)], 30),
+                (17, &[(Kind::Match, 4)], 30),
+            )?,
+            build_pair(
+                "m0:1:1101:1050:1050",
+                (1, &[(Kind::Match, 4)], 20),
+                (17, &[(Kind::Match, 4)], 20),
+            )?,
+            build_pair(
+                "m0:1:1102:1000:1000",
+                (1, &[(Kind::Match, 4)], 20),
+                (17, &[(Kind::Match, 4)], 20),
+            )?,
+        ];
+
+        let (first_reads, mates): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        let records = first_reads.into_iter().chain(mates).collect();
+
+        let marker = Builder::default()
+            .set_optical_duplicate_pixel_distance(100)
+            .build(&header);
+
+        let (marked_records, metrics) = mark(marker, &header, records)?;
+
+        assert_eq!(
+            duplicate_names(&marked_records),
+            [
+                &b"m0:1:1101:1050:1050"[..],
+                b"m0:1:1102:1000:1000",
+                b"m0:1:1101:1050:1050",
+                b"m0:1:1102:1000:1000",
+            ]
+        );
+
+        let actual: Vec<_> = marked_records
+            .iter()
+            .filter(|record| {
+                record.data().get(&DUPLICATE_TYPE) == Some(&ValueBuf::from(SEQUENCING_DUPLICATE))
+            })
+            .filter_map(|record| record.name().map(|name| name.as_ref()))
+            .collect();
+
+        assert_eq!(
+            actual,
+            [&b"m0:1:1101:1050:1050"[..], b"m0:1:1101:1050:1050"]
+        );
+
+        assert_eq!(metrics[0].read_pair_optical_duplicates(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let build_interchromosomal_pair = |name, quality_score| {
+            let mut a = build_record(
+                name,
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT,
+                1,
+                &[(Kind::Match, 4)],
+                quality_score,
+            )?;
+
+            let mut b = build_record(
+                name,
+                Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED,
+                5,
+                &[(Kind::Match, 4)],
+                quality_score,
+            )?;
+
+            *b.reference_sequence_id_mut() = Some(1);
+            fix_mates(&mut a, &mut b)?;
+
+            Ok::<_, Box<dyn std::error::Error>>((a, b))
+        };
+
+        let (r0_a, r0_b) = build_interchromosomal_pair("r0", 30)?;
+        let (r1_a, r1_b) = build_interchromosomal_pair("r1", 20)?;
+
+        let mut marker = Builder::default().build(&header);
+
+        marker.add_record(&header, r0_a)?;
+        marker.add_record(&header, r1_a)?;
+        assert!(marker.next_record().is_none());
+
+        marker.add_record(
+            &header,
+            build_record("r2", Flags::empty(), 100, &[(Kind::Match, 4)], 30)?,
+        )?;
+
+        // The pairs are resolved before their mates on the next reference sequence are added.
+        let records = take_records(&mut marker);
+        assert_eq!(names(&records), [&b"r0"[..], b"r1"]);
+        assert_eq!(duplicate_names(&records), [b"r1"]);
+        assert!(marker.pending_pairs.is_empty());
+        assert_eq!(marker.duplicate_pairs.len(), 1);
+
+        marker.add_record(&header, r0_b)?;
+        marker.add_record(&header, r1_b)?;
+        marker.finish();
+
+        let records = take_records(&mut marker);
+        assert_eq!(names(&records), [&b"r2"[..], b"r0", b"r1"]);
+        assert_eq!(duplicate_names(&records), [b"r1"]);
+        assert!(marker.duplicate_pairs.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let mut marker = Builder::default().build(&header);
+
+        marker.add_record(
+            &header,
+            build_record("r0", Flags::empty(), 8, &[(Kind::Match, 4)], 30)?,
+        )?;
+
+        let record = build_record("r1", Flags::empty(), 5, &[(Kind::Match, 4)], 30)?;
+
+        assert!(matches!(
+            marker.add_record(&header, record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_long_clip() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let records = vec![
+            build_record("r0", Flags::empty(), 90, &[(Kind::Match, 4)], 20)?,
+            // The 5' end of r1 is at 90.
+            build_record(
+                "r1",
+                Flags::empty(),
+                150,
+                &[(Kind::SoftClip, 60), (Kind::Match, 4)],
+                30,
+            )?,
+        ];
+
+        let marker = Builder::default().build(&header);
+        let (marked_records, _) = mark(marker, &header, records)?;
+
+        assert_eq!(duplicate_names(&marked_records), [b"r0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_missing_mate_cigar() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let mut marker = Builder::default().build(&header);
+
+        let (mut record, _) = build_pair(
+            "r0",
+            (1, &[(Kind::Match, 4)], 30),
+            (17, &[(Kind::Match, 4)], 30),
+        )?;
+
+        record.data_mut().remove(&Tag::MATE_CIGAR);
+
+        assert!(matches!(
+            marker.add_record(&header, record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_mates_in_different_read_groups(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let mut marker = Builder::default().build(&header);
+
+        let (record, mut mate) = build_pair(
+            "r0",
+            (1, &[(Kind::Match, 4)], 30),
+            (17, &[(Kind::Match, 4)], 30),
+        )?;
+
+        mate.data_mut()
+            .insert(Tag::READ_GROUP, ValueBuf::from("rg1"));
+
+        marker.add_record(&header, record)?;
+
+        assert!(matches!(
+            marker.add_record(&header, mate),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_cigar() -> io::Result<()> {
+        assert_eq!(
+            parse_cigar(b"2S4M1D3M")?,
+            [
+                Op::new(Kind::SoftClip, 2),
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::Deletion, 1),
+                Op::new(Kind::Match, 3),
+            ]
+        );
+
+        assert!(parse_cigar(b"").map(|ops| ops.is_empty())?);
+        assert!(parse_cigar(b"M").is_err());
+        assert!(parse_cigar(b"4").is_err());
+        assert!(parse_cigar(b"4Z").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_best() {
+        assert_eq!(find_best([3, 5, 5, 1].into_iter()), 1);
+        assert_eq!(find_best([3].into_iter()), 0);
+    }
+}
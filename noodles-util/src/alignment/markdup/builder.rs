@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use noodles_sam::{self as sam, header::record::value::map::read_group::tag};
+
+use super::{DuplicationMetrics, Marker};
+
+const UNKNOWN_LIBRARY: &str = "Unknown Library";
+
+/// An alignment record duplicate marker builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    optical_duplicate_pixel_distance: Option<u32>,
+}
+
+impl Builder {
+    /// Enables optical duplicate detection with the given maximum pixel distance.
+    ///
+    /// Duplicate pairs whose reads are in the same read group and tile and within this distance
+    /// (e.g., 100 for unpatterned and 2500 for patterned flow cells) are tagged as sequencing
+    /// duplicates (`DT:Z:SQ`). The physical location of a read is parsed from its Illumina read
+    /// name.
+    ///
+    /// By default, optical duplicate detection is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::markdup;
+    /// let builder = markdup::Builder::default().set_optical_duplicate_pixel_distance(100);
+    /// ```
+    pub fn set_optical_duplicate_pixel_distance(mut self, pixel_distance: u32) -> Self {
+        self.optical_duplicate_pixel_distance = Some(pixel_distance);
+        self
+    }
+
+    /// Builds an alignment record duplicate marker.
+    ///
+    /// The libraries of the records are read from the read groups (`@RG LB`) of the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup;
+    ///
+    /// let header = sam::Header::default();
+    /// let marker = markdup::Builder::default().build(&header);
+    /// ```
+    pub fn build(self, header: &sam::Header) -> Marker {
+        let mut metrics = Vec::new();
+        let mut library_indices = HashMap::new();
+        let mut read_group_libraries = HashMap::new();
+
+        for (id, read_group) in header.read_groups() {
+            let Some(library) = read_group.other_fields().get(&tag::LIBRARY) else {
+                continue;
+            };
+
+            let i = *library_indices
+                .entry(library.to_string())
+                .or_insert_with(|| {
+                    metrics.push(DuplicationMetrics::new(library.to_string()));
+                    metrics.len() - 1
+                });
+
+            read_group_libraries.insert(id.to_vec(), i);
+        }
+
+        let unknown_library_index = metrics.len();
+        metrics.push(DuplicationMetrics::new(UNKNOWN_LIBRARY.into()));
+
+        Marker {
+            optical_duplicate_pixel_distance: self.optical_duplicate_pixel_distance,
+            read_group_libraries,
+            unknown_library_index,
+            metrics,
+            last_coordinate: None,
+            has_unplaced_records: false,
+            max_clip_len: 0,
+            record_offset: 0,
+            records: VecDeque::new(),
+            fragment_groups: BTreeMap::new(),
+            pair_groups: BTreeMap::new(),
+            pending_pairs: HashMap::new(),
+            duplicate_pairs: HashMap::new(),
+        }
+    }
+}
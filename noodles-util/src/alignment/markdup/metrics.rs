@@ -0,0 +1,178 @@
+/// Duplication metrics of a library.
+///
+/// These match the fields of Picard's `DuplicationMetrics`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DuplicationMetrics {
+    pub(super) library: String,
+    pub(super) unpaired_reads_examined: u64,
+    pub(super) read_pairs_examined: u64,
+    pub(super) secondary_or_supplementary_reads: u64,
+    pub(super) unmapped_reads: u64,
+    pub(super) unpaired_read_duplicates: u64,
+    pub(super) read_pair_duplicates: u64,
+    pub(super) read_pair_optical_duplicates: u64,
+}
+
+impl DuplicationMetrics {
+    pub(super) fn new(library: String) -> Self {
+        Self {
+            library,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the library name (`LIBRARY`).
+    ///
+    /// This is the library (`LB`) of the read group of the records.
+    pub fn library(&self) -> &str {
+        &self.library
+    }
+
+    /// Returns the number of mapped primary reads without a mapped mate
+    /// (`UNPAIRED_READS_EXAMINED`).
+    pub fn unpaired_reads_examined(&self) -> u64 {
+        self.unpaired_reads_examined
+    }
+
+    /// Returns the number of pairs with both mates mapped (`READ_PAIRS_EXAMINED`).
+    pub fn read_pairs_examined(&self) -> u64 {
+        self.read_pairs_examined
+    }
+
+    /// Returns the number of secondary and supplementary records
+    /// (`SECONDARY_OR_SUPPLEMENTARY_RDS`).
+    pub fn secondary_or_supplementary_reads(&self) -> u64 {
+        self.secondary_or_supplementary_reads
+    }
+
+    /// Returns the number of unmapped reads (`UNMAPPED_READS`).
+    pub fn unmapped_reads(&self) -> u64 {
+        self.unmapped_reads
+    }
+
+    /// Returns the number of unpaired reads marked as duplicates (`UNPAIRED_READ_DUPLICATES`).
+    pub fn unpaired_read_duplicates(&self) -> u64 {
+        self.unpaired_read_duplicates
+    }
+
+    /// Returns the number of pairs marked as duplicates (`READ_PAIR_DUPLICATES`).
+    pub fn read_pair_duplicates(&self) -> u64 {
+        self.read_pair_duplicates
+    }
+
+    /// Returns the number of duplicate pairs that are optical duplicates
+    /// (`READ_PAIR_OPTICAL_DUPLICATES`).
+    ///
+    /// This is always 0 if optical duplicate detection is disabled.
+    pub fn read_pair_optical_duplicates(&self) -> u64 {
+        self.read_pair_optical_duplicates
+    }
+
+    /// Returns the fraction of mapped reads that are duplicates (`PERCENT_DUPLICATION`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::markdup::DuplicationMetrics;
+    /// let metrics = DuplicationMetrics::default();
+    /// assert_eq!(metrics.percent_duplication(), 0.0);
+    /// ```
+    pub fn percent_duplication(&self) -> f64 {
+        let read_count = self.unpaired_reads_examined + 2 * self.read_pairs_examined;
+
+        if read_count == 0 {
+            0.0
+        } else {
+            let duplicate_count = self.unpaired_read_duplicates + 2 * self.read_pair_duplicates;
+            duplicate_count as f64 / read_count as f64
+        }
+    }
+
+    /// Returns the estimated number of unique molecules in the library (`ESTIMATED_LIBRARY_SIZE`).
+    ///
+    /// This uses the Lander-Waterman equation with the number of non-optical duplicate pairs and
+    /// the number of unique pairs. It is only available when the library has duplicate pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::markdup::DuplicationMetrics;
+    /// let metrics = DuplicationMetrics::default();
+    /// assert!(metrics.estimated_library_size().is_none());
+    /// ```
+    pub fn estimated_library_size(&self) -> Option<u64> {
+        estimate_library_size(
+            self.read_pairs_examined - self.read_pair_optical_duplicates,
+            self.read_pairs_examined - self.read_pair_duplicates,
+        )
+    }
+}
+
+fn estimate_library_size(read_pair_count: u64, unique_read_pair_count: u64) -> Option<u64> {
+    const ITERATIONS: usize = 40;
+
+    fn f(x: f64, c: f64, n: f64) -> f64 {
+        c / x - 1.0 + (-n / x).exp()
+    }
+
+    if read_pair_count == 0 || unique_read_pair_count >= read_pair_count {
+        return None;
+    }
+
+    let n = read_pair_count as f64;
+    let c = unique_read_pair_count as f64;
+
+    let mut lower_bound = 1.0;
+    let mut upper_bound = 100.0;
+
+    if c == 0.0 || f(lower_bound * c, c, n) < 0.0 {
+        return None;
+    }
+
+    while f(upper_bound * c, c, n) > 0.0 {
+        upper_bound *= 10.0;
+    }
+
+    for _ in 0..ITERATIONS {
+        let r = (lower_bound + upper_bound) / 2.0;
+        let u = f(r * c, c, n);
+
+        if u == 0.0 {
+            break;
+        } else if u > 0.0 {
+            lower_bound = r;
+        } else {
+            upper_bound = r;
+        }
+    }
+
+    Some((c * (lower_bound + upper_bound) / 2.0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_duplication() {
+        let metrics = DuplicationMetrics {
+            unpaired_reads_examined: 4,
+            read_pairs_examined: 8,
+            unpaired_read_duplicates: 1,
+            read_pair_duplicates: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.percent_duplication(), 0.25);
+    }
+
+    #[test]
+    fn test_estimate_library_size() {
+        assert_eq!(estimate_library_size(100, 90), Some(466));
+        assert_eq!(estimate_library_size(1000, 500), Some(627));
+
+        assert!(estimate_library_size(0, 0).is_none());
+        assert!(estimate_library_size(100, 100).is_none());
+        assert!(estimate_library_size(100, 0).is_none());
+    }
+}
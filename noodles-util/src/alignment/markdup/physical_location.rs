@@ -0,0 +1,93 @@
+/// The physical location of a cluster on a flow cell.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct PhysicalLocation {
+    read_group: Option<Vec<u8>>,
+    tile: u32,
+    x: i32,
+    y: i32,
+}
+
+impl PhysicalLocation {
+    /// Parses the physical location from an Illumina read name.
+    ///
+    /// The read name is expected to have 5 (`<instrument>:<lane>:<tile>:<x>:<y>`) or 7
+    /// (`<instrument>:<run>:<flow cell>:<lane>:<tile>:<x>:<y>`) colon-separated fields.
+    pub(super) fn parse(name: &[u8], read_group: Option<&[u8]>) -> Option<Self> {
+        let fields: Vec<_> = name.split(|&b| b == b':').collect();
+
+        let [tile, x, y] = match fields[..] {
+            [_, _, tile, x, y] | [_, _, _, _, tile, x, y] => [tile, x, y],
+            _ => return None,
+        };
+
+        Some(Self {
+            read_group: read_group.map(|id| id.to_vec()),
+            tile: parse_int(tile)?,
+            x: parse_int(x)?,
+            y: parse_int(y)?,
+        })
+    }
+
+    /// Returns whether the other location is in the same read group and tile and within the given
+    /// pixel distance on both axes.
+    pub(super) fn is_near(&self, other: &Self, pixel_distance: u32) -> bool {
+        self.read_group == other.read_group
+            && self.tile == other.tile
+            && self.x.abs_diff(other.x) <= pixel_distance
+            && self.y.abs_diff(other.y) <= pixel_distance
+    }
+}
+
+fn parse_int<N>(src: &[u8]) -> Option<N>
+where
+    N: std::str::FromStr,
+{
+    std::str::from_utf8(src).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            PhysicalLocation::parse(b"r0:1:1101:1000:2000", None),
+            Some(PhysicalLocation {
+                read_group: None,
+                tile: 1101,
+                x: 1000,
+                y: 2000,
+            })
+        );
+
+        assert_eq!(
+            PhysicalLocation::parse(b"M0:8:FC0:1:1101:1000:2000", Some(b"rg0")),
+            Some(PhysicalLocation {
+                read_group: Some(b"rg0".to_vec()),
+                tile: 1101,
+                x: 1000,
+                y: 2000,
+            })
+        );
+
+        assert!(PhysicalLocation::parse(b"r0", None).is_none());
+        assert!(PhysicalLocation::parse(b"r0:1:1101:1000", None).is_none());
+        assert!(PhysicalLocation::parse(b"r0:1:1101:1000:y", None).is_none());
+    }
+
+    #[test]
+    fn test_is_near() {
+        let a = PhysicalLocation::parse(b"r0:1:1101:1000:2000", None).unwrap();
+
+        let b = PhysicalLocation::parse(b"r1:1:1101:1100:1900", None).unwrap();
+        assert!(a.is_near(&b, 100));
+        assert!(!a.is_near(&b, 99));
+
+        let b = PhysicalLocation::parse(b"r1:1:1102:1000:2000", None).unwrap();
+        assert!(!a.is_near(&b, 100));
+
+        let b = PhysicalLocation::parse(b"r1:1:1101:1000:2000", Some(b"rg0")).unwrap();
+        assert!(!a.is_near(&b, 100));
+    }
+}
@@ -0,0 +1,460 @@
+//! Alignment record merging.
+
+mod header;
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
+    io,
+};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        io::{Read, Write},
+        record::data::field::{value::Array as ArrayRef, Tag, Value as ValueRef},
+        record_buf::{
+            data::field::{value::Array, Value},
+            Cigar, Data, Name, QualityScores, Sequence,
+        },
+        Record, RecordBuf,
+    },
+};
+
+use self::header::{reconcile, Mapping};
+use super::sort::{key::Key, update_header, SortOrder};
+
+/// Merges SAM headers.
+///
+/// Reference sequences are unified by name, in order of first appearance. It is an error for
+/// reference sequences with the same name to have different lengths.
+///
+/// Read groups and programs with the same ID but different fields are renamed with a numeric
+/// suffix, e.g., `rg0` becomes `rg0-1`. Previous program IDs (`PP`) are rewritten accordingly.
+///
+/// The header (`@HD`) record is copied from the first header, and comments are de-duplicated.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     header::record::value::{map::{read_group::tag, ReadGroup}, Map},
+/// };
+/// use noodles_util::alignment::merge;
+///
+/// let a = sam::Header::builder()
+///     .add_read_group("rg0", Map::<ReadGroup>::default())
+///     .build();
+///
+/// let mut read_group = Map::<ReadGroup>::default();
+/// read_group.other_fields_mut().insert(tag::SAMPLE, "sample1".into());
+///
+/// let b = sam::Header::builder()
+///     .add_read_group("rg0", read_group)
+///     .build();
+///
+/// let header = merge::merge_headers(&[a, b])?;
+///
+/// let ids: Vec<_> = header.read_groups().keys().collect();
+/// assert_eq!(ids, ["rg0", "rg0-1"]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn merge_headers(headers: &[sam::Header]) -> io::Result<sam::Header> {
+    reconcile(headers).map(|(header, _)| header)
+}
+
+/// Merges sorted alignment readers into a single sorted stream.
+///
+/// This reads the header of each reader, merges them (see [`merge_headers`]), and writes the
+/// merged header and records to the given writer. The sort order (`SO`) and subsort order (`SS`)
+/// of the merged header are set to match the given sort order.
+///
+/// Each input must already be sorted in the given sort order. Reference sequence IDs and read
+/// group (`RG`) and program (`PG`) data fields are rewritten to match the merged header. Records
+/// that compare equal are written in the order of their readers.
+///
+/// For sort orders that compare coordinates, the reference sequences of each input must be in the
+/// same relative order as in the merged header. Otherwise, the records of that input would no
+/// longer be sorted, and this returns an error.
+///
+/// This also finishes the writer. The merged header is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam as sam;
+/// use noodles_util::alignment::{merge, sort::SortOrder};
+///
+/// let mut readers = [
+///     sam::io::Reader::new(&b"@HD\tVN:1.6\tSO:queryname\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n"[..]),
+///     sam::io::Reader::new(&b"@HD\tVN:1.6\tSO:queryname\n"[..]),
+/// ];
+///
+/// let mut writer = sam::io::Writer::new(Vec::new());
+/// merge::merge(&mut readers, &mut writer, SortOrder::QueryName)?;
+///
+/// assert_eq!(
+///     writer.get_ref(),
+///     b"@HD\tVN:1.6\tSO:queryname\tSS:queryname:natural\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n"
+/// );
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn merge<T, R, W>(
+    readers: &mut [T],
+    writer: &mut W,
+    sort_order: SortOrder,
+) -> io::Result<sam::Header>
+where
+    T: Read<R>,
+    W: Write + ?Sized,
+{
+    let headers = readers
+        .iter_mut()
+        .map(|reader| reader.read_alignment_header())
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let (mut header, mappings) = reconcile(&headers)?;
+
+    if sort_order != SortOrder::QueryName {
+        validate_reference_sequence_order(&mappings)?;
+    }
+
+    update_header(&mut header, sort_order);
+
+    writer.write_alignment_header(&header)?;
+
+    let mut sources: Vec<_> = readers
+        .iter_mut()
+        .zip(&headers)
+        .zip(&mappings)
+        .map(|((reader, src_header), mapping)| Source {
+            records: reader.alignment_records(src_header),
+            header: src_header,
+            mapping,
+        })
+        .collect();
+
+    let mut heap = BinaryHeap::with_capacity(sources.len());
+
+    for (i, source) in sources.iter_mut().enumerate() {
+        if let Some(entry) = source.next_entry(&header, sort_order, i)? {
+            heap.push(Reverse(entry));
+        }
+    }
+
+    while let Some(Reverse(entry)) = heap.pop() {
+        writer.write_alignment_record(&header, &entry.record)?;
+
+        let i = entry.source_index;
+
+        if let Some(entry) = sources[i].next_entry(&header, sort_order, i)? {
+            heap.push(Reverse(entry));
+        }
+    }
+
+    writer.finish(&header)?;
+
+    Ok(header)
+}
+
+// Coordinate sorting is only preserved if the reference sequence IDs of each input map to
+// increasing IDs in the merged header.
+fn validate_reference_sequence_order(mappings: &[Mapping]) -> io::Result<()> {
+    for (i, mapping) in mappings.iter().enumerate() {
+        let is_ordered = mapping
+            .reference_sequence_ids
+            .windows(2)
+            .all(|ids| ids[0] < ids[1]);
+
+        if !is_ordered {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "input {i}: order of reference sequences caused coordinate sort to be lost"
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+struct Source<'a> {
+    records: Box<dyn Iterator<Item = io::Result<Box<dyn Record>>> + 'a>,
+    header: &'a sam::Header,
+    mapping: &'a Mapping,
+}
+
+impl<'a> Source<'a> {
+    fn next_entry(
+        &mut self,
+        header: &sam::Header,
+        sort_order: SortOrder,
+        source_index: usize,
+    ) -> io::Result<Option<Entry>> {
+        let Some(result) = self.records.next() else {
+            return Ok(None);
+        };
+
+        let record = result?;
+
+        let mut record = try_to_record_buf(self.header, record.as_ref())?;
+        remap(self.mapping, &mut record)?;
+
+        let key = Key::from_record(sort_order, header, &record)?;
+
+        Ok(Some(Entry {
+            key,
+            source_index,
+            record,
+        }))
+    }
+}
+
+fn try_to_record_buf(header: &sam::Header, record: &dyn Record) -> io::Result<RecordBuf> {
+    let mut builder = RecordBuf::builder();
+
+    if let Some(name) = record.name() {
+        builder = builder.set_name(Name::from(name.as_bytes()));
+    }
+
+    builder = builder.set_flags(record.flags()?);
+
+    if let Some(reference_sequence_id) = record.reference_sequence_id(header).transpose()? {
+        builder = builder.set_reference_sequence_id(reference_sequence_id);
+    }
+
+    if let Some(alignment_start) = record.alignment_start().transpose()? {
+        builder = builder.set_alignment_start(alignment_start);
+    }
+
+    if let Some(mapping_quality) = record.mapping_quality().transpose()? {
+        builder = builder.set_mapping_quality(mapping_quality);
+    }
+
+    let cigar: Cigar = record.cigar().iter().collect::<io::Result<_>>()?;
+    builder = builder.set_cigar(cigar);
+
+    if let Some(mate_reference_sequence_id) =
+        record.mate_reference_sequence_id(header).transpose()?
+    {
+        builder = builder.set_mate_reference_sequence_id(mate_reference_sequence_id);
+    }
+
+    if let Some(mate_alignment_start) = record.mate_alignment_start().transpose()? {
+        builder = builder.set_mate_alignment_start(mate_alignment_start);
+    }
+
+    let data: Data = record
+        .data()
+        .iter()
+        .map(|result| result.and_then(|(tag, value)| try_to_value(value).map(|v| (tag, v))))
+        .collect::<io::Result<_>>()?;
+
+    builder = builder
+        .set_template_length(record.template_length()?)
+        .set_sequence(Sequence::from(record.sequence().iter().collect::<Vec<_>>()))
+        .set_quality_scores(QualityScores::from(
+            record.quality_scores().iter().collect::<Vec<_>>(),
+        ))
+        .set_data(data);
+
+    Ok(builder.build())
+}
+
+fn try_to_value(value: ValueRef<'_>) -> io::Result<Value> {
+    match value {
+        ValueRef::Character(c) => Ok(Value::Character(c)),
+        ValueRef::Int8(n) => Ok(Value::Int8(n)),
+        ValueRef::UInt8(n) => Ok(Value::UInt8(n)),
+        ValueRef::Int16(n) => Ok(Value::Int16(n)),
+        ValueRef::UInt16(n) => Ok(Value::UInt16(n)),
+        ValueRef::Int32(n) => Ok(Value::Int32(n)),
+        ValueRef::UInt32(n) => Ok(Value::UInt32(n)),
+        ValueRef::Float(n) => Ok(Value::Float(n)),
+        ValueRef::String(s) => Ok(Value::String(s.to_owned())),
+        ValueRef::Hex(s) => Ok(Value::Hex(s.to_owned())),
+        ValueRef::Array(array) => try_to_array(array).map(Value::Array),
+    }
+}
+
+fn try_to_array(array: ArrayRef<'_>) -> io::Result<Array> {
+    match array {
+        ArrayRef::Int8(values) => values.iter().collect::<io::Result<_>>().map(Array::Int8),
+        ArrayRef::UInt8(values) => values.iter().collect::<io::Result<_>>().map(Array::UInt8),
+        ArrayRef::Int16(values) => values.iter().collect::<io::Result<_>>().map(Array::Int16),
+        ArrayRef::UInt16(values) => values.iter().collect::<io::Result<_>>().map(Array::UInt16),
+        ArrayRef::Int32(values) => values.iter().collect::<io::Result<_>>().map(Array::Int32),
+        ArrayRef::UInt32(values) => values.iter().collect::<io::Result<_>>().map(Array::UInt32),
+        ArrayRef::Float(values) => values.iter().collect::<io::Result<_>>().map(Array::Float),
+    }
+}
+
+fn remap(mapping: &Mapping, record: &mut RecordBuf) -> io::Result<()> {
+    remap_reference_sequence_id(
+        &mapping.reference_sequence_ids,
+        record.reference_sequence_id_mut(),
+    )?;
+
+    remap_reference_sequence_id(
+        &mapping.reference_sequence_ids,
+        record.mate_reference_sequence_id_mut(),
+    )?;
+
+    remap_data_field(record.data_mut(), Tag::READ_GROUP, &mapping.read_group_ids);
+    remap_data_field(record.data_mut(), Tag::PROGRAM, &mapping.program_ids);
+
+    Ok(())
+}
+
+fn remap_reference_sequence_id(ids: &[usize], id: &mut Option<usize>) -> io::Result<()> {
+    if let Some(i) = id {
+        *i = ids.get(*i).copied().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+        })?;
+    }
+
+    Ok(())
+}
+
+fn remap_data_field(data: &mut Data, tag: Tag, ids: &HashMap<Vec<u8>, Vec<u8>>) {
+    if ids.is_empty() {
+        return;
+    }
+
+    if let Some(Value::String(id)) = data.get(&tag) {
+        if let Some(new_id) = ids.get(id.as_slice()) {
+            data.insert(tag, Value::String(new_id.clone().into()));
+        }
+    }
+}
+
+struct Entry {
+    key: Key,
+    source_index: usize,
+    record: RecordBuf,
+}
+
+impl Eq for Entry {}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .cmp(&other.key)
+            .then_with(|| self.source_index.cmp(&other.source_index))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        let a = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+@RG\tID:rg0
+r0\t0\tsq0\t1\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+r2\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+";
+
+        let b = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+@RG\tID:rg0\tSM:sample1
+r1\t0\tsq0\t3\t255\t*\tsq1\t8\t0\t*\t*\tRG:Z:rg0
+r3\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+";
+
+        let mut readers = [sam::io::Reader::new(&a[..]), sam::io::Reader::new(&b[..])];
+        let mut writer = sam::io::Writer::new(Vec::new());
+        merge(&mut readers, &mut writer, SortOrder::Coordinate)?;
+
+        let expected = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+@RG\tID:rg0
+@RG\tID:rg0-1\tSM:sample1
+r0\t0\tsq0\t1\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+r1\t0\tsq0\t3\t255\t*\tsq1\t8\t0\t*\t*\tRG:Z:rg0-1
+r2\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+r3\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0-1
+";
+
+        assert_eq!(
+            String::from_utf8_lossy(writer.get_ref()),
+            String::from_utf8_lossy(expected)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_inconsistent_reference_sequence_order() -> io::Result<()> {
+        let a = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+r0\t0\tsq0\t1\t255\t*\t*\t0\t0\t*\t*
+r2\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*
+";
+
+        // This is sorted by its own reference sequence order, which differs from the merged order.
+        let b = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq1\tLN:13
+@SQ\tSN:sq0\tLN:8
+r1\t0\tsq1\t3\t255\t*\t*\t0\t0\t*\t*
+r3\t0\tsq0\t5\t255\t*\t*\t0\t0\t*\t*
+";
+
+        let mut readers = [sam::io::Reader::new(&a[..]), sam::io::Reader::new(&b[..])];
+        let mut writer = sam::io::Writer::new(Vec::new());
+
+        assert!(matches!(
+            merge(&mut readers, &mut writer, SortOrder::Coordinate),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut readers = [sam::io::Reader::new(&a[..]), sam::io::Reader::new(&b[..])];
+        let mut writer = sam::io::Writer::new(Vec::new());
+        merge(&mut readers, &mut writer, SortOrder::QueryName)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_to_record_buf() -> io::Result<()> {
+        let src = b"@SQ\tSN:sq0\tLN:8
+r0\t99\tsq0\t1\t13\t4M\t=\t5\t8\tACGT\tNDLS\tHX:H:CAFE\tZB:B:s,-1,2
+";
+
+        let mut reader = sam::io::Reader::new(&src[..]);
+        let header = reader.read_header()?;
+        let mut record = sam::Record::default();
+        reader.read_record(&mut record)?;
+
+        let mut reader = sam::io::Reader::new(&src[..]);
+        reader.read_header()?;
+        let mut expected = RecordBuf::default();
+        reader.read_record_buf(&header, &mut expected)?;
+
+        let actual = try_to_record_buf(&header, &record)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
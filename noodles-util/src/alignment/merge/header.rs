@@ -0,0 +1,270 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
+
+use noodles_sam::{self as sam, header::record::value::map::program::tag as program_tag};
+
+#[derive(Debug, Default)]
+pub(super) struct Mapping {
+    pub(super) reference_sequence_ids: Vec<usize>,
+    pub(super) read_group_ids: HashMap<Vec<u8>, Vec<u8>>,
+    pub(super) program_ids: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+pub(super) fn reconcile(headers: &[sam::Header]) -> io::Result<(sam::Header, Vec<Mapping>)> {
+    let mut header = sam::Header::default();
+    *header.header_mut() = headers.first().and_then(|h| h.header()).cloned();
+
+    let mut mappings = Vec::with_capacity(headers.len());
+
+    for src in headers {
+        let mut mapping = Mapping::default();
+
+        add_reference_sequences(&mut header, src, &mut mapping)?;
+        add_read_groups(&mut header, src, &mut mapping);
+        add_programs(&mut header, src, &mut mapping);
+
+        for comment in src.comments() {
+            if !header.comments().contains(comment) {
+                header.comments_mut().push(comment.clone());
+            }
+        }
+
+        mappings.push(mapping);
+    }
+
+    Ok((header, mappings))
+}
+
+fn add_reference_sequences(
+    header: &mut sam::Header,
+    src: &sam::Header,
+    mapping: &mut Mapping,
+) -> io::Result<()> {
+    let reference_sequences = header.reference_sequences_mut();
+
+    for (name, reference_sequence) in src.reference_sequences() {
+        let id = if let Some((i, _, rs)) = reference_sequences.get_full(name) {
+            if rs.length() != reference_sequence.length() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("reference sequence length mismatch: {name}"),
+                ));
+            }
+
+            i
+        } else {
+            let (i, _) = reference_sequences.insert_full(name.clone(), reference_sequence.clone());
+            i
+        };
+
+        mapping.reference_sequence_ids.push(id);
+    }
+
+    Ok(())
+}
+
+fn add_read_groups(header: &mut sam::Header, src: &sam::Header, mapping: &mut Mapping) {
+    for (id, read_group) in src.read_groups() {
+        let new_id = resolve_id(id, |candidate| {
+            header
+                .read_groups()
+                .get(candidate)
+                .map(|rg| rg == read_group)
+                .unwrap_or(true)
+        });
+
+        if !header.read_groups().contains_key(new_id.as_slice()) {
+            header
+                .read_groups_mut()
+                .insert(new_id.clone().into(), read_group.clone());
+        }
+
+        if new_id != id.as_slice() {
+            mapping.read_group_ids.insert(id.to_vec(), new_id);
+        }
+    }
+}
+
+fn add_programs(header: &mut sam::Header, src: &sam::Header, mapping: &mut Mapping) {
+    // IDs are assigned before any programs are added so that previous program IDs (`PP`) can be
+    // rewritten regardless of the order of the programs.
+    let mut new_ids = Vec::with_capacity(src.programs().len());
+    let mut reserved_ids = HashSet::new();
+
+    for (id, program) in src.programs() {
+        let new_id = resolve_id(id, |candidate| {
+            !reserved_ids.contains(candidate)
+                && header
+                    .programs()
+                    .get(candidate)
+                    .map(|pg| pg == program)
+                    .unwrap_or(true)
+        });
+
+        reserved_ids.insert(new_id.clone());
+
+        if new_id != id.as_slice() {
+            mapping.program_ids.insert(id.to_vec(), new_id.clone());
+        }
+
+        new_ids.push(new_id);
+    }
+
+    for ((_, program), new_id) in src.programs().iter().zip(new_ids) {
+        if header.programs().contains_key(new_id.as_slice()) {
+            continue;
+        }
+
+        let mut program = program.clone();
+
+        if let Some(previous_program_id) = program
+            .other_fields_mut()
+            .get_mut(&program_tag::PREVIOUS_PROGRAM_ID)
+        {
+            if let Some(id) = mapping.program_ids.get(previous_program_id.as_slice()) {
+                *previous_program_id = id.clone().into();
+            }
+        }
+
+        header.programs_mut().insert(new_id.into(), program);
+    }
+}
+
+// Returns the given ID if it is available or, otherwise, the first available ID with a numeric
+// suffix, e.g., `rg0-1`.
+fn resolve_id<F>(id: &[u8], is_available: F) -> Vec<u8>
+where
+    F: Fn(&[u8]) -> bool,
+{
+    if is_available(id) {
+        return id.to_vec();
+    }
+
+    let mut n = 1;
+
+    loop {
+        let mut candidate = id.to_vec();
+        candidate.push(b'-');
+        candidate.extend(n.to_string().bytes());
+
+        if is_available(&candidate) {
+            return candidate;
+        }
+
+        n += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{
+        map::{read_group::tag as read_group_tag, Program, ReadGroup, ReferenceSequence},
+        Map,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_reconcile() -> Result<(), Box<dyn std::error::Error>> {
+        let sq = |len| -> Result<_, Box<dyn std::error::Error>> {
+            Ok(Map::<ReferenceSequence>::new(NonZeroUsize::try_from(len)?))
+        };
+
+        let mut rg0_b = Map::<ReadGroup>::default();
+        rg0_b
+            .other_fields_mut()
+            .insert(read_group_tag::SAMPLE, "sample1".into());
+
+        let mut pg0_b = Map::<Program>::default();
+        pg0_b
+            .other_fields_mut()
+            .insert(program_tag::NAME, "noodles".into());
+
+        let mut pg1_b = Map::<Program>::default();
+        pg1_b
+            .other_fields_mut()
+            .insert(program_tag::PREVIOUS_PROGRAM_ID, "pg0".into());
+
+        let a = sam::Header::builder()
+            .add_reference_sequence("sq0", sq(8)?)
+            .add_reference_sequence("sq1", sq(13)?)
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_program("pg0", Map::<Program>::default())
+            .add_comment("noodles")
+            .build();
+
+        let b = sam::Header::builder()
+            .add_reference_sequence("sq2", sq(21)?)
+            .add_reference_sequence("sq0", sq(8)?)
+            .add_read_group("rg0", rg0_b)
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .add_program("pg0", pg0_b)
+            .add_program("pg1", pg1_b)
+            .add_comment("noodles")
+            .build();
+
+        let (header, mappings) = reconcile(&[a, b])?;
+
+        let names: Vec<_> = header.reference_sequences().keys().collect();
+        assert_eq!(names, ["sq0", "sq1", "sq2"]);
+
+        let ids: Vec<_> = header.read_groups().keys().collect();
+        assert_eq!(ids, ["rg0", "rg0-1", "rg1"]);
+
+        let ids: Vec<_> = header.programs().keys().collect();
+        assert_eq!(ids, ["pg0", "pg0-1", "pg1"]);
+
+        assert_eq!(
+            header.programs()[&b"pg1"[..]]
+                .other_fields()
+                .get(&program_tag::PREVIOUS_PROGRAM_ID)
+                .map(|id| id.as_slice()),
+            Some(&b"pg0-1"[..])
+        );
+
+        assert_eq!(header.comments().len(), 1);
+
+        assert_eq!(mappings[0].reference_sequence_ids, [0, 1]);
+        assert!(mappings[0].read_group_ids.is_empty());
+        assert_eq!(mappings[1].reference_sequence_ids, [2, 0]);
+        assert_eq!(
+            mappings[1].read_group_ids,
+            [(b"rg0".to_vec(), b"rg0-1".to_vec())].into_iter().collect()
+        );
+        assert_eq!(
+            mappings[1].program_ids,
+            [(b"pg0".to_vec(), b"pg0-1".to_vec())].into_iter().collect()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconcile_with_reference_sequence_length_mismatch(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let a = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let b = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        assert!(matches!(
+            reconcile(&[a, b]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,420 @@
+//! Alignment record sorting.
+
+mod builder;
+pub(crate) mod key;
+mod sort_order;
+
+pub use self::{builder::Builder, sort_order::SortOrder};
+
+pub(crate) use self::builder::update_header;
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, BufWriter},
+    mem,
+    path::PathBuf,
+    process,
+    sync::atomic::{self, AtomicUsize},
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{self as sam, alignment::io::Write};
+
+use self::key::Key;
+
+static SORTER_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// An external-memory alignment record sorter.
+///
+/// Records are buffered in memory in the BAM encoding. When the buffer exceeds the memory limit,
+/// its records are sorted and written to a temporary file as a run of compressed BAM records.
+/// When the sorter is finished, all runs are merged into the output.
+///
+/// Records that compare equal are written in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam as sam;
+/// use noodles_util::alignment::sort::{self, SortOrder};
+///
+/// let header = sam::Header::default();
+///
+/// let mut sorter = sort::Builder::default()
+///     .set_sort_order(SortOrder::QueryName)
+///     .build(header);
+///
+/// let record = sam::alignment::RecordBuf::default();
+/// sorter.add_record(&record)?;
+///
+/// let mut writer = sam::io::Writer::new(Vec::new());
+/// sorter.finish(&mut writer)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Sorter {
+    header: sam::Header,
+    sort_order: SortOrder,
+    memory_limit: usize,
+    temporary_directory: PathBuf,
+    encoder: bam::io::Writer<Vec<u8>>,
+    entries: Vec<(Key, bam::Record)>,
+    memory_usage: usize,
+    id: Option<usize>,
+    runs: Vec<PathBuf>,
+}
+
+impl Sorter {
+    /// Returns the header of the sorted output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// let header = sorter.header();
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    /// Adds an alignment record to the sorter.
+    ///
+    /// This may write a run of sorted records to a temporary file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let mut sorter = sort::Builder::default().build(sam::Header::default());
+    ///
+    /// let record = sam::alignment::RecordBuf::default();
+    /// sorter.add_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn add_record(&mut self, record: &dyn sam::alignment::Record) -> io::Result<()> {
+        self.encoder.get_mut().clear();
+        self.encoder.write_alignment_record(&self.header, record)?;
+
+        // Discards the block size.
+        let mut buf = mem::take(self.encoder.get_mut());
+        buf.drain(..mem::size_of::<u32>());
+
+        let record = bam::Record::try_from(buf)?;
+        let key = Key::from_record(self.sort_order, &self.header, &record)?;
+
+        self.memory_usage +=
+            mem::size_of::<(Key, bam::Record)>() + key.heap_size() + record.as_ref().len();
+        self.entries.push((key, record));
+
+        if self.memory_usage >= self.memory_limit {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the header and all sorted records to the given writer.
+    ///
+    /// This also finishes the writer and removes all temporary files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    ///
+    /// let mut writer = sam::io::Writer::new(Vec::new());
+    /// sorter.finish(&mut writer)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"@HD\tVN:1.6\tSO:coordinate\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish<W>(mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        writer.write_alignment_header(&self.header)?;
+
+        if self.runs.is_empty() {
+            sort_entries(&mut self.entries);
+
+            for (_, record) in &self.entries {
+                writer.write_alignment_record(&self.header, record)?;
+            }
+        } else {
+            if !self.entries.is_empty() {
+                self.spill()?;
+            }
+
+            self.merge(writer)?;
+        }
+
+        writer.finish(&self.header)?;
+
+        self.remove_runs();
+
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        let id = *self
+            .id
+            .get_or_insert_with(|| SORTER_ID.fetch_add(1, atomic::Ordering::Relaxed));
+
+        let src = self.temporary_directory.join(format!(
+            "noodles-sort.{}.{}.{}.bam",
+            process::id(),
+            id,
+            self.runs.len()
+        ));
+
+        let file = OpenOptions::new().write(true).create_new(true).open(&src)?;
+        self.runs.push(src);
+
+        let writer = bgzf::writer::Builder::default()
+            .set_compression_level(bgzf::writer::CompressionLevel::fast())
+            .build_with_writer(BufWriter::new(file));
+        let mut writer = bam::io::Writer::from(writer);
+
+        writer.write_header(&self.header)?;
+
+        sort_entries(&mut self.entries);
+
+        for (_, record) in self.entries.drain(..) {
+            writer.write_record(&self.header, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        self.memory_usage = 0;
+
+        Ok(())
+    }
+
+    fn merge<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        let mut readers = Vec::with_capacity(self.runs.len());
+        let mut heap = BinaryHeap::with_capacity(self.runs.len());
+
+        for (i, src) in self.runs.iter().enumerate() {
+            let mut reader = File::open(src)
+                .map(BufReader::new)
+                .map(bam::io::Reader::new)?;
+
+            reader.read_header()?;
+
+            if let Some(entry) = read_entry(&mut reader, &self.header, self.sort_order, i)? {
+                heap.push(Reverse(entry));
+            }
+
+            readers.push(reader);
+        }
+
+        while let Some(Reverse(entry)) = heap.pop() {
+            writer.write_alignment_record(&self.header, &entry.record)?;
+
+            let i = entry.run_index;
+
+            if let Some(entry) = read_entry(&mut readers[i], &self.header, self.sort_order, i)? {
+                heap.push(Reverse(entry));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_runs(&mut self) {
+        for src in self.runs.drain(..) {
+            let _ = fs::remove_file(src);
+        }
+    }
+}
+
+impl Drop for Sorter {
+    fn drop(&mut self) {
+        self.remove_runs();
+    }
+}
+
+fn sort_entries(entries: &mut [(Key, bam::Record)]) {
+    // This must be a stable sort to keep equal records in their input order.
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+}
+
+struct Entry {
+    key: Key,
+    run_index: usize,
+    record: bam::Record,
+}
+
+impl Eq for Entry {}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Runs are created in input order, so ties are broken by the run index.
+        self.key
+            .cmp(&other.key)
+            .then_with(|| self.run_index.cmp(&other.run_index))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn read_entry<R>(
+    reader: &mut bam::io::Reader<R>,
+    header: &sam::Header,
+    sort_order: SortOrder,
+    run_index: usize,
+) -> io::Result<Option<Entry>>
+where
+    R: io::Read,
+{
+    let mut record = bam::Record::default();
+
+    if reader.read_record(&mut record)? == 0 {
+        return Ok(None);
+    }
+
+    let key = Key::from_record(sort_order, header, &record)?;
+
+    Ok(Some(Entry {
+        key,
+        run_index,
+        record,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{record::Flags, record_buf::Name, RecordBuf},
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_records() -> Result<Vec<RecordBuf>, Box<dyn std::error::Error>> {
+        let mut records = Vec::new();
+
+        for (name, reference_sequence_id, alignment_start, is_reverse_complemented) in [
+            (b"r10", Some(1), Some(5), false),
+            (b"r2_", None, None, false),
+            (b"r1_", Some(0), Some(13), false),
+            (b"r3_", Some(1), Some(5), true),
+            (b"r11", Some(0), Some(8), false),
+            (b"r4_", Some(1), Some(3), false),
+        ] {
+            let mut builder = RecordBuf::builder().set_name(Name::from(name));
+
+            if let Some(id) = reference_sequence_id {
+                builder = builder.set_reference_sequence_id(id);
+            }
+
+            if let Some(start) = alignment_start {
+                builder = builder.set_alignment_start(Position::try_from(start)?);
+            }
+
+            if is_reverse_complemented {
+                builder = builder.set_flags(Flags::REVERSE_COMPLEMENTED);
+            }
+
+            records.push(builder.build());
+        }
+
+        Ok(records)
+    }
+
+    fn sort(
+        header: &sam::Header,
+        records: &[RecordBuf],
+        sort_order: SortOrder,
+        memory_limit: usize,
+    ) -> io::Result<Vec<Vec<u8>>> {
+        let mut sorter = Builder::default()
+            .set_sort_order(sort_order)
+            .set_memory_limit(memory_limit)
+            .build(header.clone());
+
+        for record in records {
+            sorter.add_record(record)?;
+        }
+
+        let runs = sorter.runs.clone();
+
+        let mut writer = bam::io::Writer::from(Vec::new());
+        sorter.finish(&mut writer)?;
+
+        assert!(runs.iter().all(|src| !src.exists()));
+
+        let mut reader = bam::io::Reader::from(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        reader
+            .records()
+            .map(|result| {
+                result.map(|record| {
+                    record
+                        .name()
+                        .map(|name| name.as_bytes().to_vec())
+                        .unwrap_or_default()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build();
+
+        let records = build_records()?;
+
+        for memory_limit in [usize::MAX, 1, 512] {
+            assert_eq!(
+                sort(&header, &records, SortOrder::Coordinate, memory_limit)?,
+                [b"r11", b"r1_", b"r4_", b"r10", b"r3_", b"r2_"]
+            );
+
+            assert_eq!(
+                sort(&header, &records, SortOrder::QueryName, memory_limit)?,
+                [b"r1_", b"r2_", b"r3_", b"r4_", b"r10", b"r11"]
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::{env, path::PathBuf};
+
+use noodles_bam as bam;
+use noodles_sam::{
+    self as sam,
+    header::record::value::map::header::{sort_order, subsort_order, tag},
+};
+
+use super::{SortOrder, Sorter};
+
+// 768 MiB
+const DEFAULT_MEMORY_LIMIT: usize = 768 << 20;
+
+/// An alignment record sorter builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    sort_order: SortOrder,
+    memory_limit: Option<usize>,
+    temporary_directory: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the sort order.
+    ///
+    /// By default, records are sorted by coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort::{self, SortOrder};
+    /// let builder = sort::Builder::default().set_sort_order(SortOrder::QueryName);
+    /// ```
+    pub fn set_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Sets the approximate maximum number of bytes of records to hold in memory.
+    ///
+    /// When the limit is reached, the buffered records are sorted and written to a temporary file.
+    /// By default, this is 768 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort;
+    /// let builder = sort::Builder::default().set_memory_limit(1 << 30);
+    /// ```
+    pub fn set_memory_limit(mut self, memory_limit: usize) -> Self {
+        self.memory_limit = Some(memory_limit);
+        self
+    }
+
+    /// Sets the directory to write temporary files to.
+    ///
+    /// By default, this is the system temporary directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort;
+    /// let builder = sort::Builder::default().set_temporary_directory("/tmp");
+    /// ```
+    pub fn set_temporary_directory<P>(mut self, temporary_directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temporary_directory = Some(temporary_directory.into());
+        self
+    }
+
+    /// Builds an alignment record sorter.
+    ///
+    /// The given header is used to encode the records. The header of the output is a copy with
+    /// its sort order (`SO`) and subsort order (`SS`) set to match the sort order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// assert!(sorter.header().header().is_some());
+    /// ```
+    pub fn build(self, mut header: sam::Header) -> Sorter {
+        update_header(&mut header, self.sort_order);
+
+        Sorter {
+            header,
+            sort_order: self.sort_order,
+            memory_limit: self.memory_limit.unwrap_or(DEFAULT_MEMORY_LIMIT),
+            temporary_directory: self.temporary_directory.unwrap_or_else(env::temp_dir),
+            encoder: bam::io::Writer::from(Vec::new()),
+            entries: Vec::new(),
+            memory_usage: 0,
+            id: None,
+            runs: Vec::new(),
+        }
+    }
+}
+
+pub(crate) fn update_header(header: &mut sam::Header, sort_order: SortOrder) {
+    let header = header.header_mut().get_or_insert_with(Default::default);
+    let other_fields = header.other_fields_mut();
+
+    let (so, ss) = match sort_order {
+        SortOrder::Coordinate => (sort_order::SortOrder::Coordinate, None),
+        SortOrder::QueryName => (
+            sort_order::SortOrder::QueryName,
+            Some(subsort_order::SubsortOrder::QueryName(vec![String::from(
+                "natural",
+            )])),
+        ),
+        SortOrder::Tag(t) => {
+            let [a, b] = *t.as_ref();
+            let name = format!("{}{}", char::from(a), char::from(b));
+
+            (
+                sort_order::SortOrder::Unsorted,
+                Some(subsort_order::SubsortOrder::Unsorted(vec![
+                    String::from("tag"),
+                    name,
+                ])),
+            )
+        }
+    };
+
+    other_fields.insert(tag::SORT_ORDER, so.to_string().into());
+
+    match ss {
+        Some(ss) => {
+            other_fields.insert(tag::SUBSORT_ORDER, ss.to_string().into());
+        }
+        None => {
+            other_fields.shift_remove(&tag::SUBSORT_ORDER);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_header() {
+        fn t(sort_order: SortOrder, expected: &[u8]) {
+            let mut header = sam::Header::default();
+            update_header(&mut header, sort_order);
+
+            let mut writer = sam::io::Writer::new(Vec::new());
+            writer.write_header(&header).unwrap();
+
+            assert_eq!(writer.get_ref(), expected);
+        }
+
+        t(SortOrder::Coordinate, b"@HD\tVN:1.6\tSO:coordinate\n");
+        t(
+            SortOrder::QueryName,
+            b"@HD\tVN:1.6\tSO:queryname\tSS:queryname:natural\n",
+        );
+        t(
+            SortOrder::Tag([b'C', b'B'].into()),
+            b"@HD\tVN:1.6\tSO:unsorted\tSS:unsorted:tag:CB\n",
+        );
+    }
+}
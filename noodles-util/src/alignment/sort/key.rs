@@ -0,0 +1,260 @@
+use std::{cmp::Ordering, io};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            data::field::{Tag, Value},
+            Flags,
+        },
+        Record,
+    },
+};
+
+use super::SortOrder;
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Key {
+    Coordinate(CoordinateKey),
+    QueryName(QueryNameKey),
+    Tag(TagValue, CoordinateKey),
+}
+
+impl Key {
+    pub(crate) fn from_record(
+        sort_order: SortOrder,
+        header: &sam::Header,
+        record: &dyn Record,
+    ) -> io::Result<Self> {
+        match sort_order {
+            SortOrder::Coordinate => {
+                CoordinateKey::from_record(header, record).map(Self::Coordinate)
+            }
+            SortOrder::QueryName => QueryNameKey::from_record(record).map(Self::QueryName),
+            SortOrder::Tag(tag) => {
+                let value = TagValue::from_record(record, tag)?;
+                let key = CoordinateKey::from_record(header, record)?;
+                Ok(Self::Tag(value, key))
+            }
+        }
+    }
+
+    pub(crate) fn heap_size(&self) -> usize {
+        match self {
+            Self::Coordinate(_) => 0,
+            Self::QueryName(key) => key.name.len(),
+            Self::Tag(TagValue::String(buf), _) => buf.len(),
+            Self::Tag(..) => 0,
+        }
+    }
+}
+
+// (reference sequence ID, alignment start, is reverse complemented)
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct CoordinateKey(usize, usize, bool);
+
+impl CoordinateKey {
+    fn from_record(header: &sam::Header, record: &dyn Record) -> io::Result<Self> {
+        let reference_sequence_id = record
+            .reference_sequence_id(header)
+            .transpose()?
+            .unwrap_or(usize::MAX);
+
+        let alignment_start = record
+            .alignment_start()
+            .transpose()?
+            .map(usize::from)
+            .unwrap_or_default();
+
+        let is_reverse_complemented = record.flags()?.is_reverse_complemented();
+
+        Ok(Self(
+            reference_sequence_id,
+            alignment_start,
+            is_reverse_complemented,
+        ))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct QueryNameKey {
+    name: Vec<u8>,
+    flags: u16,
+}
+
+impl QueryNameKey {
+    fn from_record(record: &dyn Record) -> io::Result<Self> {
+        const ORDER_FLAGS: u16 = Flags::FIRST_SEGMENT.bits()
+            | Flags::LAST_SEGMENT.bits()
+            | Flags::SECONDARY.bits()
+            | Flags::SUPPLEMENTARY.bits();
+
+        let name = record
+            .name()
+            .map(|name| name.as_bytes().to_vec())
+            .unwrap_or_default();
+
+        // The segment flags are ordered before the secondary and supplementary flags.
+        let flags = record.flags()?.bits() & ORDER_FLAGS;
+        let flags = flags.rotate_left(8);
+
+        Ok(Self { name, flags })
+    }
+}
+
+impl Ord for QueryNameKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        natural_cmp(&self.name, &other.name).then_with(|| self.flags.cmp(&other.flags))
+    }
+}
+
+impl PartialOrd for QueryNameKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum TagValue {
+    Missing,
+    Number(f64),
+    String(Vec<u8>),
+}
+
+impl TagValue {
+    fn from_record(record: &dyn Record, tag: Tag) -> io::Result<Self> {
+        let data = record.data();
+
+        let Some(value) = data.get(&tag).transpose()? else {
+            return Ok(Self::Missing);
+        };
+
+        match value {
+            Value::Character(c) => Ok(Self::String(vec![c])),
+            Value::Float(n) => Ok(Self::Number(f64::from(n))),
+            Value::String(s) | Value::Hex(s) => Ok(Self::String(s.to_vec())),
+            Value::Array(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "cannot sort by an array data field value",
+            )),
+            // All integer types are losslessly representable as an `f64`.
+            _ => value
+                .as_int()
+                .map(|n| Self::Number(n as f64))
+                .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Missing => 0,
+            Self::Number(_) => 1,
+            Self::String(_) => 2,
+        }
+    }
+}
+
+impl Eq for TagValue {}
+
+impl PartialEq for TagValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Ord for TagValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for TagValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Compares two names, treating runs of digits as numbers.
+//
+// This is the same ordering as `strnum_cmp` in samtools.
+fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        if !a[i].is_ascii_digit() || !b[j].is_ascii_digit() {
+            match a[i].cmp(&b[j]) {
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                }
+                ordering => return ordering,
+            }
+        } else {
+            while a.get(i) == Some(&b'0') {
+                i += 1;
+            }
+
+            while b.get(j) == Some(&b'0') {
+                j += 1;
+            }
+
+            while i < a.len() && j < b.len() && a[i].is_ascii_digit() && a[i] == b[j] {
+                i += 1;
+                j += 1;
+            }
+
+            // The first differing digit decides numbers of the same length.
+            let ordering = a.get(i).cmp(&b.get(j));
+
+            while i < a.len() && j < b.len() && a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+                i += 1;
+                j += 1;
+            }
+
+            let is_a_digit = a.get(i).map(u8::is_ascii_digit).unwrap_or(false);
+            let is_b_digit = b.get(j).map(u8::is_ascii_digit).unwrap_or(false);
+
+            match (is_a_digit, is_b_digit) {
+                (true, _) => return Ordering::Greater,
+                (_, true) => return Ordering::Less,
+                _ => {
+                    if ordering.is_ne() {
+                        return ordering;
+                    }
+                }
+            }
+        }
+    }
+
+    (a.len() - i).cmp(&(b.len() - j))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp(b"r1", b"r1"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r2", b"r10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r10", b"r2"), Ordering::Greater);
+        assert_eq!(natural_cmp(b"r10", b"r11"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r007", b"r7"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r7a", b"r7b"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r7", b"r7a"), Ordering::Less);
+        assert_eq!(natural_cmp(b"a", b"b"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r1:2:3", b"r1:10:1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_tag_value_cmp() {
+        assert!(TagValue::Missing < TagValue::Number(0.0));
+        assert!(TagValue::Number(2.0) < TagValue::Number(10.0));
+        assert!(TagValue::Number(10.0) < TagValue::String(b"a".to_vec()));
+        assert!(TagValue::String(b"a".to_vec()) < TagValue::String(b"b".to_vec()));
+    }
+}
@@ -0,0 +1,21 @@
+use noodles_sam::alignment::record::data::field::Tag;
+
+/// An alignment record sort order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Records are sorted by reference sequence ID, alignment start, and strand.
+    ///
+    /// Records without a reference sequence ID are placed last. This matches `samtools sort`.
+    #[default]
+    Coordinate,
+    /// Records are sorted by natural ordering of their names, i.e., with numeric substrings
+    /// compared by value, and then by segment and whether they are primary alignments.
+    ///
+    /// This matches `samtools sort -n`.
+    QueryName,
+    /// Records are sorted by the value of the given data field and then by coordinate.
+    ///
+    /// Records without the field are placed first, followed by numeric values and then character
+    /// and string values. This matches `samtools sort -t <tag>`.
+    Tag(Tag),
+}
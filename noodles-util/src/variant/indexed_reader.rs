@@ -1,8 +1,9 @@
 //! Indexed variant reader.
 
 mod builder;
+mod query_regions;
 
-pub use self::builder::Builder;
+pub use self::{builder::Builder, query_regions::QueryRegions};
 
 use std::io::{self, Read, Seek};
 
@@ -14,7 +15,7 @@ use noodles_vcf::{self as vcf, Record};
 /// An indexed variant reader.
 pub enum IndexedReader<R> {
     /// VCF.
-    Vcf(vcf::IndexedReader<R>),
+    Vcf(vcf::IndexedReader<bgzf::Reader<R>>),
     /// BCF.
     Bcf(bcf::IndexedReader<bgzf::Reader<R>>),
 }
@@ -62,4 +63,20 @@ where
 
         Ok(records)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each record is returned once, even when regions
+    /// overlap. The regions that a record intersects are available from
+    /// [`QueryRegions::region_indices`].
+    pub fn query_regions<'r, 'h>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        match self {
+            Self::Vcf(reader) => reader.query_regions(header, regions).map(QueryRegions::Vcf),
+            Self::Bcf(reader) => reader.query_regions(header, regions).map(QueryRegions::Bcf),
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
+use noodles_vcf::{self as vcf, Record};
+
+/// An iterator over records of an indexed variant reader that intersect any of the given regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`super::IndexedReader::query_regions`].
+pub enum QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    /// VCF.
+    Vcf(vcf::reader::QueryRegions<'r, 'h, bgzf::Reader<R>>),
+    /// BCF.
+    Bcf(bcf::reader::QueryRegions<'r, 'h, R>),
+}
+
+impl<'r, 'h, R> QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        match self {
+            Self::Vcf(query) => query.region_indices(),
+            Self::Bcf(query) => query.region_indices(),
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Vcf(query) => query.next(),
+            Self::Bcf(query) => query.next(),
+        }
+    }
+}
@@ -1,15 +1,16 @@
 mod header;
 mod query;
+mod query_regions;
 
 use std::str;
 
 use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncSeek};
 
-use self::{header::read_header, query::query};
+use self::{header::read_header, query::query, query_regions::query_regions};
 use crate::{lazy, reader::resolve_region, Header, Record};
 
 const LINE_FEED: char = '\n';
@@ -389,6 +390,63 @@ where
             header,
         ))
     }
+
+    /// Returns a stream over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. Each record is paired with the
+    /// indices of the regions it intersects.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .await
+    ///     .map(bgzf::AsyncReader::new)
+    ///     .map(vcf::AsyncReader::new)?;
+    ///
+    /// let header = reader.read_header().await?;
+    ///
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some((record, region_indices)) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_regions<'r, I>(
+        &'r mut self,
+        header: &'r Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<impl Stream<Item = io::Result<(Record, Vec<usize>)>> + 'r>
+    where
+        I: BinningIndex,
+    {
+        let mut intervals = Vec::with_capacity(regions.len());
+        let mut resolved_regions = Vec::with_capacity(regions.len());
+
+        for region in regions {
+            let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
+            intervals.push((reference_sequence_id, region.interval()));
+            resolved_regions.push((reference_sequence_name, region.interval()));
+        }
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(query_regions(self, chunks, resolved_regions, header))
+    }
 }
 
 async fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
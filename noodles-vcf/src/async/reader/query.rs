@@ -84,7 +84,7 @@ where
     }))
 }
 
-async fn next_record<R>(
+pub(super) async fn next_record<R>(
     reader: &mut Reader<bgzf::AsyncReader<R>>,
     header: &Header,
 ) -> io::Result<Option<Record>>
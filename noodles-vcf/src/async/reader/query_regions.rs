@@ -0,0 +1,90 @@
+use std::vec;
+
+use futures::{stream, Stream};
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::binning_index::index::reference_sequence::bin::Chunk;
+use tokio::io::{self, AsyncRead, AsyncSeek};
+
+use super::{query::next_record, Reader};
+use crate::{reader::query::intersects, Header, Record};
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+struct Context<'r, R>
+where
+    R: AsyncRead + AsyncSeek,
+{
+    reader: &'r mut Reader<bgzf::AsyncReader<R>>,
+
+    chunks: vec::IntoIter<Chunk>,
+
+    regions: Vec<(Vec<u8>, Interval)>,
+
+    state: State,
+
+    header: &'r Header,
+}
+
+pub fn query_regions<'r, R>(
+    reader: &'r mut Reader<bgzf::AsyncReader<R>>,
+    chunks: Vec<Chunk>,
+    regions: Vec<(Vec<u8>, Interval)>,
+    header: &'r Header,
+) -> impl Stream<Item = io::Result<(Record, Vec<usize>)>> + 'r
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let ctx = Context {
+        reader,
+
+        chunks: chunks.into_iter(),
+
+        regions,
+
+        state: State::Seek,
+
+        header,
+    };
+
+    Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            match ctx.state {
+                State::Seek => {
+                    ctx.state = match ctx.chunks.next() {
+                        Some(chunk) => {
+                            ctx.reader.seek(chunk.start()).await?;
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    };
+                }
+                State::Read(chunk_end) => match next_record(ctx.reader, ctx.header).await? {
+                    Some(record) => {
+                        if ctx.reader.virtual_position() >= chunk_end {
+                            ctx.state = State::Seek;
+                        }
+
+                        let mut region_indices = Vec::new();
+
+                        for (i, (name, interval)) in ctx.regions.iter().enumerate() {
+                            if intersects(&record, name, *interval)? {
+                                region_indices.push(i);
+                            }
+                        }
+
+                        if !region_indices.is_empty() {
+                            return Ok(Some(((record, region_indices), ctx)));
+                        }
+                    }
+                    None => ctx.state = State::Seek,
+                },
+                State::Done => return Ok(None),
+            }
+        }
+    }))
+}
@@ -6,6 +6,7 @@ mod fmt;
 mod number;
 pub mod parser;
 pub mod record;
+pub mod sequence_dictionary;
 
 pub use self::{
     builder::Builder, file_format::FileFormat, number::Number, parser::ParseError, parser::Parser,
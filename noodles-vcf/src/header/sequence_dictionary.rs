@@ -0,0 +1,163 @@
+//! Conversions between VCF header contigs and a sequence dictionary.
+
+use std::io;
+
+use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+
+use super::{
+    record::value::{map::Contig, Map},
+    Contigs,
+};
+
+/// Converts VCF header contigs to a sequence dictionary.
+///
+/// The length (`length`) and MD5 checksum (`md5`) fields are carried over, if present.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{record::value::{map::Contig, Map}, sequence_dictionary},
+/// };
+///
+/// let mut contig = Map::<Contig>::new();
+/// *contig.length_mut() = Some(8);
+///
+/// let header = vcf::Header::builder().add_contig("sq0".parse()?, contig).build();
+///
+/// let dictionary = sequence_dictionary::from_contigs(header.contigs())?;
+/// assert_eq!(dictionary.get(b"sq0").and_then(|sequence| sequence.length()), Some(8));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn from_contigs(contigs: &Contigs) -> io::Result<SequenceDictionary> {
+    let mut dictionary = SequenceDictionary::new();
+
+    for (name, contig) in contigs {
+        let mut sequence = Sequence::new(name.as_ref());
+
+        if let Some(length) = contig.length() {
+            sequence = sequence.set_length(length);
+        }
+
+        if let Some(md5) = contig.md5() {
+            let md5_checksum = parse_md5_checksum(md5).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid contig MD5 checksum: {md5}"),
+                )
+            })?;
+
+            sequence = sequence.set_md5_checksum(md5_checksum);
+        }
+
+        dictionary.insert(sequence);
+    }
+
+    Ok(dictionary)
+}
+
+/// Converts a sequence dictionary to VCF header contigs.
+///
+/// VCF contig records have no field for alternative names, so sequence aliases are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+/// use noodles_vcf::header::sequence_dictionary;
+///
+/// let dictionary: SequenceDictionary = [Sequence::new("sq0").set_length(8)]
+///     .into_iter()
+///     .collect();
+///
+/// let contigs = sequence_dictionary::to_contigs(&dictionary)?;
+/// assert_eq!(contigs["sq0"].length(), Some(8));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn to_contigs(dictionary: &SequenceDictionary) -> io::Result<Contigs> {
+    let mut contigs = Contigs::with_capacity(dictionary.len());
+
+    for sequence in dictionary.iter() {
+        let name = std::str::from_utf8(sequence.name())
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "invalid contig name: {}",
+                        String::from_utf8_lossy(sequence.name())
+                    ),
+                )
+            })?;
+
+        let mut contig = Map::<Contig>::new();
+        *contig.length_mut() = sequence.length();
+        *contig.md5_mut() = sequence.md5_checksum().map(format_md5_checksum);
+
+        contigs.insert(name, contig);
+    }
+
+    Ok(contigs)
+}
+
+fn parse_md5_checksum(s: &str) -> Option<[u8; 16]> {
+    if s.len() != 32 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let mut md5_checksum = [0; 16];
+
+    for (i, b) in md5_checksum.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).ok()?;
+    }
+
+    Some(md5_checksum)
+}
+
+fn format_md5_checksum(md5_checksum: [u8; 16]) -> String {
+    md5_checksum.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let mut sq0 = Map::<Contig>::new();
+        *sq0.length_mut() = Some(8);
+        *sq0.md5_mut() = Some(String::from("d7eba311421bbc9d3ada44709dd61534"));
+
+        let sq1 = Map::<Contig>::new();
+
+        let contigs: Contigs = [("sq0".parse()?, sq0), ("sq1".parse()?, sq1)]
+            .into_iter()
+            .collect();
+
+        let dictionary = from_contigs(&contigs)?;
+
+        let expected: SequenceDictionary = [
+            Sequence::new("sq0").set_length(8).set_md5_checksum([
+                0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6,
+                0x15, 0x34,
+            ]),
+            Sequence::new("sq1"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(dictionary, expected);
+        assert_eq!(to_contigs(&dictionary)?, contigs);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_md5_checksum() {
+        assert!(parse_md5_checksum("d7eba311421bbc9d3ada44709dd61534").is_some());
+        assert!(parse_md5_checksum("d7eba311421bbc9d3ada44709dd6153").is_none());
+        assert!(parse_md5_checksum("d7eba311421bbc9d3ada44709dd6153z").is_none());
+    }
+}
@@ -4,51 +4,79 @@ mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, Read, Seek};
+use std::io::{self, BufRead, Read};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 use super::{
-    reader::{Query, Records},
+    reader::{Query, QueryRegions, Records},
     Header, Reader, Record,
 };
 use crate::lazy;
 
 /// An indexed VCF reader.
+///
+/// The underlying reader is a BGZF reader, e.g., a [`bgzf::Reader`] or a
+/// [`bgzf::MultithreadedReader`].
 pub struct IndexedReader<R> {
-    inner: Reader<bgzf::Reader<R>>,
+    inner: Reader<R>,
     index: Box<dyn BinningIndex>,
 }
 
-impl<R> IndexedReader<R>
+impl<R> IndexedReader<bgzf::Reader<R>>
 where
     R: Read,
 {
     /// Creates an indexed VCF reader.
     pub fn new<I>(inner: R, index: I) -> Self
+    where
+        I: BinningIndex + 'static,
+    {
+        Self::from_reader(bgzf::Reader::new(inner), index)
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an indexed VCF reader from a BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let inner = bgzf::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, &[][..]);
+    /// let reader = vcf::IndexedReader::from_reader(inner, tabix::Index::default());
+    /// ```
+    pub fn from_reader<I>(inner: R, index: I) -> Self
     where
         I: BinningIndex + 'static,
     {
         Self {
-            inner: Reader::new(bgzf::Reader::new(inner)),
+            inner: Reader::new(inner),
             index: Box::new(index),
         }
     }
 
     /// Returns a reference to the underlying reader.
-    pub fn get_ref(&self) -> &bgzf::Reader<R> {
+    pub fn get_ref(&self) -> &R {
         self.inner.get_ref()
     }
 
     /// Returns a mutable reference to the underlying reader.
-    pub fn get_mut(&mut self) -> &mut bgzf::Reader<R> {
+    pub fn get_mut(&mut self) -> &mut R {
         self.inner.get_mut()
     }
 
     /// Returns the underlying reader.
-    pub fn into_inner(self) -> bgzf::Reader<R> {
+    pub fn into_inner(self) -> R {
         self.inner.into_inner()
     }
 
@@ -63,10 +91,7 @@ where
     }
 
     /// Returns an iterator over records starting from the current stream position.
-    pub fn records<'r, 'h: 'r>(
-        &'r mut self,
-        header: &'h Header,
-    ) -> Records<'r, 'h, bgzf::Reader<R>> {
+    pub fn records<'r, 'h: 'r>(&'r mut self, header: &'h Header) -> Records<'r, 'h, R> {
         self.inner.records(header)
     }
 
@@ -83,7 +108,7 @@ where
 
 impl<R> IndexedReader<R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     /// Returns an iterator over records that intersects the given region.
     pub fn query<'r, 'h>(
@@ -93,4 +118,15 @@ where
     ) -> io::Result<Query<'r, 'h, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        self.inner.query_regions(header, &self.index, regions)
+    }
 }
@@ -1,14 +1,27 @@
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Position;
-use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+use noodles_csi::{
+    self as csi,
+    binning_index::index::{
+        header::ReferenceSequenceNames,
+        reference_sequence::{bin::Chunk, index::BinnedIndex},
+    },
+};
 use noodles_tabix as tabix;
 
 use super::{Reader, Record};
 
 /// Indexes a bgzipped-compressed VCF file.
 ///
+/// This builds a tabix index, which uses a min shift of 14 and depth of 5, i.e., it can only
+/// index positions up to 2^29 - 1. Use [`index_csi`] for longer reference sequences.
+///
 /// ```no_run
 /// use noodles_vcf as vcf;
 /// let index = vcf::index("sample.vcf.gz")?;
@@ -19,11 +32,76 @@ where
     P: AsRef<Path>,
 {
     let mut reader = File::open(src).map(bgzf::Reader::new).map(Reader::new)?;
-    let header = reader.read_header()?;
 
     let mut indexer = tabix::index::Indexer::default();
     indexer.set_header(csi::binning_index::index::header::Builder::vcf().build());
 
+    index_records(&mut reader, |reference_sequence_name, start, end, chunk| {
+        indexer.add_record(reference_sequence_name, start, end, chunk)
+    })?;
+
+    Ok(indexer.build())
+}
+
+/// Indexes a bgzipped-compressed VCF file with a coordinate-sorted index (CSI).
+///
+/// The min shift and depth of the binning index are configurable, which allows indexing reference
+/// sequences with positions greater than the 2^29 - 1 limit of tabix indices. The maximum
+/// position is 2^(min_shift + 3 * depth) - 1. `bcftools index --csi` uses a min shift of 14 and
+/// depth of 5 by default.
+///
+/// The index includes a tabix header with the reference sequence names in order of first
+/// appearance.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_vcf as vcf;
+/// let index = vcf::index_csi("sample.vcf.gz", 14, 6)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index_csi<P>(src: P, min_shift: u8, depth: u8) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(bgzf::Reader::new).map(Reader::new)?;
+    build_csi_index(&mut reader, min_shift, depth)
+}
+
+fn build_csi_index<R>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    min_shift: u8,
+    depth: u8,
+) -> io::Result<csi::Index>
+where
+    R: Read,
+{
+    let mut indexer = csi::binning_index::Indexer::<BinnedIndex>::new(min_shift, depth);
+    let mut reference_sequence_names = ReferenceSequenceNames::new();
+
+    index_records(reader, |reference_sequence_name, start, end, chunk| {
+        let (reference_sequence_id, _) =
+            reference_sequence_names.insert_full(reference_sequence_name.into());
+
+        indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)
+    })?;
+
+    let reference_sequence_count = reference_sequence_names.len();
+
+    let header = csi::binning_index::index::header::Builder::vcf()
+        .set_reference_sequence_names(reference_sequence_names)
+        .build();
+
+    Ok(indexer.set_header(header).build(reference_sequence_count))
+}
+
+fn index_records<R, F>(reader: &mut Reader<bgzf::Reader<R>>, mut f: F) -> io::Result<()>
+where
+    R: Read,
+    F: FnMut(&str, Position, Position, Chunk) -> io::Result<()>,
+{
+    let header = reader.read_header()?;
+
     let mut record = Record::default();
     let mut start_position = reader.get_ref().virtual_position();
 
@@ -42,10 +120,55 @@ where
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
             })?;
 
-        indexer.add_record(&reference_sequence_name, start, end, chunk)?;
+        f(&reference_sequence_name, start, end, chunk)?;
 
         start_position = end_position;
     }
 
-    Ok(indexer.build())
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+
+    #[test]
+    fn test_build_csi_index() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 6;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"##fileformat=VCFv4.3\n")?;
+        writer.write_all(b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n")?;
+        writer.write_all(b"sq0\t8\t.\tA\t.\t.\tPASS\t.\n")?;
+        writer.write_all(b"sq1\t536870913\t.\tA\t.\t.\tPASS\t.\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data.clone())));
+        let index = build_csi_index(&mut reader, MIN_SHIFT, DEPTH)?;
+
+        assert_eq!(index.min_shift(), MIN_SHIFT);
+        assert_eq!(index.depth(), DEPTH);
+
+        let header = index.header().ok_or("missing header")?;
+        let names: Vec<_> = header.reference_sequence_names().iter().collect();
+        assert_eq!(names, ["sq0", "sq1"]);
+
+        let position = Position::try_from(536870913)?;
+        let chunks = index.query(1, (position..=position).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        // The default tabix binning parameters cannot index the second record.
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        assert!(matches!(
+            build_csi_index(&mut reader, 14, 5),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -34,8 +34,14 @@ mod variant_writer;
 pub mod writer;
 
 pub use self::{
-    header::Header, indexed_reader::IndexedReader, indexer::index, reader::Reader, record::Record,
-    variant_reader::VariantReader, variant_writer::VariantWriter, writer::Writer,
+    header::Header,
+    indexed_reader::IndexedReader,
+    indexer::{index, index_csi},
+    reader::Reader,
+    record::Record,
+    variant_reader::VariantReader,
+    variant_writer::VariantWriter,
+    writer::Writer,
 };
 
 #[cfg(feature = "async")]
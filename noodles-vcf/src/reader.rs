@@ -3,22 +3,25 @@
 mod builder;
 mod header;
 pub(crate) mod query;
+mod query_regions;
 pub mod record;
 mod records;
 
 use crate::lazy;
 
 pub(crate) use self::record::parse_record;
-pub use self::{builder::Builder, query::Query, records::Records};
+pub use self::{builder::Builder, query::Query, query_regions::QueryRegions, records::Records};
 
 use std::{
-    io::{self, BufRead, Read, Seek},
+    io::{self, BufRead},
+    iter,
+    ops::Range,
     str,
 };
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::query_intervals, BinningIndex};
 
 use self::header::read_header;
 use super::{Header, Record, VariantReader};
@@ -248,9 +251,9 @@ where
     }
 }
 
-impl<R> Reader<bgzf::Reader<R>>
+impl<R> Reader<R>
 where
-    R: Read,
+    R: bgzf::io::BufRead,
 {
     /// Returns the current virtual position of the underlying BGZF reader.
     ///
@@ -274,9 +277,9 @@ where
     }
 }
 
-impl<R> Reader<bgzf::Reader<R>>
+impl<R> Reader<R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     /// Seeks the underlying BGZF stream to the given virtual position.
     ///
@@ -297,7 +300,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
-        self.inner.seek(pos)
+        self.inner.seek_to_virtual_position(pos)
     }
 
     /// Returns an iterator over records that intersects the given region.
@@ -347,6 +350,133 @@ where
             header,
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each chunk is read once, and each record is
+    /// returned once, even when regions overlap or are adjacent. The regions that a record
+    /// intersects are available from [`QueryRegions::region_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(vcf::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let mut query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// while let Some(record) = query.next().transpose()? {
+    ///     let region_indices = query.region_indices();
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h, I>(
+        &'r mut self,
+        header: &'h Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        let mut intervals = Vec::with_capacity(regions.len());
+        let mut resolved_regions = Vec::with_capacity(regions.len());
+
+        for region in regions {
+            let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
+            intervals.push((reference_sequence_id, region.interval()));
+            resolved_regions.push((reference_sequence_name, region.interval()));
+        }
+
+        let chunks = query_intervals(index, &intervals)?;
+
+        Ok(QueryRegions::new(
+            self.get_mut(),
+            chunks,
+            resolved_regions,
+            header,
+        ))
+    }
+
+    /// Returns an iterator over records that start in the given partition.
+    ///
+    /// Partitions typically come from [`bgzf::partition::partition`]. Unless the partition starts
+    /// at the beginning of the stream, the partial line at the start of the partition is skipped.
+    /// Any header lines are also skipped. Records are then read until one starts after the end of
+    /// the partition.
+    ///
+    /// A record that starts exactly at the end of a partition is read by that partition and
+    /// skipped by the next. This guarantees that, given contiguous partitions of a stream, each
+    /// record is read by exactly one partition.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(vcf::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let partitions = bgzf::partition::partition(reader.get_mut().get_mut(), worker_count)?;
+    ///
+    /// for partition in &partitions {
+    ///     for result in reader.query_partition(&header, partition)? {
+    ///         let record = result?;
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_partition<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h Header,
+        partition: &Range<bgzf::VirtualPosition>,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + 'r> {
+        const HEADER_PREFIX: u8 = b'#';
+
+        self.seek(partition.start)?;
+
+        if partition.start != bgzf::VirtualPosition::default() {
+            skip_line(&mut self.inner)?;
+        }
+
+        while self.inner.fill_buf()?.first() == Some(&HEADER_PREFIX) {
+            skip_line(&mut self.inner)?;
+        }
+
+        let end = partition.end;
+
+        Ok(iter::from_fn(move || {
+            if self.virtual_position() > end {
+                return None;
+            }
+
+            let mut record = Record::default();
+
+            match self.read_record(header, &mut record) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok(record)),
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
 }
 
 impl<R> VariantReader<R> for Reader<R>
@@ -392,6 +522,38 @@ where
     }
 }
 
+// Discards all bytes until a line feed ('\n') or EOF is reached.
+fn skip_line<R>(reader: &mut R) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    const LINE_FEED: u8 = b'\n';
+
+    let mut len = 0;
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            break;
+        }
+
+        let (n, is_eol) = match src.iter().position(|&b| b == LINE_FEED) {
+            Some(i) => (i + 1, true),
+            None => (src.len(), false),
+        };
+
+        reader.consume(n);
+        len += n;
+
+        if is_eol {
+            break;
+        }
+    }
+
+    Ok(len)
+}
+
 fn read_lazy_record<R>(reader: &mut R, record: &mut lazy::Record) -> io::Result<usize>
 where
     R: BufRead,
@@ -517,6 +679,140 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_query_partition() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::{Cursor, Write},
+            num::NonZeroUsize,
+        };
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"##fileformat=VCFv4.3\n")?;
+        writer.flush()?;
+        writer.write_all(b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n")?;
+        writer.flush()?;
+        writer.write_all(b"sq0\t1\t.\tA\t.\t.\tPASS\t.\n")?;
+        writer.flush()?;
+        writer.write_all(b"sq0\t2\t.\tA\t.\t.")?;
+        writer.flush()?;
+        writer.write_all(b"\tPASS\t.\nsq0\t3\t.\tA\t.\t.\tPASS\t.\nsq0\t4")?;
+        writer.flush()?;
+        writer.write_all(b"\t.\tA\t.\t.\tPASS\t.\n")?;
+        writer.flush()?;
+        writer.write_all(b"sq0\t5\t.\tA\t.\t.\tPASS\t.\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        let header = reader.read_header()?;
+
+        for count in 1..=8 {
+            let partitions = bgzf::partition::partition(
+                reader.get_mut().get_mut(),
+                NonZeroUsize::try_from(count)?,
+            )?;
+
+            let mut positions = Vec::new();
+
+            for partition in &partitions {
+                for result in reader.query_partition(&header, partition)? {
+                    let record = result?;
+                    positions.push(usize::from(record.position()));
+                }
+            }
+
+            assert_eq!(positions, [1, 2, 3, 4, 5]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::{Cursor, Write},
+            num::NonZeroUsize,
+        };
+
+        use noodles_core::Position;
+        use noodles_csi::binning_index::index::{header, reference_sequence::bin::Chunk};
+        use noodles_tabix as tabix;
+
+        let records = [("sq0", 1), ("sq0", 5), ("sq0", 9), ("sq0", 13), ("sq1", 1)];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"##fileformat=VCFv4.3\n")?;
+        writer.write_all(b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n")?;
+
+        let mut indexer = tabix::index::Indexer::default();
+        indexer.set_header(header::Builder::vcf().build());
+
+        for (reference_sequence_name, position) in records {
+            let start_position = writer.virtual_position();
+            writeln!(
+                writer,
+                "{reference_sequence_name}\t{position}\t.\tACGT\t.\t.\tPASS\t."
+            )?;
+            let end_position = writer.virtual_position();
+
+            let start = Position::try_from(position)?;
+            let end = start.checked_add(3).ok_or("invalid end")?;
+
+            indexer.add_record(
+                reference_sequence_name,
+                start,
+                end,
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build();
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data.clone())));
+        let header = reader.read_header()?;
+
+        let regions = ["sq0:6-10".parse()?, "sq0:9-9".parse()?, "sq1".parse()?];
+
+        let mut query = reader.query_regions(&header, &index, &regions)?;
+        let mut actual = Vec::new();
+
+        while let Some(record) = query.next().transpose()? {
+            actual.push((
+                record.chromosome().to_string(),
+                usize::from(record.position()),
+                query.region_indices().to_vec(),
+            ));
+        }
+
+        let expected = [
+            (String::from("sq0"), 5, vec![0]),
+            (String::from("sq0"), 9, vec![0, 1]),
+            (String::from("sq1"), 1, vec![2]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let inner = bgzf::MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+        let mut reader = crate::IndexedReader::from_reader(inner, index);
+        let header = reader.read_header()?;
+
+        let mut query = reader.query_regions(&header, &regions)?;
+        let mut actual = Vec::new();
+
+        while let Some(record) = query.next().transpose()? {
+            actual.push((
+                record.chromosome().to_string(),
+                usize::from(record.position()),
+                query.region_indices().to_vec(),
+            ));
+        }
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -1,4 +1,4 @@
-use std::io::{self, Read, Seek};
+use std::io;
 
 use noodles_bgzf as bgzf;
 use noodles_core::region::Interval;
@@ -12,7 +12,7 @@ use crate::{Header, Record};
 /// This is created by calling [`Reader::query`].
 pub struct Query<'r, 'h, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     reader: Reader<csi::io::Query<'r, R>>,
     reference_sequence_name: Vec<u8>,
@@ -23,10 +23,10 @@ where
 
 impl<'r, 'h, R> Query<'r, 'h, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     pub(super) fn new(
-        reader: &'r mut bgzf::Reader<R>,
+        reader: &'r mut R,
         chunks: Vec<Chunk>,
         reference_sequence_name: Vec<u8>,
         interval: Interval,
@@ -53,7 +53,7 @@ where
 
 impl<'r, 'h, R> Iterator for Query<'r, 'h, R>
 where
-    R: Read + Seek,
+    R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     type Item = io::Result<Record>;
 
@@ -0,0 +1,94 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::{query::intersects, Reader};
+use crate::{Header, Record};
+
+/// An iterator over records of a VCF reader that intersect any of a list of regions.
+///
+/// Each record is returned at most once. The indices of the regions the last returned record
+/// intersects are available from [`Self::region_indices`].
+///
+/// This is created by calling [`Reader::query_regions`].
+pub struct QueryRegions<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    reader: Reader<csi::io::Query<'r, R>>,
+    regions: Vec<(Vec<u8>, Interval)>,
+    header: &'h Header,
+    record: Record,
+    region_indices: Vec<usize>,
+}
+
+impl<'r, 'h, R> QueryRegions<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        chunks: Vec<Chunk>,
+        regions: Vec<(Vec<u8>, Interval)>,
+        header: &'h Header,
+    ) -> Self {
+        Self {
+            reader: Reader::new(csi::io::Query::new(reader, chunks)),
+            regions,
+            header,
+            record: Record::default(),
+            region_indices: Vec::new(),
+        }
+    }
+
+    /// Returns the indices of the regions that the last returned record intersects.
+    ///
+    /// The indices are positions in the list of regions given to the query.
+    pub fn region_indices(&self) -> &[usize] {
+        &self.region_indices
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<Record>> {
+        self.reader
+            .read_record(self.header, &mut self.record)
+            .map(|n| match n {
+                0 => None,
+                _ => Some(self.record.clone()),
+            })
+    }
+
+    fn update_region_indices(&mut self, record: &Record) -> io::Result<()> {
+        self.region_indices.clear();
+
+        for (i, (reference_sequence_name, interval)) in self.regions.iter().enumerate() {
+            if intersects(record, reference_sequence_name, *interval)? {
+                self.region_indices.push(i);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'r, 'h, R> Iterator for QueryRegions<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.next_record() {
+                Ok(Some(record)) => match self.update_region_indices(&record) {
+                    Ok(()) if !self.region_indices.is_empty() => return Some(Ok(record)),
+                    Ok(()) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}